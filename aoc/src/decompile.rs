@@ -0,0 +1,79 @@
+use aoc16::solutions::computer::{decompiler, Operation};
+use util::parse::{self, ParseError};
+
+/// The days of 2016 whose inputs are assembunny programs.
+const ASSEMBUNNY_DAYS: [u8; 3] = [12, 23, 25];
+
+/// The pseudo-code of the assembunny program, or its listing with `disassemble`.
+fn render(program: &str, disassemble: bool) -> Result<String, ParseError> {
+    let instructions = parse::map_lines(program, Operation::from)?;
+
+    Ok(match disassemble {
+        true => decompiler::disassemble(&instructions),
+        false => decompiler::decompile(&instructions),
+    })
+}
+
+/// Print the decompiled input of a day of 2016 whose input is an assembunny program.
+/// Returns false if the day has no such input or the input could not be read or parsed.
+pub fn decompile(day: u8, input_path: Option<&str>, disassemble: bool) -> bool {
+    if !ASSEMBUNNY_DAYS.contains(&day) {
+        eprintln!(
+            "The input of 2016 day {} is not an assembunny program, only days {:?} are",
+            day, ASSEMBUNNY_DAYS
+        );
+        return false;
+    }
+
+    let year = &aoc16::YEAR;
+    let input = match input_path {
+        Some(path) => util::dlin::read_input_file(path),
+        None => util::dlin::read_input(year.directory, &year.year.to_string(), day),
+    };
+    let input = match input {
+        Ok(text) => text,
+        Err(e) => {
+            crate::print_error(&e);
+            return false;
+        }
+    };
+
+    match render(&input, disassemble) {
+        Ok(text) => {
+            println!("{}", text);
+            true
+        }
+        Err(e) => {
+            eprintln!("Error parsing the input of day {}: {}", day, e);
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM: &str = "cpy 4 b\ninc a\ndec b\njnz b -2";
+
+    #[test]
+    fn programs_are_decompiled_or_disassembled() {
+        let instructions = parse::map_lines(PROGRAM, Operation::from).unwrap();
+
+        assert_eq!(
+            Ok(decompiler::decompile(&instructions)),
+            render(PROGRAM, false)
+        );
+        assert_eq!(
+            Ok(decompiler::disassemble(&instructions)),
+            render(PROGRAM, true)
+        );
+    }
+
+    #[test]
+    fn invalid_programs_are_errors() {
+        let error = render("cpy 4 b\nfly a", false).unwrap_err();
+
+        assert_eq!(Some(2), error.line());
+    }
+}
@@ -0,0 +1,426 @@
+// general structure taken and adapted from github user fspoettel at https://github.com/fspoettel/advent-of-code-2021/blob/master/src/main.rs
+
+use std::{ops::RangeInclusive, process};
+
+use clap::{Parser, Subcommand};
+use report::{Report, ReportFile};
+use util::{
+    answers::Answers,
+    dlin::DlinError,
+    solution::{Answer, Solved},
+    year::{Day, Year},
+};
+
+mod decompile;
+mod report;
+mod scaffold;
+mod submit;
+mod verify;
+
+const YEARS: [&Year; 4] = [&aoc16::YEAR, &aoc21::YEAR, &aoc22::YEAR, &aoc23::YEAR];
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Run the Advent of Code solutions of every year")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run the solutions of a year. Runs every solved day if no day is given.
+    Run {
+        #[arg(short, long)]
+        year: u16,
+        /// A single day like `17` or a range of days like `1-5`
+        #[arg(short, long, value_parser = parse_days)]
+        day: Option<RangeInclusive<u8>>,
+        /// Write the answers and timings to a `.json` or `.csv` file
+        #[arg(long, value_parser = ReportFile::parse)]
+        report: Option<ReportFile>,
+        /// Record the answers as accepted in the year's `answers.toml`. Recorded answers which
+        /// differ are kept unless `--overwrite` is given as well
+        #[arg(long)]
+        confirm: bool,
+        /// Replace recorded answers which differ when confirming
+        #[arg(long, requires = "confirm")]
+        overwrite: bool,
+        /// Read the input of a single day from a file instead, `-` reads from stdin
+        #[arg(short, long)]
+        input: Option<String>,
+    },
+    /// Compare the answers on the cached inputs with the recorded ones. Verifies every year if no year is given.
+    Verify {
+        #[arg(short, long)]
+        year: Option<u16>,
+    },
+    /// Save the example blocks of a puzzle description to the year's `examples` directory
+    Examples {
+        #[arg(short, long)]
+        year: u16,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Create a new day from `sample_code.txt` and register it in the year's crate
+    NewDay {
+        #[arg(short, long)]
+        year: u16,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+    },
+    /// Solve a day and submit the answer of one of its parts
+    Submit {
+        #[arg(short, long)]
+        year: u16,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Download every input of a year that is unlocked and not cached yet
+    Prefetch {
+        #[arg(short, long)]
+        year: u16,
+    },
+    /// Print the assembunny program of 2016 day 12, 23 or 25 as pseudo-code
+    Decompile {
+        #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        /// List the instructions with their jumps and toggles instead
+        #[arg(long)]
+        disassemble: bool,
+        /// Read the program from a file instead, `-` reads from stdin
+        #[arg(short, long)]
+        input: Option<String>,
+    },
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    let success = match cli.command {
+        Command::Run {
+            year,
+            day,
+            report,
+            confirm,
+            overwrite,
+            input,
+        } => {
+            let year = find_year(year);
+            let confirm = match (confirm, overwrite) {
+                (true, true) => Confirm::Overwrite,
+                (true, false) => Confirm::Missing,
+                (false, _) => Confirm::No,
+            };
+            run(year, day.unwrap_or(1..=25), report, confirm, input)
+        }
+        Command::Verify { year } => {
+            let years = match year {
+                Some(year) => vec![find_year(year)],
+                None => YEARS.to_vec(),
+            };
+
+            let mut success = true;
+            for year in years {
+                success &= verify::verify(year);
+            }
+            success
+        }
+        Command::Examples { year, day } => {
+            let year = find_year(year);
+            fetch_examples(year, day)
+        }
+        Command::NewDay { year, day } => {
+            let year = find_year(year);
+            scaffold::new_day(year, day)
+        }
+        Command::Submit { year, day, part } => {
+            let year = find_year(year);
+            match year.get(day) {
+                Some(day) => submit::submit(year, day, part),
+                None => {
+                    eprintln!("Day {} of {} has not been solved yet", day, year.year);
+                    false
+                }
+            }
+        }
+        Command::Prefetch { year } => {
+            let year = find_year(year);
+            prefetch(year)
+        }
+        Command::Decompile {
+            day,
+            disassemble,
+            input,
+        } => decompile::decompile(day, input.as_deref(), disassemble),
+    };
+
+    if !success {
+        process::exit(1);
+    }
+}
+
+fn find_year(year: u16) -> &'static Year {
+    match YEARS.into_iter().find(|solved| solved.year == year) {
+        Some(solved) => solved,
+        None => {
+            eprintln!("Year {} has not been solved yet", year);
+            process::exit(1);
+        }
+    }
+}
+
+/// Print an error of the website or the input cache together with how to fix it.
+fn print_error(e: &DlinError) {
+    eprintln!("Error: {}", e);
+
+    if let Some(remedy) = remedy(e) {
+        eprintln!("{}", remedy);
+    }
+}
+
+fn remedy(e: &DlinError) -> Option<&'static str> {
+    match e {
+        DlinError::MissingSession => Some(
+            "Add `session=<cookie>` with the session cookie of the website to the `.env`-file.",
+        ),
+        DlinError::SessionExpired => {
+            Some("Log in to the website again and refresh the session cookie in the `.env`-file.")
+        }
+        DlinError::NotUnlocked => Some("Wait until the puzzle is unlocked and try again."),
+        DlinError::NotCached(_) => Some(
+            "Download the input without `AOC_OFFLINE` or run the day on a file with `--input`.",
+        ),
+        DlinError::Offline => Some("Unset `AOC_OFFLINE` to contact the website."),
+        DlinError::Status(_) | DlinError::Http(_) => {
+            Some("Check the connection to the website and try again later.")
+        }
+        DlinError::UnexpectedResponse(_) | DlinError::Io(_) => None,
+    }
+}
+
+fn parse_days(value: &str) -> Result<RangeInclusive<u8>, String> {
+    let parse_day = |day: &str| {
+        day.trim()
+            .parse::<u8>()
+            .ok()
+            .filter(|day| (1..=25).contains(day))
+            .ok_or(format!("'{}' is not a day between 1 and 25", day))
+    };
+
+    match value.split_once('-') {
+        Some((start, end)) => Ok(parse_day(start)?..=parse_day(end)?),
+        None => parse_day(value).map(|day| day..=day),
+    }
+}
+
+/// Which answers of a run are recorded as accepted.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Confirm {
+    No,
+    /// Only answers of parts without a recorded answer.
+    Missing,
+    Overwrite,
+}
+
+fn run(
+    year: &Year,
+    days: RangeInclusive<u8>,
+    report_file: Option<ReportFile>,
+    confirm: Confirm,
+    input_path: Option<String>,
+) -> bool {
+    let selected = year
+        .days
+        .iter()
+        .filter(|solved| days.contains(&solved.day))
+        .collect::<Vec<_>>();
+
+    if selected.is_empty() {
+        eprintln!("No day in {:?} of {} has been solved yet", days, year.year);
+        return false;
+    }
+
+    if input_path.is_some() && selected.len() > 1 {
+        eprintln!("An input file can only be given for a single day");
+        return false;
+    }
+    if input_path.is_some() && confirm != Confirm::No {
+        eprintln!("Only answers for the cached puzzle input can be confirmed");
+        return false;
+    }
+
+    let mut answers = match confirm {
+        Confirm::Missing | Confirm::Overwrite => match Answers::load(year.directory) {
+            Ok(answers) => Some(answers),
+            Err(e) => {
+                eprintln!("Error reading the answers of {}: {}", year.year, e);
+                return false;
+            }
+        },
+        Confirm::No => None,
+    };
+
+    let mut success = true;
+    let mut report = Report::new(year.year);
+    for day in selected.iter() {
+        match solve_day(year, day, input_path.as_deref()) {
+            Some(solved) => {
+                if let Some(answers) = answers.as_mut() {
+                    for (part, answer) in [(1, &solved.part1), (2, &solved.part2)] {
+                        success &= confirm_answer(answers, day.day, part, answer, confirm);
+                    }
+                }
+                report.add(day.day, solved);
+            }
+            None => success = false,
+        }
+    }
+
+    if let Some(answers) = answers {
+        if let Err(e) = answers.save() {
+            eprintln!("Error writing the answers of {}: {}", year.year, e);
+            success = false;
+        }
+    }
+
+    if selected.len() > 1 {
+        println!("\n{}", report.table());
+    }
+
+    if let Some(file) = report_file {
+        if let Err(e) = report.write(&file) {
+            eprintln!("Error writing report to {}: {}", file.path.display(), e);
+            success = false;
+        }
+    }
+
+    success
+}
+
+/// Record the answer, reporting a recorded answer which differs unless it is overwritten.
+fn confirm_answer(
+    answers: &mut Answers,
+    day: u8,
+    part: u8,
+    answer: &Answer,
+    confirm: Confirm,
+) -> bool {
+    if confirm == Confirm::Overwrite {
+        answers.overwrite(day, part, answer.clone());
+        return true;
+    }
+
+    match answers.confirm(day, part, answer.clone()) {
+        Ok(()) => true,
+        Err(recorded) => {
+            eprintln!(
+                "Day {} part {}: {} differs from the recorded answer {}, use --overwrite to replace it",
+                day, part, answer, recorded
+            );
+            false
+        }
+    }
+}
+
+fn fetch_examples(year: &Year, day: u8) -> bool {
+    let examples = match util::dlin::fetch_examples(year.directory, &year.year.to_string(), day) {
+        Ok(examples) => examples,
+        Err(e) => {
+            print_error(&e);
+            return false;
+        }
+    };
+
+    for file in examples.files.iter() {
+        println!("Example: {}", file.display());
+    }
+    println!("Highlighted answers: {}", examples.answers.join(", "));
+
+    true
+}
+
+fn prefetch(year: &Year) -> bool {
+    match util::dlin::prefetch_inputs(year.directory, &year.year.to_string()) {
+        Ok(days) if days.is_empty() => {
+            println!("No new inputs of {} to download", year.year);
+            true
+        }
+        Ok(days) => {
+            println!("Downloaded the inputs of {} for days {:?}", year.year, days);
+            true
+        }
+        Err(e) => {
+            print_error(&e);
+            false
+        }
+    }
+}
+
+fn solve_day(year: &Year, day: &Day, input_path: Option<&str>) -> Option<Solved> {
+    println!("Day {}", day.day);
+
+    let input = match input_path {
+        Some(path) => util::dlin::read_input_file(path),
+        None => util::dlin::read_input(year.directory, &year.year.to_string(), day.day),
+    };
+    let input = match input {
+        Ok(text) => text,
+        Err(e) => {
+            print_error(&e);
+            return None;
+        }
+    };
+
+    let solved = match (day.solve)(&input) {
+        Ok(solved) => solved,
+        Err(e) => {
+            eprintln!("Error parsing the input of day {}: {}", day.day, e);
+            return None;
+        }
+    };
+    println!("Parse: {:?}", solved.parse_time);
+    println!("Part 1: {}\n {:?}", solved.part1, solved.part1_time);
+    println!("Part 2: {}\n {:?}", solved.part2, solved.part2_time);
+
+    Some(solved)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn single_day_is_parsed() {
+        assert_eq!(Ok(17..=17), parse_days("17"));
+    }
+
+    #[test]
+    fn range_of_days_is_parsed() {
+        assert_eq!(Ok(3..=7), parse_days("3-7"));
+    }
+
+    #[test]
+    fn days_outside_of_the_event_are_rejected() {
+        assert!(parse_days("0").is_err());
+        assert!(parse_days("26").is_err());
+        assert!(parse_days("1-31").is_err());
+        assert!(parse_days("a").is_err());
+    }
+
+    #[test]
+    fn every_year_is_registered_once() {
+        let years = YEARS.map(|year| year.year);
+
+        assert_eq!([2016, 2021, 2022, 2023], years);
+    }
+
+    #[test]
+    fn session_errors_explain_how_to_fix_them() {
+        assert!(remedy(&DlinError::MissingSession)
+            .is_some_and(|remedy| remedy.contains("session cookie")));
+        assert!(remedy(&DlinError::SessionExpired)
+            .is_some_and(|remedy| remedy.contains("refresh the session cookie")));
+    }
+}
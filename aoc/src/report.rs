@@ -0,0 +1,253 @@
+use std::{fs, io, path::PathBuf, time::Duration};
+
+use serde_json::json;
+use util::solution::{Answer, Solved};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Json,
+    Csv,
+}
+
+/// Where to write a report to. The format is taken from the file extension.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportFile {
+    pub path: PathBuf,
+    pub format: Format,
+}
+
+impl ReportFile {
+    pub fn parse(value: &str) -> Result<ReportFile, String> {
+        let path = PathBuf::from(value);
+        let format = match path.extension().and_then(|extension| extension.to_str()) {
+            Some("json") => Format::Json,
+            Some("csv") => Format::Csv,
+            _ => return Err(format!("'{}' has to end in .json or .csv", value)),
+        };
+
+        Ok(ReportFile { path, format })
+    }
+}
+
+/// A single part of a day. Both parts of a day share the time it took to parse the input.
+pub struct Row {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+}
+
+pub struct Report {
+    pub year: u16,
+    pub rows: Vec<Row>,
+}
+
+impl Report {
+    pub fn new(year: u16) -> Self {
+        Report {
+            year,
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn add(&mut self, day: u8, solved: Solved) {
+        let parts = [
+            (solved.part1, solved.part1_time),
+            (solved.part2, solved.part2_time),
+        ];
+
+        for (part, (answer, solve_time)) in (1..).zip(parts) {
+            self.rows.push(Row {
+                day,
+                part,
+                answer,
+                parse_time: solved.parse_time,
+                solve_time,
+            });
+        }
+    }
+
+    pub fn total_time(&self) -> Duration {
+        // the parse time is shared by both parts of a day and only counted once
+        self.rows
+            .iter()
+            .map(|row| match row.part {
+                1 => row.parse_time + row.solve_time,
+                _ => row.solve_time,
+            })
+            .sum()
+    }
+
+    pub fn table(&self) -> String {
+        let header = ["Day", "Part", "Answer", "Parse", "Solve"].map(String::from);
+        let lines: Vec<[String; 5]> = self
+            .rows
+            .iter()
+            .map(|row| {
+                [
+                    row.day.to_string(),
+                    row.part.to_string(),
+                    row.answer.to_string().escape_debug().to_string(),
+                    format!("{:.2?}", row.parse_time),
+                    format!("{:.2?}", row.solve_time),
+                ]
+            })
+            .collect();
+
+        let mut widths = header.clone().map(|cell| cell.chars().count());
+        for line in lines.iter() {
+            for (width, cell) in widths.iter_mut().zip(line) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let format_line = |line: &[String; 5]| {
+            line.iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+                .collect::<Vec<_>>()
+                .join(" | ")
+                .trim_end()
+                .to_string()
+        };
+        let separator = widths.map(|width| "-".repeat(width)).join("-+-");
+
+        let mut table = vec![format_line(&header), separator];
+        table.extend(lines.iter().map(format_line));
+        table.push(format!("Total: {:.2?}", self.total_time()));
+
+        table.join("\n")
+    }
+
+    pub fn to_json(&self) -> String {
+        let rows: Vec<_> = self
+            .rows
+            .iter()
+            .map(|row| {
+                json!({
+                    "day": row.day,
+                    "part": row.part,
+                    "answer": row.answer.to_string(),
+                    "parse_us": row.parse_time.as_micros() as u64,
+                    "solve_us": row.solve_time.as_micros() as u64,
+                })
+            })
+            .collect();
+
+        let report = json!({
+            "year": self.year,
+            "total_us": self.total_time().as_micros() as u64,
+            "results": rows,
+        });
+
+        serde_json::to_string_pretty(&report).unwrap()
+    }
+
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("year,day,part,answer,parse_us,solve_us\n");
+
+        for row in self.rows.iter() {
+            csv.push_str(&format!(
+                "{},{},{},{},{},{}\n",
+                self.year,
+                row.day,
+                row.part,
+                escape_csv(&row.answer.to_string()),
+                row.parse_time.as_micros(),
+                row.solve_time.as_micros(),
+            ));
+        }
+
+        csv
+    }
+
+    pub fn write(&self, file: &ReportFile) -> Result<(), io::Error> {
+        let content = match file.format {
+            Format::Json => self.to_json(),
+            Format::Csv => self.to_csv(),
+        };
+
+        fs::write(&file.path, content)
+    }
+}
+
+fn escape_csv(field: &str) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example_report() -> Report {
+        let mut report = Report::new(2022);
+        report.add(
+            1,
+            Solved {
+                parse_time: Duration::from_micros(10),
+                part1: Answer::from(24000),
+                part1_time: Duration::from_micros(5),
+                part2: Answer::from("A,B"),
+                part2_time: Duration::from_micros(7),
+            },
+        );
+
+        report
+    }
+
+    #[test]
+    fn report_files_are_recognized_by_extension() {
+        assert_eq!(
+            Format::Json,
+            ReportFile::parse("times.json").unwrap().format
+        );
+        assert_eq!(
+            Format::Csv,
+            ReportFile::parse("out/times.csv").unwrap().format
+        );
+        assert!(ReportFile::parse("times.txt").is_err());
+        assert!(ReportFile::parse("times").is_err());
+    }
+
+    #[test]
+    fn parse_time_is_counted_once_per_day() {
+        assert_eq!(Duration::from_micros(22), example_report().total_time());
+    }
+
+    #[test]
+    fn table_has_a_line_per_part() {
+        let table = example_report().table();
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(5, lines.len());
+        assert!(lines[2].starts_with("1   | 1    | 24000"));
+        assert!(lines[3].starts_with("1   | 2    | A,B"));
+    }
+
+    #[test]
+    fn csv_fields_are_quoted_when_needed() {
+        let csv = example_report().to_csv();
+
+        assert_eq!(
+            "year,day,part,answer,parse_us,solve_us\n\
+             2022,1,1,24000,10,5\n\
+             2022,1,2,\"A,B\",10,7\n",
+            csv
+        );
+    }
+
+    #[test]
+    fn json_contains_every_part() {
+        let json: serde_json::Value = serde_json::from_str(&example_report().to_json()).unwrap();
+
+        assert_eq!(2022, json["year"]);
+        assert_eq!(2, json["results"].as_array().unwrap().len());
+        assert_eq!("24000", json["results"][0]["answer"]);
+        assert_eq!(7, json["results"][1]["solve_us"]);
+    }
+}
@@ -0,0 +1,185 @@
+use std::{fs, path::Path};
+
+use util::year::Year;
+
+const TEMPLATE: &str = include_str!("../../sample_code.txt");
+
+/// Create the module of a new day from `sample_code.txt`, register it in the year's crate,
+/// create an empty example file and download the input.
+pub fn new_day(year: &Year, day: u8) -> bool {
+    match scaffold(Path::new(year.directory), day) {
+        Ok(()) => println!("Created day {} of {}", day, year.year),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            return false;
+        }
+    }
+
+    match util::dlin::read_input(year.directory, &year.year.to_string(), day) {
+        Ok(_) => true,
+        Err(e) => {
+            crate::print_error(&e);
+            false
+        }
+    }
+}
+
+fn scaffold(directory: &Path, day: u8) -> Result<(), String> {
+    let module = directory
+        .join("src/solutions")
+        .join(format!("day{:0>2}.rs", day));
+    if module.exists() {
+        return Err(format!("{} exists already", module.display()));
+    }
+
+    let solutions = directory.join("src/solutions.rs");
+    let lib = directory.join("src/lib.rs");
+    let registered_solutions = register_module(&read(&solutions)?, day);
+    let registered_lib = register_day(&read(&lib)?, day)?;
+
+    write(&module, &render_template(day))?;
+    write(&solutions, &registered_solutions)?;
+    write(&lib, &registered_lib)?;
+
+    let example = directory
+        .join("examples")
+        .join(format!("day{:0>2}.txt", day));
+    if !example.exists() {
+        write(&example, "")?;
+    }
+
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("reading {}: {}", path.display(), e))
+}
+
+fn write(path: &Path, content: &str) -> Result<(), String> {
+    fs::write(path, content).map_err(|e| format!("writing {}: {}", path.display(), e))
+}
+
+fn render_template(day: u8) -> String {
+    TEMPLATE
+        .replace("DayXX", &format!("Day{:0>2}", day))
+        .replace(
+            "include_str!(\"\")",
+            &format!("include_str!(\"../../examples/day{:0>2}.txt\")", day),
+        )
+}
+
+/// Add `pub mod dayNN;` to `solutions.rs`, keeping the modules sorted.
+fn register_module(solutions: &str, day: u8) -> String {
+    let module = format!("pub mod day{:0>2};", day);
+    let mut lines: Vec<&str> = solutions.lines().collect();
+
+    let position = lines
+        .iter()
+        .position(|line| line.starts_with("pub mod day") && **line > *module)
+        .unwrap_or(lines.len());
+    lines.insert(position, &module);
+
+    let mut registered = lines.join("\n");
+    if solutions.ends_with('\n') {
+        registered.push('\n');
+    }
+
+    registered
+}
+
+/// Add `N => dayNN::DayNN,` to the `util::year!` invocation in `lib.rs`, keeping the days sorted.
+fn register_day(lib: &str, day: u8) -> Result<String, String> {
+    let entry_day = |line: &str| -> Option<u8> {
+        let (number, solution) = line.trim().split_once(" => ")?;
+        solution.starts_with("day").then_some(())?;
+        number.parse().ok()
+    };
+
+    let mut lines: Vec<String> = lib.lines().map(String::from).collect();
+    let entries: Vec<(usize, u8)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| entry_day(line).map(|day| (index, day)))
+        .collect();
+
+    let &(last, _) = entries
+        .last()
+        .ok_or("no days are registered with `util::year!` in lib.rs")?;
+    let position = entries
+        .iter()
+        .find(|(_, registered)| *registered > day)
+        .map_or(last + 1, |&(index, _)| index);
+
+    let indentation = &lines[last][..lines[last].len() - lines[last].trim_start().len()];
+    let entry = format!("{}{} => day{:0>2}::Day{:0>2},", indentation, day, day, day);
+    lines.insert(position, entry);
+
+    let mut registered = lines.join("\n");
+    if lib.ends_with('\n') {
+        registered.push('\n');
+    }
+
+    Ok(registered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "\
+pub mod solutions;
+
+use solutions::*;
+
+util::year!(2023, \"aoc23\", {
+    1 => day01::Day01,
+    20 => day20::Day20,
+    22 => day22::Day22,
+});
+";
+
+    #[test]
+    fn template_is_rendered_for_the_day() {
+        let module = render_template(7);
+
+        assert!(module.contains("pub struct Day07;"));
+        assert!(module.contains("Day07::part1(&Day07::parse(EXAMPLE).unwrap())"));
+        assert!(module.contains("include_str!(\"../../examples/day07.txt\")"));
+        assert!(!module.contains("DayXX"));
+    }
+
+    #[test]
+    fn modules_are_registered_in_order() {
+        let solutions = "pub mod day01;\npub mod day20;\npub mod day22;";
+
+        assert_eq!(
+            "pub mod day01;\npub mod day20;\npub mod day21;\npub mod day22;",
+            register_module(solutions, 21)
+        );
+        assert_eq!(
+            "pub mod day01;\npub mod day20;\npub mod day22;\npub mod day24;\n",
+            register_module(&format!("{}\n", solutions), 24)
+        );
+    }
+
+    #[test]
+    fn days_are_registered_in_order() {
+        let registered = register_day(LIB, 21).unwrap();
+
+        assert!(registered.contains(
+            "    20 => day20::Day20,\n    21 => day21::Day21,\n    22 => day22::Day22,\n"
+        ));
+    }
+
+    #[test]
+    fn later_days_are_registered_last() {
+        let registered = register_day(LIB, 25).unwrap();
+
+        assert!(registered.contains("    22 => day22::Day22,\n    25 => day25::Day25,\n});\n"));
+    }
+
+    #[test]
+    fn lib_without_registered_days_is_rejected() {
+        assert!(register_day("pub mod solutions;\n", 1).is_err());
+    }
+}
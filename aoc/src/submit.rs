@@ -0,0 +1,98 @@
+use util::{
+    answers::Answers,
+    dlin::{self, DlinError, Verdict},
+    year::{Day, Year},
+};
+
+/// Solve a day and submit the answer of one part. Accepted and rejected answers are recorded,
+/// so a rejected answer is never submitted again.
+pub fn submit(year: &Year, day: &Day, part: u8) -> bool {
+    let mut answers = match Answers::load(year.directory) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Error reading the answers of {}: {}", year.year, e);
+            return false;
+        }
+    };
+
+    let input = match dlin::read_input(year.directory, &year.year.to_string(), day.day) {
+        Ok(text) => text,
+        Err(e) => {
+            crate::print_error(&e);
+            return false;
+        }
+    };
+
+    let solved = match (day.solve)(&input) {
+        Ok(solved) => solved,
+        Err(e) => {
+            eprintln!("Error parsing the input of day {}: {}", day.day, e);
+            return false;
+        }
+    };
+    let answer = match part {
+        1 => solved.part1,
+        _ => solved.part2,
+    };
+    println!("Day {} part {}: {}", day.day, part, answer);
+
+    if answers.get(day.day, part) == Some(&answer) {
+        println!("This answer has been accepted already");
+        return true;
+    }
+    if answers.is_rejected(day.day, part, &answer) {
+        eprintln!("This answer has been rejected before and is not submitted again");
+        return false;
+    }
+
+    let verdict = match dlin::submit_answer(&year.year.to_string(), day.day, part, &answer) {
+        Ok(verdict) => verdict,
+        Err(e) => {
+            crate::print_error(&e);
+            if matches!(e, DlinError::Status(_) | DlinError::Http(_)) {
+                eprintln!(
+                    "The answer may have been judged anyway, check the puzzle page before \
+                     submitting it again."
+                );
+            }
+            return false;
+        }
+    };
+
+    let success = match verdict {
+        Verdict::Correct => {
+            println!("That's the right answer!");
+            // the website has the final say over a recorded answer
+            answers.overwrite(day.day, part, answer);
+            true
+        }
+        Verdict::Incorrect | Verdict::TooHigh | Verdict::TooLow => {
+            let hint = match verdict {
+                Verdict::TooHigh => " It is too high.",
+                Verdict::TooLow => " It is too low.",
+                _ => "",
+            };
+            eprintln!("That's not the right answer.{}", hint);
+            answers.reject(day.day, part, answer);
+            false
+        }
+        Verdict::RateLimited(wait) => {
+            eprintln!(
+                "An answer was submitted too recently, wait another {:?}",
+                wait
+            );
+            false
+        }
+        Verdict::WrongLevel => {
+            eprintln!("Part {} is either solved already or not unlocked yet", part);
+            false
+        }
+    };
+
+    if let Err(e) = answers.save() {
+        eprintln!("Error writing the answers of {}: {}", year.year, e);
+        return false;
+    }
+
+    success
+}
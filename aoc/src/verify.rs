@@ -0,0 +1,100 @@
+use util::{answers::Answers, solution::Answer, year::Year};
+
+#[derive(Debug, PartialEq)]
+enum Outcome {
+    Correct,
+    Wrong,
+    Unknown,
+}
+
+fn check(expected: Option<&Answer>, actual: &Answer) -> Outcome {
+    match expected {
+        Some(expected) if expected == actual => Outcome::Correct,
+        Some(_) => Outcome::Wrong,
+        None => Outcome::Unknown,
+    }
+}
+
+/// Re-run every day with a cached input and compare the results with the recorded answers.
+/// Returns false if a single answer differs or the answers could not be read.
+pub fn verify(year: &Year) -> bool {
+    let answers = match Answers::load(year.directory) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Error reading the answers of {}: {}", year.year, e);
+            return false;
+        }
+    };
+
+    let (mut correct, mut wrong, mut unknown, mut skipped) = (0, 0, 0, 0);
+    for day in year.days.iter() {
+        let input = match util::dlin::read_cached_input(year.directory, day.day) {
+            Ok(Some(text)) => text,
+            Ok(None) => {
+                skipped += 1;
+                continue;
+            }
+            Err(e) => {
+                eprintln!(
+                    "Error reading the input of {} day {}: {}",
+                    year.year, day.day, e
+                );
+                wrong += 1;
+                continue;
+            }
+        };
+
+        let solved = match (day.solve)(&input) {
+            Ok(solved) => solved,
+            Err(e) => {
+                eprintln!(
+                    "Error parsing the input of {} day {}: {}",
+                    year.year, day.day, e
+                );
+                wrong += 1;
+                continue;
+            }
+        };
+        for (part, actual) in [(1, &solved.part1), (2, &solved.part2)] {
+            let expected = answers.get(day.day, part);
+
+            match check(expected, actual) {
+                Outcome::Correct => correct += 1,
+                Outcome::Unknown => unknown += 1,
+                Outcome::Wrong => {
+                    wrong += 1;
+                    eprintln!(
+                        "{} day {} part {}: expected {}, got {}",
+                        year.year,
+                        day.day,
+                        part,
+                        expected.unwrap(),
+                        actual
+                    );
+                }
+            }
+        }
+    }
+
+    println!(
+        "{}: {} correct, {} wrong, {} without a recorded answer, {} days without a cached input",
+        year.year, correct, wrong, unknown, skipped
+    );
+
+    wrong == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn answers_are_compared_with_recorded_ones() {
+        let recorded = Answer::from(42);
+
+        assert_eq!(Outcome::Correct, check(Some(&recorded), &Answer::from(42)));
+        assert_eq!(Outcome::Wrong, check(Some(&recorded), &Answer::from(41)));
+        assert_eq!(Outcome::Wrong, check(Some(&recorded), &Answer::from("42")));
+        assert_eq!(Outcome::Unknown, check(None, &Answer::from(42)));
+    }
+}
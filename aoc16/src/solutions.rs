@@ -23,4 +23,4 @@ pub mod day22;
 pub mod day23;
 pub mod day24;
 pub mod day25;
-mod computer;
\ No newline at end of file
+pub mod computer;
\ No newline at end of file
@@ -1,155 +1,390 @@
-use std::collections::HashMap;
+use std::{fmt, str::FromStr};
 
 use itertools::Itertools;
+use util::parse::{self, ParseError};
+
+pub use debugger::{Debugger, Stop, TraceStep};
+use optimiser::Fused;
+
+mod debugger;
+pub mod decompiler;
+mod optimiser;
+
+/// The registers `a` to `d`.
+const REGISTERS: usize = 4;
 
 pub struct Computer {
     instructions: Vec<Operation>,
-    registers: HashMap<String, i32>,
-    output: Vec<i32>,
+    /// Loops which are executed in one step, by the index of their first instruction.
+    fused: Vec<Option<Fused>>,
+    /// Whether loops are fused at all, see `without_fusion`.
+    fusion: bool,
+    /// Number of instructions changed by `tgl`, so states are only compared within the same
+    /// program.
+    toggles: usize,
+    registers: [i64; REGISTERS],
+    /// The value of the last `out` until it is taken by `next_output`.
+    output: Option<i64>,
     ic: usize,
-    halted: bool,
+    /// Only set up once it is asked for, so normal runs don't pay for the bookkeeping.
+    debugger: Option<Debugger>,
+    /// Whether the program paused at the breakpoint of the next instruction, so resuming
+    /// executes it instead of pausing again.
+    at_breakpoint: bool,
 }
 
 impl Computer {
     pub fn new(instructions: Vec<Operation>) -> Self {
         Computer {
+            fused: optimiser::optimise(&instructions),
+            fusion: true,
+            toggles: 0,
             instructions,
-            registers: HashMap::new(),
-            output: Vec::from([1]), // initialize with first value to be able to acces at 2nd from the back after inserting immediately
+            registers: [0; REGISTERS],
+            output: None,
             ic: 0,
-            halted: false,
+            debugger: None,
+            at_breakpoint: false,
         }
     }
 
-    pub fn get(&self, key: &str) -> Option<i32> {
-        self.registers.get(key).copied()
+    /// Execute every instruction on its own, so breakpoints and watches inside loops pause
+    /// and every execution counts as a hit. Runs much slower on the puzzle inputs.
+    pub fn without_fusion(mut self) -> Self {
+        self.fusion = false;
+        self.fused.fill(None);
+        self
+    }
+
+    /// The value of the register named by the letter.
+    ///
+    /// Panics if there is no such register.
+    pub fn get(&self, register: char) -> i64 {
+        self.registers[register_index(register)]
+    }
+
+    /// Set the register named by the letter.
+    ///
+    /// Panics if there is no such register.
+    pub fn insert(&mut self, register: char, value: i64) {
+        self.registers[register_index(register)] = value;
+    }
+
+    /// The index of the next instruction.
+    pub fn ic(&self) -> usize {
+        self.ic
     }
 
-    pub fn insert(&mut self, key: String, value: i32) {
-        self.registers.insert(key, value);
+    /// The debugger, which is attached on first use. Breakpoints and watches only pause
+    /// `step` and `resume`, while hits and the trace are recorded by every run.
+    /// Fused loops run at once, use `without_fusion` to pause inside them.
+    pub fn debugger(&mut self) -> &mut Debugger {
+        let instructions = self.instructions.len();
+        self.debugger
+            .get_or_insert_with(|| Debugger::new(instructions))
     }
 
     pub fn run(&mut self) {
-        while let Some(instruction) = self.instructions.get(self.ic) {
-            self.execute(&instruction.clone());
+        while self.ic < self.instructions.len() {
+            self.advance();
+        }
+    }
+
+    /// Run until the next `out`, `None` if the program ends before.
+    pub fn next_output(&mut self) -> Option<i64> {
+        while self.is_running() {
+            self.advance();
+            if let Some(output) = self.output.take() {
+                return Some(output);
+            }
+        }
+
+        None
+    }
+
+    /// The values of all upcoming `out`s, ending with the program.
+    pub fn outputs(&mut self) -> impl Iterator<Item = i64> + '_ {
+        std::iter::from_fn(|| self.next_output())
+    }
+
+    /// The index of the next instruction, the registers and the number of toggled
+    /// instructions. Two equal states run the same program, so they do the same from then on.
+    pub fn state(&self) -> (usize, [i64; REGISTERS], usize) {
+        (self.ic, self.registers, self.toggles)
+    }
+
+    /// Execute a single instruction, or a whole fused loop.
+    /// Returns why the program should pause before the next one, if it should.
+    pub fn step(&mut self) -> Option<Stop> {
+        if !self.is_running() {
+            return Some(Stop::Halted);
+        }
+
+        let stop = self.advance().or_else(|| self.breakpoint());
+        stop.or((!self.is_running()).then_some(Stop::Halted))
+    }
+
+    /// Execute instructions until a breakpoint or watch is hit or the program halts.
+    /// Pauses before an instruction with a breakpoint, unless the program already paused there.
+    pub fn resume(&mut self) -> Stop {
+        loop {
+            if !self.at_breakpoint {
+                if let Some(stop) = self.breakpoint() {
+                    return stop;
+                }
+            }
+            if !self.is_running() {
+                return Stop::Halted;
+            }
+            if let Some(stop) = self.advance() {
+                return stop;
+            }
         }
     }
 
-    pub fn run_to_output_length(&mut self, length: usize) -> bool {
-        while !self.halted && self.output.len() < length {
-            if let Some(instruction) = self.instructions.get(self.ic) {
-                self.execute(&instruction.clone());
+    /// Pause if the next instruction has a breakpoint.
+    fn breakpoint(&mut self) -> Option<Stop> {
+        let debugger = self.debugger.as_ref()?;
+
+        self.at_breakpoint = debugger.has_breakpoint(self.ic);
+        self.at_breakpoint.then_some(Stop::Breakpoint(self.ic))
+    }
+
+    fn is_running(&self) -> bool {
+        self.ic < self.instructions.len()
+    }
+
+    fn advance(&mut self) -> Option<Stop> {
+        let ic = self.ic;
+        let operation = self.instructions[ic];
+        let before = self.registers;
+        self.at_breakpoint = false;
+
+        let fused = self.execute_fused();
+        if !fused {
+            self.execute(&operation);
+        }
+
+        let step = TraceStep {
+            ic,
+            operation,
+            fused,
+            registers: self.registers,
+        };
+        self.debugger
+            .as_mut()
+            .and_then(|debugger| debugger.record(step, &before))
+    }
+
+    /// Execute the loop starting at the current instruction at once.
+    /// Loops with counters which are not positive run differently or forever, so they are
+    /// left to the single instructions.
+    fn execute_fused(&mut self) -> bool {
+        let Some(fused) = &self.fused[self.ic] else {
+            return false;
+        };
+
+        match *fused {
+            Fused::Add { target, counter } => {
+                let count = self.registers[counter as usize];
+                if count <= 0 {
+                    return false;
+                }
+
+                self.registers[target as usize] += count;
+                self.registers[counter as usize] = 0;
+            }
+            Fused::Multiply {
+                target,
+                factor,
+                inner,
+                counter,
+            } => {
+                let factor = self.registers[factor as usize];
+                let count = self.registers[counter as usize];
+                if factor <= 0 || count <= 0 {
+                    return false;
+                }
+
+                self.registers[target as usize] += factor * count;
+                self.registers[inner as usize] = 0;
+                self.registers[counter as usize] = 0;
             }
         }
+        self.ic += fused.len();
 
-        self.check_output()
+        true
     }
 
     fn execute(&mut self, instruction: &Operation) {
         let offset = self.execute_instruction(instruction);
-        if offset < 0 {
-            self.ic -= offset.unsigned_abs() as usize;
-        } else {
-            self.ic += offset as usize;
-        }
+        // jumping before the first instruction halts the program like jumping past the last
+        self.ic = self
+            .relative_index(offset)
+            .unwrap_or(self.instructions.len());
     }
 
-    fn execute_instruction(&mut self, instruction: &Operation) -> i32 {
-        match instruction {
+    fn execute_instruction(&mut self, instruction: &Operation) -> i64 {
+        match *instruction {
             Operation::Cpy(x, y) => {
-                let value = self.get_immediate_or_register_value(x);
-                if self.is_valid_register(y) {
-                    *self.registers.entry(y.to_string()).or_default() = value;
+                let value = self.value(x);
+                if let Operand::Reg(y) = y {
+                    self.registers[y as usize] = value;
+                }
+            }
+            Operation::Inc(x) => {
+                if let Operand::Reg(x) = x {
+                    self.registers[x as usize] += 1;
+                }
+            }
+            Operation::Dec(x) => {
+                if let Operand::Reg(x) = x {
+                    self.registers[x as usize] -= 1;
                 }
             }
-            Operation::Inc(x) => *self.registers.entry(x.to_string()).or_default() += 1,
-            Operation::Dec(x) => *self.registers.entry(x.to_string()).or_default() -= 1,
             Operation::Jnz(x, y) => {
-                if self.get_immediate_or_register_value(x) != 0 {
-                    return self.get_immediate_or_register_value(y);
+                if self.value(x) != 0 {
+                    return self.value(y);
                 }
             }
             Operation::Tgl(x) => {
-                let offset = self.get_immediate_or_register_value(x);
-                if let Some(instr) = self.instructions.get((offset + self.ic as i32) as usize) {
-                    let toggled = self.toggle(instr);
-                    self.instructions[(offset + self.ic as i32) as usize] = toggled;
+                let target = self.relative_index(self.value(x));
+                if let Some(target) = target.filter(|&i| i < self.instructions.len()) {
+                    self.instructions[target] = self.instructions[target].toggled();
+                    self.toggles += 1;
+                    // the toggled instruction may start or end a loop
+                    if self.fusion {
+                        self.fused = optimiser::optimise(&self.instructions);
+                    }
                 }
             }
             Operation::Out(x) => {
-                let value = self.get_immediate_or_register_value(x);
-                self.output.push(value);
-                let last_two = &self.output[(self.output.len() - 2)..];
-                if ![0, 1].contains(&value) || last_two[0] == last_two[1] {
-                    self.halted = true;
-                }
+                self.output = Some(self.value(x));
             }
             Operation::Mul(x, y, z) => {
-                let y: i32 = self.get_immediate_or_register_value(y);
-                let z: i32 = self.get_immediate_or_register_value(z);
-                self.registers.insert(x.to_string(), y * z);
+                let value = self.value(y) * self.value(z);
+                if let Operand::Reg(x) = x {
+                    self.registers[x as usize] = value;
+                }
             }
             Operation::Noop => {}
         }
         1
     }
 
-    fn get_immediate_or_register_value(&self, x: &str) -> i32 {
-        match x.parse::<i32>() {
-            Ok(val) => val,
-            Err(_) => *self.registers.get(x).unwrap_or(&0),
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Reg(register) => self.registers[register as usize],
+            Operand::Imm(value) => value,
         }
     }
 
-    fn is_valid_register(&self, identifier: &str) -> bool {
-        identifier.parse::<i32>().is_err()
+    /// The index of the instruction `offset` away from the current one, if it is not negative.
+    fn relative_index(&self, offset: i64) -> Option<usize> {
+        usize::try_from(self.ic as i64 + offset).ok()
     }
+}
+
+/// The position of the register named by the letter in the register array.
+///
+/// Panics if there is no such register.
+fn register_index(register: char) -> usize {
+    match register {
+        'a'..='d' => register as usize - 'a' as usize,
+        _ => panic!("there is no register {}", register),
+    }
+}
+
+/// An argument of an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operand {
+    /// The index of a register, `0` for `a` up to `3` for `d`.
+    Reg(u8),
+    Imm(i64),
+}
 
-    fn toggle(&self, operation: &Operation) -> Operation {
-        match operation {
-            Operation::Inc(x) => Operation::Dec(x.clone()),
-            Operation::Dec(x) | Operation::Tgl(x) | Operation::Out(x) => Operation::Inc(x.clone()),
-            Operation::Jnz(x, y) => Operation::Cpy(x.clone(), y.to_string()),
-            Operation::Cpy(x, y) => Operation::Jnz(x.clone(), y.clone()),
-            Operation::Noop | Operation::Mul(_, _, _) => unreachable!(),
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Reg(register) => write!(f, "{}", (b'a' + register) as char),
+            Operand::Imm(value) => write!(f, "{}", value),
         }
     }
+}
+
+impl FromStr for Operand {
+    type Err = ParseError;
 
-    fn check_output(&self) -> bool {
-        self.output.iter().all(|&n| n == 0 || n == 1)
-            && (1..self.output.len()).all(|i| self.output[i] != self.output[i - 1])
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let mut chars = text.chars();
+        match (chars.next(), chars.next()) {
+            (Some(register @ 'a'..='d'), None) => Ok(Operand::Reg(register_index(register) as u8)),
+            _ => parse::field(text).map(Operand::Imm),
+        }
     }
 }
 
-#[derive(Clone, Debug)]
+/// Instructions which write to an immediate value instead of a register are skipped, as they
+/// can only be created by toggling.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum Operation {
-    Cpy(String, String),
-    Inc(String),
-    Dec(String),
-    Jnz(String, String),
-    Tgl(String),
-    Out(String),
-    Mul(String, String, String),
+    Cpy(Operand, Operand),
+    Inc(Operand),
+    Dec(Operand),
+    Jnz(Operand, Operand),
+    Tgl(Operand),
+    Out(Operand),
+    Mul(Operand, Operand, Operand),
     Noop,
 }
 
 impl Operation {
-    pub fn from(line: &str) -> Self {
+    pub fn from(line: &str) -> Result<Self, ParseError> {
         let parts = line.split_whitespace().collect_vec();
-        match parts[0] {
-            "cpy" => Operation::Cpy(parts[1].to_string(), parts[2].to_string()),
-            "inc" => Operation::Inc(parts[1].to_string()),
-            "dec" => Operation::Dec(parts[1].to_string()),
-            "jnz" => Operation::Jnz(parts[1].to_string(), parts[2].parse().unwrap()),
-            "tgl" => Operation::Tgl(parts[1].to_string()),
-            "out" => Operation::Out(parts[1].to_string()),
-            "mul" => Operation::Mul(
-                parts[1].to_string(),
-                parts[2].to_string(),
-                parts[3].to_string(),
-            ),
-            "noop" => Operation::Noop,
-            _ => unreachable!(),
+        let operand = |index: usize| match parts.get(index) {
+            Some(part) => part.parse(),
+            None => Err(ParseError::new(line, "missing operand")),
+        };
+
+        let operation = match parts.first() {
+            Some(&"cpy") => Operation::Cpy(operand(1)?, operand(2)?),
+            Some(&"inc") => Operation::Inc(operand(1)?),
+            Some(&"dec") => Operation::Dec(operand(1)?),
+            Some(&"jnz") => Operation::Jnz(operand(1)?, operand(2)?),
+            Some(&"tgl") => Operation::Tgl(operand(1)?),
+            Some(&"out") => Operation::Out(operand(1)?),
+            Some(&"mul") => Operation::Mul(operand(1)?, operand(2)?, operand(3)?),
+            Some(&"noop") => Operation::Noop,
+            _ => return Err(ParseError::new(line, "unknown instruction")),
+        };
+
+        Ok(operation)
+    }
+
+    /// The instruction after `tgl` hit it.
+    /// The multiplication and `noop` are not part of the puzzle and stay as they are.
+    fn toggled(&self) -> Self {
+        match *self {
+            Operation::Inc(x) => Operation::Dec(x),
+            Operation::Dec(x) | Operation::Tgl(x) | Operation::Out(x) => Operation::Inc(x),
+            Operation::Jnz(x, y) => Operation::Cpy(x, y),
+            Operation::Cpy(x, y) => Operation::Jnz(x, y),
+            Operation::Mul(..) | Operation::Noop => *self,
+        }
+    }
+}
+
+impl fmt::Display for Operation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operation::Cpy(x, y) => write!(f, "cpy {} {}", x, y),
+            Operation::Inc(x) => write!(f, "inc {}", x),
+            Operation::Dec(x) => write!(f, "dec {}", x),
+            Operation::Jnz(x, y) => write!(f, "jnz {} {}", x, y),
+            Operation::Tgl(x) => write!(f, "tgl {}", x),
+            Operation::Out(x) => write!(f, "out {}", x),
+            Operation::Mul(x, y, z) => write!(f, "mul {} {} {}", x, y, z),
+            Operation::Noop => write!(f, "noop"),
         }
     }
 }
@@ -158,15 +393,14 @@ impl Operation {
 mod tests {
     use super::*;
 
+    fn program(source: &str) -> Vec<Operation> {
+        parse::map_lines(source, Operation::from).unwrap()
+    }
+
     #[test]
     fn offset_calculated_correctly() {
         let mut computer = Computer::new(vec![]);
-        let instructions = vec![
-            Operation::Inc("a".to_string()),
-            Operation::Jnz("a".to_string(), "10".to_string()),
-            Operation::Jnz("b".to_string(), "-10".to_string()),
-            Operation::Jnz("a".to_string(), "-10".to_string()),
-        ];
+        let instructions = program("inc a\njnz a 10\njnz b -10\njnz a -10");
 
         assert_eq!(1, computer.execute_instruction(&instructions[0]));
         assert_eq!(10, computer.execute_instruction(&instructions[1]));
@@ -177,20 +411,62 @@ mod tests {
     #[test]
     fn registers_manipulated_correctly() {
         let mut computer = Computer::new(vec![]);
-        let instructions = vec![
-            Operation::Inc("a".to_string()),
-            Operation::Dec("b".to_string()),
-            Operation::Cpy("10".to_string(), "c".to_string()),
-            Operation::Cpy("b".to_string(), "d".to_string()),
-        ];
+        let instructions = program("inc a\ndec b\ncpy 10 c\ncpy b d");
 
         instructions.iter().for_each(|instr| {
             computer.execute_instruction(instr);
         });
 
-        assert_eq!(1, computer.get("a").unwrap());
-        assert_eq!(-1, computer.get("b").unwrap());
-        assert_eq!(10, computer.get("c").unwrap());
-        assert_eq!(-1, computer.get("d").unwrap());
+        assert_eq!(1, computer.get('a'));
+        assert_eq!(-1, computer.get('b'));
+        assert_eq!(10, computer.get('c'));
+        assert_eq!(-1, computer.get('d'));
+    }
+
+    #[test]
+    fn operands_are_decoded() {
+        assert_eq!(
+            Ok(Operation::Cpy(Operand::Imm(-16), Operand::Reg(2))),
+            Operation::from("cpy -16 c")
+        );
+        assert_eq!(
+            Ok(Operation::Jnz(Operand::Reg(3), Operand::Reg(0))),
+            Operation::from("jnz d a")
+        );
+    }
+
+    #[test]
+    fn invalid_instructions_are_errors() {
+        for line in ["", "add a b", "inc e", "cpy 1", "jnz 1 -x"] {
+            assert!(Operation::from(line).is_err(), "{}", line);
+        }
+
+        let error = parse::map_lines("inc a\ndec b\nhlt", Operation::from).unwrap_err();
+        assert_eq!(Some(3), error.line());
+    }
+
+    #[test]
+    fn registers_hold_large_values() {
+        let mut computer = Computer::new(program("mul a a a\ninc a"));
+        computer.insert('a', 3_000_000_000);
+        computer.run();
+
+        assert_eq!(9_000_000_000_000_000_001, computer.get('a'));
+    }
+
+    #[test]
+    fn outputs_are_yielded_until_the_program_ends() {
+        let mut computer = Computer::new(program("cpy 3 a\nout a\ndec a\njnz a -2\nout 7"));
+
+        assert_eq!(vec![3, 2, 1, 7], computer.outputs().collect::<Vec<_>>());
+        assert_eq!(None, computer.next_output());
+    }
+
+    #[test]
+    fn toggled_instructions_writing_immediates_are_skipped() {
+        let mut computer = Computer::new(program("tgl 1\njnz 1 2\ninc a"));
+        computer.run();
+
+        assert_eq!(1, computer.get('a'));
     }
 }
@@ -0,0 +1,285 @@
+use std::collections::{HashSet, VecDeque};
+
+use itertools::Itertools;
+
+use super::{register_index, Operation, REGISTERS};
+
+/// The reason a debugged program paused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Stop {
+    /// The program ended.
+    Halted,
+    /// The next instruction to execute has a breakpoint.
+    Breakpoint(usize),
+    /// A watched register changed its value.
+    Watch { register: char, old: i64, new: i64 },
+}
+
+/// An executed instruction of the trace.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TraceStep {
+    pub ic: usize,
+    pub operation: Operation,
+    /// Whether the whole loop starting at the instruction was executed at once.
+    pub fused: bool,
+    /// The registers after the instruction.
+    pub registers: [i64; REGISTERS],
+}
+
+/// Breakpoints, register watches and the profile of a `Computer`.
+pub struct Debugger {
+    breakpoints: HashSet<usize>,
+    watches: [bool; REGISTERS],
+    /// Number of executions by instruction index.
+    hits: Vec<usize>,
+    trace: VecDeque<TraceStep>,
+    trace_capacity: usize,
+}
+
+impl Debugger {
+    pub(super) fn new(instructions: usize) -> Self {
+        Debugger {
+            breakpoints: HashSet::new(),
+            watches: [false; REGISTERS],
+            hits: vec![0; instructions],
+            trace: VecDeque::new(),
+            trace_capacity: 0,
+        }
+    }
+
+    /// Pause before the instruction at the index is executed.
+    pub fn add_breakpoint(&mut self, ic: usize) {
+        self.breakpoints.insert(ic);
+    }
+
+    pub fn remove_breakpoint(&mut self, ic: usize) {
+        self.breakpoints.remove(&ic);
+    }
+
+    pub(super) fn has_breakpoint(&self, ic: usize) -> bool {
+        self.breakpoints.contains(&ic)
+    }
+
+    /// Pause whenever the register named by the letter changes.
+    ///
+    /// Panics if there is no such register.
+    pub fn watch(&mut self, register: char) {
+        self.watches[register_index(register)] = true;
+    }
+
+    pub fn unwatch(&mut self, register: char) {
+        self.watches[register_index(register)] = false;
+    }
+
+    /// Keep the last `capacity` executed instructions, no trace is kept for `0`.
+    pub fn record_trace(&mut self, capacity: usize) {
+        self.trace_capacity = capacity;
+        while self.trace.len() > capacity {
+            self.trace.pop_front();
+        }
+    }
+
+    /// How often each instruction was executed. A fused loop counts as one execution of its
+    /// first instruction, unless the computer runs `without_fusion`.
+    pub fn hits(&self) -> &[usize] {
+        &self.hits
+    }
+
+    /// The indices of the `count` most executed instructions with their hits.
+    pub fn hottest(&self, count: usize) -> Vec<(usize, usize)> {
+        self.hits
+            .iter()
+            .copied()
+            .enumerate()
+            .filter(|&(_, hits)| hits > 0)
+            .sorted_by_key(|&(ic, hits)| (std::cmp::Reverse(hits), ic))
+            .take(count)
+            .collect()
+    }
+
+    pub fn trace(&self) -> impl Iterator<Item = &TraceStep> {
+        self.trace.iter()
+    }
+
+    /// The recorded trace with one line per instruction, like
+    /// `   7  jnz c -2      a=5 b=0 c=2 d=0`.
+    pub fn dump_trace(&self) -> String {
+        self.trace
+            .iter()
+            .map(|step| {
+                let registers = (0..REGISTERS)
+                    .map(|r| format!("{}={}", (b'a' + r as u8) as char, step.registers[r]))
+                    .join(" ");
+                let fused = if step.fused { " (fused)" } else { "" };
+
+                format!(
+                    "{:>4}  {:<12}  {}{}",
+                    step.ic,
+                    step.operation.to_string(),
+                    registers,
+                    fused
+                )
+            })
+            .join("\n")
+    }
+
+    /// Note the execution of an instruction and find out whether a watched register changed.
+    pub(super) fn record(&mut self, step: TraceStep, before: &[i64; REGISTERS]) -> Option<Stop> {
+        if let Some(hits) = self.hits.get_mut(step.ic) {
+            *hits += 1;
+        }
+        if self.trace_capacity > 0 {
+            if self.trace.len() == self.trace_capacity {
+                self.trace.pop_front();
+            }
+            self.trace.push_back(step);
+        }
+
+        (0..REGISTERS)
+            .find(|&r| self.watches[r] && before[r] != step.registers[r])
+            .map(|r| Stop::Watch {
+                register: (b'a' + r as u8) as char,
+                old: before[r],
+                new: step.registers[r],
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use util::parse;
+
+    use super::super::Computer;
+    use super::*;
+
+    /// Counts `a` down from 3 with a loop which can't be fused.
+    const COUNTDOWN: &str = "cpy 3 a\ndec a\ninc b\ninc b\njnz a -3\ncpy 7 c";
+
+    fn computer(source: &str) -> Computer {
+        Computer::new(parse::map_lines(source, Operation::from).unwrap())
+    }
+
+    #[test]
+    fn breakpoints_pause_before_the_instruction() {
+        let mut computer = computer(COUNTDOWN);
+        computer.debugger().add_breakpoint(4);
+
+        assert_eq!(Stop::Breakpoint(4), computer.resume());
+        assert_eq!(2, computer.get('a'));
+        assert_eq!(Stop::Breakpoint(4), computer.resume());
+        assert_eq!(1, computer.get('a'));
+
+        computer.debugger().remove_breakpoint(4);
+        assert_eq!(Stop::Halted, computer.resume());
+        assert_eq!(6, computer.get('b'));
+        assert_eq!(Some(Stop::Halted), computer.step());
+    }
+
+    #[test]
+    fn breakpoints_pause_before_the_first_instruction() {
+        let mut computer = computer(COUNTDOWN);
+        computer.debugger().add_breakpoint(0);
+
+        assert_eq!(Stop::Breakpoint(0), computer.resume());
+        assert_eq!((0, 0), (computer.ic(), computer.get('a')));
+        assert_eq!(Stop::Halted, computer.resume());
+    }
+
+    #[test]
+    fn breakpoints_after_a_watch_pause_on_the_next_resume() {
+        let mut computer = computer(COUNTDOWN);
+        computer.debugger().watch('a');
+        computer.debugger().add_breakpoint(1);
+
+        assert_eq!(
+            Stop::Watch {
+                register: 'a',
+                old: 0,
+                new: 3
+            },
+            computer.resume()
+        );
+        assert_eq!(Stop::Breakpoint(1), computer.resume());
+        assert_eq!(1, computer.ic());
+    }
+
+    #[test]
+    fn watched_registers_pause_on_changes() {
+        let mut computer = computer(COUNTDOWN);
+        computer.debugger().watch('c');
+
+        assert_eq!(
+            Stop::Watch {
+                register: 'c',
+                old: 0,
+                new: 7
+            },
+            computer.resume()
+        );
+    }
+
+    #[test]
+    fn single_steps_follow_jumps() {
+        let mut computer = computer(COUNTDOWN);
+        for _ in 0..5 {
+            assert_eq!(None, computer.step());
+        }
+
+        assert_eq!(1, computer.ic());
+    }
+
+    #[test]
+    fn hits_are_counted() {
+        let mut computer = computer(COUNTDOWN);
+        computer.debugger();
+        computer.run();
+
+        let debugger = computer.debugger();
+        assert_eq!(&[1, 3, 3, 3, 3, 1], debugger.hits());
+        assert_eq!(vec![(1, 3), (2, 3)], debugger.hottest(2));
+    }
+
+    #[test]
+    fn fused_loops_are_traced_once() {
+        let mut computer = computer("cpy 4 b\ninc a\ndec b\njnz b -2");
+        computer.debugger().record_trace(10);
+        computer.run();
+
+        let trace: Vec<&TraceStep> = computer.debugger().trace().collect();
+        assert_eq!(2, trace.len());
+        assert!(trace[1].fused);
+        assert_eq!(
+            "   0  cpy 4 b       a=0 b=4 c=0 d=0\n   1  inc a         a=4 b=0 c=0 d=0 (fused)",
+            computer.debugger().dump_trace()
+        );
+    }
+
+    #[test]
+    fn loops_are_debugged_without_fusion() {
+        let source = "cpy 4 b\ninc a\ndec b\njnz b -2";
+        let mut fused = computer(source);
+        fused.debugger().add_breakpoint(2);
+        assert_eq!(Stop::Halted, fused.resume());
+        assert_eq!(&[1, 1, 0, 0], fused.debugger().hits());
+
+        let mut computer = computer(source).without_fusion();
+        computer.debugger().add_breakpoint(2);
+        assert_eq!(Stop::Breakpoint(2), computer.resume());
+        assert_eq!((1, 4), (computer.get('a'), computer.get('b')));
+
+        computer.debugger().remove_breakpoint(2);
+        assert_eq!(Stop::Halted, computer.resume());
+        assert_eq!(4, computer.get('a'));
+        assert_eq!(&[1, 4, 4, 4], computer.debugger().hits());
+    }
+
+    #[test]
+    fn traces_keep_the_last_steps() {
+        let mut computer = computer(COUNTDOWN);
+        computer.debugger().record_trace(2);
+        computer.run();
+
+        let ics: Vec<usize> = computer.debugger().trace().map(|step| step.ic).collect();
+        assert_eq!(vec![4, 5], ics);
+    }
+}
@@ -0,0 +1,482 @@
+//! Reading assembunny programs: the control flow graph, its loops and structured pseudo-code.
+//! The analysis is static, so instructions toggled while running are only flagged.
+
+use std::{
+    collections::{BTreeSet, HashSet},
+    ops::Range,
+};
+
+use itertools::Itertools;
+
+use super::optimiser::{self, Fused};
+use super::{Operand, Operation};
+
+/// Instructions which are only entered at the first and left after the last one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Block {
+    pub instructions: Range<usize>,
+    /// The first instructions of the blocks which can follow. The length of the program
+    /// stands for its end.
+    pub successors: Vec<usize>,
+    /// Whether the block ends with a jump by a register, which can go anywhere.
+    pub dynamic_jump: bool,
+}
+
+/// Instructions repeated by a backward `jnz`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Loop {
+    pub start: usize,
+    /// The index of the `jnz` jumping back to `start`.
+    pub end: usize,
+    /// `None` if the loop never ends.
+    pub condition: Option<Operand>,
+}
+
+/// Where a `jnz` goes when it jumps.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Jump {
+    Never,
+    /// `condition` is `None` for unconditional jumps. Jumps out of the program end it, so
+    /// their target is the length of the program.
+    Static {
+        condition: Option<Operand>,
+        target: usize,
+    },
+    Dynamic {
+        condition: Option<Operand>,
+        offset: Operand,
+    },
+}
+
+fn jump(instructions: &[Operation], ic: usize) -> Option<Jump> {
+    let Operation::Jnz(condition, offset) = instructions[ic] else {
+        return None;
+    };
+    let condition = match condition {
+        Operand::Imm(0) => return Some(Jump::Never),
+        Operand::Imm(_) => None,
+        Operand::Reg(_) => Some(condition),
+    };
+
+    let jump = match offset {
+        Operand::Imm(offset) => Jump::Static {
+            condition,
+            target: usize::try_from(ic as i64 + offset)
+                .map_or(instructions.len(), |target| target.min(instructions.len())),
+        },
+        Operand::Reg(_) => Jump::Dynamic { condition, offset },
+    };
+
+    Some(jump)
+}
+
+/// The basic blocks of the program in order.
+pub fn control_flow(instructions: &[Operation]) -> Vec<Block> {
+    let mut leaders = BTreeSet::from([0]);
+    for ic in 0..instructions.len() {
+        if let Some(jump) = jump(instructions, ic) {
+            leaders.insert(ic + 1);
+            if let Jump::Static { target, .. } = jump {
+                leaders.insert(target);
+            }
+        }
+    }
+    leaders.retain(|&leader| leader < instructions.len());
+
+    leaders
+        .iter()
+        .chain([&instructions.len()])
+        .tuple_windows()
+        .map(|(&start, &end)| {
+            let (successors, dynamic_jump) = match jump(instructions, end - 1) {
+                Some(Jump::Static {
+                    condition: None,
+                    target,
+                }) => (vec![target], false),
+                Some(Jump::Static { target, .. }) if target != end => (vec![end, target], false),
+                Some(Jump::Dynamic {
+                    condition: None, ..
+                }) => (vec![], true),
+                Some(Jump::Dynamic { .. }) => (vec![end], true),
+                _ => (vec![end], false),
+            };
+
+            Block {
+                instructions: start..end,
+                successors,
+                dynamic_jump,
+            }
+        })
+        .collect()
+}
+
+/// All loops by their start, outer loops before inner ones with the same start.
+/// The loops may overlap without nesting.
+pub fn loops(instructions: &[Operation]) -> Vec<Loop> {
+    (0..instructions.len())
+        .filter_map(|ic| match jump(instructions, ic)? {
+            Jump::Static { condition, target } if target <= ic => Some(Loop {
+                start: target,
+                end: ic,
+                condition,
+            }),
+            _ => None,
+        })
+        .sorted_by_key(|l| (l.start, usize::MAX - l.end))
+        .collect()
+}
+
+/// The instructions each `tgl` may change, by the index of the `tgl`.
+/// The offset of a `tgl` by a register is only known when running. It is assumed to be not
+/// negative, like in the puzzles, so everything from the `tgl` to the end may change.
+pub fn toggle_targets(instructions: &[Operation]) -> Vec<(usize, Range<usize>)> {
+    (0..instructions.len())
+        .filter_map(|ic| match instructions[ic] {
+            Operation::Tgl(Operand::Imm(offset)) => {
+                let target = usize::try_from(ic as i64 + offset).ok()?;
+                (target < instructions.len()).then_some((ic, target..target + 1))
+            }
+            Operation::Tgl(Operand::Reg(_)) => Some((ic, ic..instructions.len())),
+            _ => None,
+        })
+        .collect()
+}
+
+/// One instruction per line with its index, where its jump goes and what it toggles.
+pub fn disassemble(instructions: &[Operation]) -> String {
+    let toggled = toggled(instructions);
+    let toggles = toggle_targets(instructions);
+
+    (0..instructions.len())
+        .map(|ic| {
+            let mut notes = Vec::new();
+            match jump(instructions, ic) {
+                Some(Jump::Static { target, .. }) => {
+                    notes.push(format!("-> {}", label(target, instructions)))
+                }
+                Some(Jump::Dynamic { .. }) => notes.push("-> ?".to_string()),
+                _ => {}
+            }
+            if let Some((_, targets)) = toggles.iter().find(|(tgl, _)| *tgl == ic) {
+                notes.push(format!("toggles {:?}", targets));
+            }
+            if toggled[ic] {
+                notes.push("self-modifying".to_string());
+            }
+
+            format!(
+                "{:>4}  {:<12}  {}",
+                ic,
+                instructions[ic].to_string(),
+                notes.join(", ")
+            )
+            .trim_end()
+            .to_string()
+        })
+        .join("\n")
+}
+
+/// Structured pseudo-code of the program, like `a += b * d` for a multiplication loop.
+/// Loops which nest become `do { .. } while x != 0`, other jumps become `goto`s.
+/// Statements from instructions which may be toggled are marked.
+pub fn decompile(instructions: &[Operation]) -> String {
+    let fused = fusable(instructions);
+    let loops = structured_loops(instructions, &fused);
+    let loop_ends: HashSet<usize> = loops.iter().map(|l| l.end).collect();
+    let toggled = toggled(instructions);
+
+    let mut statements: Vec<(usize, usize, String)> = Vec::new();
+    let mut labels = BTreeSet::new();
+    let mut depth = 0;
+    let mut ic = 0;
+    while ic < instructions.len() {
+        for l in loops.iter().filter(|l| l.start == ic) {
+            let opening = match l.condition {
+                Some(_) => "do {",
+                None => "loop {",
+            };
+            statements.push((ic, depth, opening.to_string()));
+            depth += 1;
+        }
+
+        match &fused[ic] {
+            Some(fused) => {
+                let is_toggled = toggled[ic..ic + fused.len()].contains(&true);
+                for statement in fused_statements(fused) {
+                    statements.push((ic, depth, mark(statement, is_toggled)));
+                }
+                ic += fused.len();
+            }
+            None => {
+                if !loop_ends.contains(&ic) {
+                    let statement = statement(instructions, ic, &mut labels);
+                    statements.push((ic, depth, mark(statement, toggled[ic])));
+                }
+                ic += 1;
+            }
+        }
+
+        for l in loops.iter().rev().filter(|l| l.end == ic - 1) {
+            depth -= 1;
+            let closing = match l.condition {
+                Some(condition) => format!("}} while {} != 0", condition),
+                None => "}".to_string(),
+            };
+            statements.push((l.end, depth, mark(closing, toggled[l.end])));
+        }
+    }
+
+    let mut lines = toggle_targets(instructions)
+        .into_iter()
+        .map(|(tgl, targets)| format!("// tgl at {} may change instructions {:?}", tgl, targets))
+        .collect_vec();
+    let mut labelled = HashSet::new();
+    for (ic, depth, statement) in statements {
+        if labels.contains(&ic) && labelled.insert(ic) {
+            lines.push(format!("{}:", label(ic, instructions)));
+        }
+        lines.push(format!("{}{}", "    ".repeat(depth + 1), statement));
+    }
+
+    lines.join("\n")
+}
+
+/// Whether each instruction may be changed by a `tgl`.
+fn toggled(instructions: &[Operation]) -> Vec<bool> {
+    let mut toggled = vec![false; instructions.len()];
+    for (_, targets) in toggle_targets(instructions) {
+        toggled[targets].fill(true);
+    }
+
+    toggled
+}
+
+/// The loops the optimiser fuses, unless a jump from outside goes into the middle of them.
+fn fusable(instructions: &[Operation]) -> Vec<Option<Fused>> {
+    let jumps: Vec<(usize, usize)> = (0..instructions.len())
+        .filter_map(|ic| match jump(instructions, ic)? {
+            Jump::Static { target, .. } => Some((ic, target)),
+            _ => None,
+        })
+        .collect();
+
+    optimiser::optimise(instructions)
+        .into_iter()
+        .enumerate()
+        .map(|(start, fused)| {
+            let inside = start..start + fused?.len();
+            let is_entered = jumps.iter().any(|(ic, target)| {
+                !inside.contains(ic) && inside.contains(target) && *target != start
+            });
+
+            (!is_entered).then_some(fused?)
+        })
+        .collect()
+}
+
+/// The loops which nest with each other and with the fused loops, so they can be written as
+/// blocks.
+fn structured_loops(instructions: &[Operation], fused: &[Option<Fused>]) -> Vec<Loop> {
+    let fused_ranges = fused
+        .iter()
+        .enumerate()
+        .filter_map(|(start, fused)| Some(start..start + fused.as_ref()?.len()))
+        .collect_vec();
+    let nests = |a: &Range<usize>, b: &Range<usize>| {
+        a.end <= b.start
+            || b.end <= a.start
+            || (a.start <= b.start && b.end <= a.end)
+            || (b.start <= a.start && a.end <= b.end)
+    };
+
+    let mut structured: Vec<Loop> = Vec::new();
+    for l in loops(instructions) {
+        let range = l.start..l.end + 1;
+        // the jump back must not be part of a fused loop, so the loop can only contain them
+        let is_structured = fused_ranges
+            .iter()
+            .all(|fused| !fused.contains(&l.end) && nests(fused, &range))
+            && structured
+                .iter()
+                .all(|other| nests(&(other.start..other.end + 1), &range));
+
+        if is_structured {
+            structured.push(l);
+        }
+    }
+
+    structured
+}
+
+fn fused_statements(fused: &Fused) -> Vec<String> {
+    let register = |r: u8| Operand::Reg(r).to_string();
+
+    match *fused {
+        Fused::Add { target, counter } => vec![
+            format!("{} += {}", register(target), register(counter)),
+            format!("{} = 0", register(counter)),
+        ],
+        Fused::Multiply {
+            target,
+            factor,
+            inner,
+            counter,
+        } => vec![
+            format!(
+                "{} += {} * {}",
+                register(target),
+                register(factor),
+                register(counter)
+            ),
+            format!("{} = 0", register(inner)),
+            format!("{} = 0", register(counter)),
+        ],
+    }
+}
+
+/// The pseudo-code of a single instruction. Targets of `goto`s are added to the labels.
+fn statement(instructions: &[Operation], ic: usize, labels: &mut BTreeSet<usize>) -> String {
+    let mut goto = |target: usize| match target == instructions.len() {
+        true => "halt".to_string(),
+        false => {
+            labels.insert(target);
+            format!("goto {}", label(target, instructions))
+        }
+    };
+
+    match instructions[ic] {
+        Operation::Cpy(x, Operand::Reg(y)) => format!("{} = {}", Operand::Reg(y), x),
+        Operation::Inc(Operand::Reg(x)) => format!("{} += 1", Operand::Reg(x)),
+        Operation::Dec(Operand::Reg(x)) => format!("{} -= 1", Operand::Reg(x)),
+        Operation::Mul(Operand::Reg(x), y, z) => format!("{} = {} * {}", Operand::Reg(x), y, z),
+        Operation::Jnz(..) => match jump(instructions, ic) {
+            Some(Jump::Static {
+                condition: None,
+                target,
+            }) => goto(target),
+            Some(Jump::Static {
+                condition: Some(condition),
+                target,
+            }) => format!("if {} != 0 {{ {} }}", condition, goto(target)),
+            Some(Jump::Dynamic {
+                condition: None,
+                offset,
+            }) => format!("goto {} + {}", ic, offset),
+            Some(Jump::Dynamic {
+                condition: Some(condition),
+                offset,
+            }) => format!("if {} != 0 {{ goto {} + {} }}", condition, ic, offset),
+            _ => "nop".to_string(),
+        },
+        Operation::Tgl(Operand::Imm(offset)) => format!("toggle({})", ic as i64 + offset),
+        Operation::Tgl(offset) => format!("toggle({} + {})", ic, offset),
+        Operation::Out(x) => format!("out({})", x),
+        // instructions writing to an immediate value are skipped
+        _ => "nop".to_string(),
+    }
+}
+
+fn label(ic: usize, instructions: &[Operation]) -> String {
+    match ic == instructions.len() {
+        true => "end".to_string(),
+        false => format!("L{}", ic),
+    }
+}
+
+fn mark(statement: String, is_toggled: bool) -> String {
+    match is_toggled {
+        true => format!("{}  // may be toggled", statement),
+        false => statement,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use util::parse;
+
+    use super::*;
+
+    const FACTORIAL: &str = include_str!("../../../examples/day23_factorial.txt");
+
+    fn program(source: &str) -> Vec<Operation> {
+        parse::map_lines(source, Operation::from).unwrap()
+    }
+
+    #[test]
+    fn blocks_are_split_at_jumps() {
+        let blocks = control_flow(&program(
+            "cpy 2 a\njnz a 2\ninc b\ndec a\njnz a -1\njnz 1 c",
+        ));
+
+        assert_eq!(
+            vec![
+                Block {
+                    instructions: 0..2,
+                    successors: vec![2, 3],
+                    dynamic_jump: false
+                },
+                Block {
+                    instructions: 2..3,
+                    successors: vec![3],
+                    dynamic_jump: false
+                },
+                Block {
+                    instructions: 3..5,
+                    successors: vec![5, 3],
+                    dynamic_jump: false
+                },
+                Block {
+                    instructions: 5..6,
+                    successors: vec![],
+                    dynamic_jump: true
+                },
+            ],
+            blocks
+        );
+    }
+
+    #[test]
+    fn jumps_become_gotos() {
+        let code = decompile(&program("cpy 2 a\njnz a 2\ninc b\ndec a\ninc c\njnz a -2"));
+
+        assert_eq!(
+            "    a = 2\n    if a != 0 { goto L3 }\n    b += 1\nL3:\n    c += a\n    a = 0",
+            code
+        );
+    }
+
+    #[test]
+    fn loops_entered_in_the_middle_are_not_fused() {
+        let code = decompile(&program("cpy 3 a\njnz 1 3\ninc b\ndec a\ninc c\njnz a -2"));
+
+        assert_eq!(
+            [
+                "    a = 3",
+                "    goto L4",
+                "    b += 1",
+                "    do {",
+                "        a -= 1",
+                "L4:",
+                "        c += 1",
+                "    } while a != 0",
+            ]
+            .join("\n"),
+            code
+        );
+    }
+
+    #[test]
+    fn toggled_instructions_are_flagged() {
+        let program = program(FACTORIAL);
+        let code = decompile(&program);
+
+        assert_eq!(vec![(16, 16..26)], toggle_targets(&program));
+        assert_eq!(5, loops(&program).len());
+        assert!(code.contains("\n    a += b * d\n"));
+        assert!(code.contains("\n    toggle(16 + c)  // may be toggled\n"));
+        assert!(!code.contains("b = a  // may be toggled"));
+        assert!(
+            disassemble(&program).contains("  16  tgl c         toggles 16..26, self-modifying\n")
+        );
+    }
+}
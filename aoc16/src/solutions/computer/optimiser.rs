@@ -0,0 +1,158 @@
+use super::Operand::{Imm, Reg};
+use super::Operation::{self, Cpy, Dec, Inc, Jnz};
+
+/// A loop which is executed in a single step instead of instruction by instruction.
+/// The operands are the indices of registers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Fused {
+    /// `target += counter; counter = 0` for
+    /// ```text
+    /// inc target
+    /// dec counter
+    /// jnz counter -2
+    /// ```
+    /// The `inc` and `dec` may be swapped.
+    Add { target: u8, counter: u8 },
+    /// `target += factor * counter; inner = 0; counter = 0` for
+    /// ```text
+    /// cpy factor inner
+    /// inc target
+    /// dec inner
+    /// jnz inner -2
+    /// dec counter
+    /// jnz counter -5
+    /// ```
+    Multiply {
+        target: u8,
+        factor: u8,
+        inner: u8,
+        counter: u8,
+    },
+}
+
+impl Fused {
+    /// The number of instructions replaced by the loop.
+    pub fn len(&self) -> usize {
+        match self {
+            Fused::Add { .. } => 3,
+            Fused::Multiply { .. } => 6,
+        }
+    }
+}
+
+/// The loop which can be fused starting at every instruction, if there is one.
+pub fn optimise(instructions: &[Operation]) -> Vec<Option<Fused>> {
+    (0..instructions.len())
+        .map(|i| find_multiply(&instructions[i..]).or_else(|| find_add(&instructions[i..])))
+        .collect()
+}
+
+fn find_add(instructions: &[Operation]) -> Option<Fused> {
+    match *instructions {
+        [Inc(Reg(target)), Dec(Reg(counter)), Jnz(Reg(check), Imm(-2)), ..]
+        | [Dec(Reg(counter)), Inc(Reg(target)), Jnz(Reg(check), Imm(-2)), ..]
+            if check == counter && target != counter =>
+        {
+            Some(Fused::Add { target, counter })
+        }
+        _ => None,
+    }
+}
+
+fn find_multiply(instructions: &[Operation]) -> Option<Fused> {
+    let [Cpy(Reg(factor), Reg(inner)), _, _, _, Dec(Reg(counter)), Jnz(Reg(check), Imm(-5)), ..] =
+        *instructions
+    else {
+        return None;
+    };
+    let Some(Fused::Add {
+        target,
+        counter: added,
+    }) = find_add(&instructions[1..])
+    else {
+        return None;
+    };
+
+    // the factor has to stay the same while the loops run
+    let is_independent = added == inner
+        && check == counter
+        && ![target, inner].contains(&counter)
+        && ![target, inner, counter].contains(&factor);
+
+    is_independent.then_some(Fused::Multiply {
+        target,
+        factor,
+        inner,
+        counter,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn program(source: &str) -> Vec<Operation> {
+        source
+            .lines()
+            .map(|line| Operation::from(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn add_loops_are_found() {
+        let fused = optimise(&program("inc a\ndec b\njnz b -2\ndec c\ninc d\njnz c -2"));
+
+        assert_eq!(
+            Some(Fused::Add {
+                target: 0,
+                counter: 1
+            }),
+            fused[0]
+        );
+        assert_eq!(
+            Some(Fused::Add {
+                target: 3,
+                counter: 2
+            }),
+            fused[3]
+        );
+        assert_eq!(2, fused.iter().flatten().count());
+    }
+
+    #[test]
+    fn multiply_loops_are_found() {
+        let fused = optimise(&program("cpy b c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5"));
+
+        assert_eq!(
+            Some(Fused::Multiply {
+                target: 0,
+                factor: 1,
+                inner: 2,
+                counter: 3
+            }),
+            fused[0]
+        );
+        // the inner loop is fused as well for jumps into it
+        assert!(matches!(fused[1], Some(Fused::Add { .. })));
+    }
+
+    #[test]
+    fn loops_changing_their_own_counters_are_not_fused() {
+        for source in [
+            "inc a\ndec a\njnz a -2",
+            "inc a\ndec b\njnz c -2",
+            "inc a\ndec b\njnz b -3",
+            "cpy a c\ninc a\ndec c\njnz c -2\ndec d\njnz d -5",
+            "cpy b c\ninc a\ndec c\njnz c -2\ndec a\njnz a -5",
+        ] {
+            let fused = optimise(&program(source));
+
+            assert!(
+                !matches!(fused[0], Some(Fused::Multiply { .. })),
+                "{}",
+                source
+            );
+            assert!(!matches!(fused[0], Some(Fused::Add { .. })), "{}", source);
+        }
+    }
+}
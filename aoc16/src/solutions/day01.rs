@@ -1,36 +1,57 @@
 use std::collections::HashSet;
 use util::grid::{Coordinate, Direction};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> u32 {
-    let mut direction = Direction::Up;
-    let mut coordinate = Coordinate::default();
+pub struct Day01;
 
-    for (turn, steps) in parse(input) {
-        change_direction(&mut direction, turn);
-        coordinate = coordinate.step(&direction, steps);
+impl Solution for Day01 {
+    type Input<'a> = Vec<(char, u32)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .split(", ")
+            .map(|line| {
+                let mut chars = line.chars();
+                chars
+                    .next()
+                    .map(|c| (c, chars.as_str().parse().unwrap()))
+                    .unwrap()
+            })
+            .collect())
     }
 
-    coordinate.manhatten_distance(&Coordinate::default())
-}
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let mut direction = Direction::Up;
+        let mut coordinate: Coordinate = Coordinate::default();
+
+        for &(turn, steps) in input {
+            change_direction(&mut direction, turn);
+            coordinate = coordinate.step(&direction, steps);
+        }
+
+        coordinate.manhatten_distance(&Coordinate::default()).into()
+    }
 
-pub fn part2(input: &str) -> u32 {
-    let mut direction = Direction::Right;
-    let mut coordinate = Coordinate::default();
-    let mut visited = HashSet::new();
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let mut direction = Direction::Right;
+        let mut coordinate: Coordinate = Coordinate::default();
+        let mut visited = HashSet::new();
 
-    'outer: for (turn, steps) in parse(input) {
-        change_direction(&mut direction, turn);
+        'outer: for &(turn, steps) in input {
+            change_direction(&mut direction, turn);
 
-        for _ in 1..=steps {
-            coordinate = coordinate.step(&direction, 1);
-            if visited.contains(&coordinate) {
-                break 'outer;
+            for _ in 1..=steps {
+                coordinate = coordinate.step(&direction, 1);
+                if visited.contains(&coordinate) {
+                    break 'outer;
+                }
+                visited.insert(coordinate);
             }
-            visited.insert(coordinate);
         }
-    }
 
-    coordinate.manhatten_distance(&Coordinate::default())
+        coordinate.manhatten_distance(&Coordinate::default()).into()
+    }
 }
 
 fn change_direction(direction: &mut Direction, turn: char) {
@@ -41,29 +62,19 @@ fn change_direction(direction: &mut Direction, turn: char) {
     };
 }
 
-fn parse(input: &str) -> impl Iterator<Item = (char, u32)> + '_ {
-    input.split(", ").map(|line| {
-        let mut chars = line.chars();
-        chars
-            .next()
-            .map(|c| (c, chars.as_str().parse().unwrap()))
-            .unwrap()
-    })
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn part1_ex() {
-        assert_eq!(5, part1("R2, L3"));
-        assert_eq!(2, part1("R2, R2, R2"));
-        assert_eq!(12, part1("R5, L5, R5, R3"));
+        assert_eq!(5, Day01::part1(&Day01::parse("R2, L3").unwrap()));
+        assert_eq!(2, Day01::part1(&Day01::parse("R2, R2, R2").unwrap()));
+        assert_eq!(12, Day01::part1(&Day01::parse("R5, L5, R5, R3").unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(4, part2("R8, R4, R4, R8"));
+        assert_eq!(4, Day01::part2(&Day01::parse("R8, R4, R4, R8").unwrap()));
     }
 }
@@ -1,4 +1,6 @@
 use util::grid::{Bounds, Coordinate, Direction};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 const KEYPAD_1: [[char; 3]; 3] = [['1', '2', '3'], ['4', '5', '6'], ['7', '8', '9']];
 
@@ -10,29 +12,42 @@ const KEYPAD_2: [[char; 5]; 5] = [
     [' ', ' ', 'D', ' ', ' '],
 ];
 
-pub fn part1(input: &str) -> String {
-    let coordinate = Coordinate::from(1, 1);
-    let bounds = Bounds::from(0..KEYPAD_1[0].len() as i32, 0..KEYPAD_1.len() as i32);
-    let keypad = KEYPAD_1.iter().map(|&line| line.to_vec()).collect();
+pub struct Day02;
 
-    build_code(keypad, input, coordinate, &bounds)
-}
+impl Solution for Day02 {
+    type Input<'a> = Vec<Vec<Direction>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| line.chars().map(Direction::from).collect())
+            .collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let coordinate = Coordinate::from(1, 1);
+        let bounds = Bounds::from(0..KEYPAD_1[0].len() as i32, 0..KEYPAD_1.len() as i32);
+        let keypad = KEYPAD_1.iter().map(|&line| line.to_vec()).collect();
+
+        build_code(keypad, input, coordinate, &bounds).into()
+    }
 
-pub fn part2(input: &str) -> String {
-    let coordinate = Coordinate::from(0, 2);
-    let bounds = Bounds::from(0..KEYPAD_2[0].len() as i32, 0..KEYPAD_2.len() as i32);
-    let keypad = KEYPAD_2.iter().map(|&line| line.to_vec()).collect();
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let coordinate = Coordinate::from(0, 2);
+        let bounds = Bounds::from(0..KEYPAD_2[0].len() as i32, 0..KEYPAD_2.len() as i32);
+        let keypad = KEYPAD_2.iter().map(|&line| line.to_vec()).collect();
 
-    build_code(keypad, input, coordinate, &bounds)
+        build_code(keypad, input, coordinate, &bounds).into()
+    }
 }
 
 fn build_code(
     keypad: Vec<Vec<char>>,
-    input: &str,
+    instructions: &[Vec<Direction>],
     mut coordinate: Coordinate,
     bounds: &Bounds,
 ) -> String {
-    parse(input)
+    instructions
         .iter()
         .map(|line| {
             coordinate = execute_line(&keypad, line, coordinate, bounds);
@@ -60,13 +75,6 @@ fn execute_line(
     coordinate
 }
 
-fn parse(input: &str) -> Vec<Vec<Direction>> {
-    input
-        .lines()
-        .map(|line| line.chars().map(Direction::from).collect())
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -75,11 +83,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!("1985", part1(EXAMPLE));
+        assert_eq!("1985", Day02::part1(&Day02::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!("5DB3", part2(EXAMPLE));
+        assert_eq!("5DB3", Day02::part2(&Day02::parse(EXAMPLE).unwrap()));
     }
 }
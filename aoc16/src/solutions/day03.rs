@@ -1,15 +1,37 @@
-pub fn part1(input: &str) -> usize {
-    parse(input)
-        .iter()
-        .filter(|&values| is_valid_triangle(values))
-        .count()
-}
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Vec<u32>>;
 
-pub fn part2(input: &str) -> usize {
-    parse_vertially(input)
-        .iter()
-        .filter(|&values| is_valid_triangle(values))
-        .count()
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|n| n.parse::<u32>().unwrap())
+                    .collect()
+            })
+            .collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input
+            .iter()
+            .filter(|&values| is_valid_triangle(values))
+            .count()
+            .into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        regroup_vertically(input)
+            .iter()
+            .filter(|&values| is_valid_triangle(values))
+            .count()
+            .into()
+    }
 }
 
 fn is_valid_triangle(values: &[u32]) -> bool {
@@ -20,34 +42,9 @@ fn is_valid_triangle(values: &[u32]) -> bool {
     values.iter().all(|&value| sum - value > value)
 }
 
-fn parse(input: &str) -> Vec<Vec<u32>> {
-    input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|n| n.parse::<u32>().unwrap())
-                .collect()
-        })
-        .collect()
-}
-
-fn parse_vertially(input: &str) -> Vec<Vec<u32>> {
-    input
-        .lines()
-        .step_by(3)
-        .zip(input.lines().skip(1).step_by(3))
-        .zip(input.lines().skip(2).step_by(3))
-        .flat_map(|((a, b), c)| {
-            a.split_whitespace()
-                .zip(b.split_whitespace())
-                .zip(c.split_whitespace())
-                .map(|((x, y), z)| {
-                    vec![x, y, z]
-                        .into_iter()
-                        .map(|n| n.parse::<u32>().unwrap())
-                        .collect()
-                })
-        })
+fn regroup_vertically(rows: &[Vec<u32>]) -> Vec<Vec<u32>> {
+    rows.chunks(3)
+        .flat_map(|chunk| (0..3).map(move |column| chunk.iter().map(|row| row[column]).collect()))
         .collect()
 }
 
@@ -1,46 +1,64 @@
-use itertools::Itertools;
-use lazy_static::lazy_static;
-use regex::Regex;
+use std::str::FromStr;
 
-lazy_static! {
-    static ref ROOM_REGEX: Regex = Regex::new(r"^(.*)-(\d+)\[(.*)\]$").unwrap();
-}
+use itertools::Itertools;
+use util::parse::{self, ParseError};
+use util::scan;
+use util::solution::{Answer, Solution};
 
 #[derive(Debug)]
-struct Room {
+pub struct Room {
     encrypted_name: String,
     id: u32,
     checksum: String,
 }
 
-impl Room {
-    fn from(line: &str) -> Self {
-        let captures = ROOM_REGEX.captures_iter(line).next().unwrap();
-        Room {
-            encrypted_name: captures[1].to_string(),
-            id: captures[2].parse().unwrap(),
-            checksum: captures[3].to_string(),
-        }
+impl FromStr for Room {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (name_and_id, checksum) = scan!(line, "{}[{}]", String, String)?;
+        // the name itself contains dashes, so the id follows the last one
+        let (encrypted_name, id) = name_and_id
+            .rsplit_once('-')
+            .ok_or_else(|| ParseError::new(line, "missing sector id"))?;
+
+        Ok(Room {
+            encrypted_name: encrypted_name.to_string(),
+            id: parse::field(id)?,
+            checksum,
+        })
     }
 }
 
-pub fn part1(input: &str) -> u32 {
-    parse(input)
-        .iter()
-        .filter(|&room| check_room_real(room))
-        .map(|room| room.id)
-        .sum()
-}
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<Room>;
 
-pub fn part2(input: &str) -> u32 {
-    let search_for = "northpole object storage".to_string().to_ascii_lowercase();
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::map_lines(input, str::parse)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input
+            .iter()
+            .filter(|&room| check_room_real(room))
+            .map(|room| room.id)
+            .sum::<u32>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let search_for = "northpole object storage".to_string().to_ascii_lowercase();
 
-    parse(input)
-        .iter()
-        .filter(|&room| check_room_real(room))
-        .find(|&room| shift_encrypted(room) == search_for)
-        .unwrap()
-        .id
+        input
+            .iter()
+            .filter(|&room| check_room_real(room))
+            .find(|&room| shift_encrypted(room) == search_for)
+            .unwrap()
+            .id
+            .into()
+    }
 }
 
 fn check_room_real(room: &Room) -> bool {
@@ -79,10 +97,6 @@ fn shift_char(c: char, shift: u32) -> char {
     char::from_u32((ord + shift) % 26 + 'a' as u32).unwrap()
 }
 
-fn parse(input: &str) -> Vec<Room> {
-    input.lines().map(Room::from).collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,17 +105,17 @@ mod tests {
 
     #[test]
     fn room_real_check_works() {
-        assert!(check_room_real(&Room::from("aaaaa-bbb-z-y-x-123[abxyz]")));
-        assert!(check_room_real(&Room::from("a-b-c-d-e-f-g-h-987[abcde]")));
-        assert!(check_room_real(&Room::from("not-a-real-room-404[oarel]")));
-        assert!(!check_room_real(&Room::from(
-            "totally-real-room-200[decoy]"
-        )));
+        let room = |line: &str| line.parse::<Room>().unwrap();
+
+        assert!(check_room_real(&room("aaaaa-bbb-z-y-x-123[abxyz]")));
+        assert!(check_room_real(&room("a-b-c-d-e-f-g-h-987[abcde]")));
+        assert!(check_room_real(&room("not-a-real-room-404[oarel]")));
+        assert!(!check_room_real(&room("totally-real-room-200[decoy]")));
     }
 
     #[test]
     fn part1_ex() {
-        assert_eq!(1514, part1(INPUT));
+        assert_eq!(1514, Day04::part1(&Day04::parse(INPUT).unwrap()));
     }
 
     #[test]
@@ -112,7 +126,7 @@ mod tests {
 
     #[test]
     fn room_decryption() {
-        let room = Room::from("qzmt-zixmtkozy-ivhz-343[abcde]");
+        let room: Room = "qzmt-zixmtkozy-ivhz-343[abcde]".parse().unwrap();
         assert_eq!("very encrypted name".to_string(), shift_encrypted(&room));
     }
 }
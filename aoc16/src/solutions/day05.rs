@@ -1,53 +1,65 @@
 use itertools::all;
 use md5::{self, Digest};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 const PASSWORD_LENGTH: usize = 8;
 
-pub fn part1(input: &str) -> String {
-    let mut index = 0;
-    let mut password = String::new();
+pub struct Day05;
 
-    loop {
-        let digest = hash_value(input, index);
+impl Solution for Day05 {
+    type Input<'a> = &'a str;
 
-        if let Some((c, _)) = test_hash(&digest) {
-            password.push(c);
-            if password.len() == PASSWORD_LENGTH {
-                break;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let mut index = 0;
+        let mut password = String::new();
+
+        loop {
+            let digest = hash_value(input, index);
+
+            if let Some((c, _)) = test_hash(&digest) {
+                password.push(c);
+                if password.len() == PASSWORD_LENGTH {
+                    break;
+                }
             }
+
+            index += 1;
         }
 
-        index += 1;
+        password.into()
     }
 
-    password
-}
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let mut index = 0;
+        let mut password = [' '; PASSWORD_LENGTH];
+        let mut index_set = [false; PASSWORD_LENGTH];
 
-pub fn part2(input: &str) -> String {
-    let mut index = 0;
-    let mut password = [' '; PASSWORD_LENGTH];
-    let mut index_set = [false; PASSWORD_LENGTH];
+        loop {
+            let digest = hash_value(input, index);
 
-    loop {
-        let digest = hash_value(input, index);
+            if let Some((pos, c)) = test_hash(&digest) {
+                let pos = pos.to_digit(16).unwrap() as usize;
 
-        if let Some((pos, c)) = test_hash(&digest) {
-            let pos = pos.to_digit(16).unwrap() as usize;
+                if (0..PASSWORD_LENGTH).contains(&pos) && !index_set[pos] {
+                    password[pos] = c;
+                    index_set[pos] = true;
+                }
 
-            if (0..PASSWORD_LENGTH).contains(&pos) && !index_set[pos] {
-                password[pos] = c;
-                index_set[pos] = true;
+                if all(index_set, |b| b) {
+                    break;
+                }
             }
 
-            if all(index_set, |b| b) {
-                break;
-            }
+            index += 1;
         }
 
-        index += 1;
+        password.iter().collect::<String>().into()
     }
-
-    password.iter().collect()
 }
 
 fn hash_value(id: &str, index: u32) -> Digest {
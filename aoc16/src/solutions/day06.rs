@@ -1,23 +1,37 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> String {
-    let index_map = build_index_map(input);
+pub struct Day06;
 
-    let message_length = input.lines().next().unwrap().len();
-    (0..message_length)
-        .map(|i| find_most_common(index_map.get(&i).unwrap()))
-        .collect()
-}
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
 
-pub fn part2(input: &str) -> String {
-    let index_map = build_index_map(input);
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let index_map = build_index_map(input);
 
-    let message_length = input.lines().next().unwrap().len();
-    (0..message_length)
-        .map(|i| find_least_common(index_map.get(&i).unwrap()))
-        .collect()
+        let message_length = input.lines().next().unwrap().len();
+        (0..message_length)
+            .map(|i| find_most_common(index_map.get(&i).unwrap()))
+            .collect::<String>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let index_map = build_index_map(input);
+
+        let message_length = input.lines().next().unwrap().len();
+        (0..message_length)
+            .map(|i| find_least_common(index_map.get(&i).unwrap()))
+            .collect::<String>()
+            .into()
+    }
 }
 
 fn build_index_map(input: &str) -> HashMap<usize, Vec<char>> {
@@ -50,11 +64,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!("easter", part1(INPUT));
+        assert_eq!("easter", Day06::part1(&Day06::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!("advent", part2(INPUT));
+        assert_eq!("advent", Day06::part2(&Day06::parse(INPUT).unwrap()));
     }
 }
@@ -1,9 +1,11 @@
 use std::collections::HashSet;
 
 use itertools::Itertools;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(Debug)]
-struct IPv7 {
+pub struct IPv7 {
     supernet_sequences: Vec<String>,
     hypernet_sequences: Vec<String>,
 }
@@ -45,18 +47,30 @@ impl IPv7 {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    parse(input)
-        .iter()
-        .filter(|address| supports_tls(address))
-        .count()
-}
+pub struct Day07;
 
-pub fn part2(input: &str) -> usize {
-    parse(input)
-        .iter()
-        .filter(|address| supports_ssl(address))
-        .count()
+impl Solution for Day07 {
+    type Input<'a> = Vec<IPv7>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines().map(IPv7::from).collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input
+            .iter()
+            .filter(|address| supports_tls(address))
+            .count()
+            .into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input
+            .iter()
+            .filter(|address| supports_ssl(address))
+            .count()
+            .into()
+    }
 }
 
 fn supports_tls(address: &IPv7) -> bool {
@@ -101,10 +115,6 @@ fn aba_pairings(sequence: &str) -> impl Iterator<Item = (char, char, char)> + '_
         .filter(|&(a, b, c)| a == c && a != b)
 }
 
-fn parse(input: &str) -> Vec<IPv7> {
-    input.lines().map(IPv7::from).collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,7 +132,7 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(2, part1(EXAMPLE_TSL));
+        assert_eq!(2, Day07::part1(&Day07::parse(EXAMPLE_TSL).unwrap()));
     }
 
     #[test]
@@ -135,6 +145,6 @@ mod tests {
 
     #[test]
     fn part2_ex() {
-        assert_eq!(3, part2(EXAMPLE_SSL));
+        assert_eq!(3, Day07::part2(&Day07::parse(EXAMPLE_SSL).unwrap()));
     }
 }
@@ -1,8 +1,12 @@
 use lazy_static::lazy_static;
 use regex::Regex;
+use util::grid::{Coordinate, Grid};
+use util::ocr;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(Debug)]
-enum Operation {
+pub enum Operation {
     Rectangle(usize, usize),
     RotateRow(usize, usize),
     RotateColumn(usize, usize),
@@ -29,7 +33,7 @@ impl Operation {
         unreachable!();
     }
 
-    fn execute(&self, display: &mut [[bool; WIDTH]; HEIGHT]) {
+    fn execute(&self, display: &mut Grid<bool>) {
         match *self {
             Operation::Rectangle(x, y) => rectangle(display, x, y),
             Operation::RotateColumn(column, amount) => rotate_column(display, column, amount),
@@ -47,67 +51,70 @@ lazy_static! {
         Regex::new(r"^rotate (row y|column x)=(\d+) by (\d+)$").unwrap();
 }
 
-pub fn part1(input: &str) -> usize {
-    let display = start_display(input);
+pub struct Day08;
 
-    display.iter().flatten().filter(|b| **b).count()
-}
+impl Solution for Day08 {
+    type Input<'a> = Vec<Operation>;
 
-pub fn part2(input: &str) -> String {
-    let display = start_display(input);
-
-    let mut display_string = String::new();
-    for row in display {
-        display_string.push('\n');
-        for b in row {
-            match b {
-                true => display_string.push('▮'),
-                false => display_string.push(' '),
-            }
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines().map(Operation::from).collect())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let display = start_display(input);
+
+        display.values().filter(|b| **b).count().into()
     }
 
-    display_string
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let display = start_display(input);
+        if let Some(letters) = ocr::recognize(&display) {
+            return letters.into();
+        }
+
+        // show the picture if the letters can not be read
+        let display = display.map(|b| match b {
+            true => '▮',
+            false => ' ',
+        });
+
+        format!("\n{}", display).into()
+    }
 }
 
-fn start_display(input: &str) -> [[bool; WIDTH]; HEIGHT] {
+fn start_display(operations: &[Operation]) -> Grid<bool> {
     let mut display = build_display();
-    parse(input).iter().for_each(|op| op.execute(&mut display));
+    operations.iter().for_each(|op| op.execute(&mut display));
 
     display
 }
 
-fn rectangle(display: &mut [[bool; WIDTH]; HEIGHT], x: usize, y: usize) {
-    for row in display.iter_mut().take(y) {
-        for cell in row.iter_mut().take(x) {
-            *cell = true;
+fn rectangle(display: &mut Grid<bool>, x: usize, y: usize) {
+    for y in 0..y as i32 {
+        for x in 0..x as i32 {
+            display[Coordinate::from(x, y)] = true;
         }
     }
 }
 
-fn rotate_column(display: &mut [[bool; WIDTH]; HEIGHT], column: usize, amount: usize) {
-    let old_column: Vec<bool> = (0..HEIGHT).map(|y| display[y][column]).collect();
+fn rotate_column(display: &mut Grid<bool>, column: usize, amount: usize) {
+    let old_column: Vec<bool> = display.column(column).copied().collect();
 
-    for (y, row) in display.iter_mut().enumerate() {
-        row[column] = old_column[(y + HEIGHT - amount) % HEIGHT];
+    for (y, cell) in old_column.iter().enumerate() {
+        display[Coordinate::from(column as i32, ((y + amount) % HEIGHT) as i32)] = *cell;
     }
 }
 
-fn rotate_row(display: &mut [[bool; WIDTH]; HEIGHT], row: usize, amount: usize) {
-    let row = &mut display[row];
-    let old_row = *row;
+fn rotate_row(display: &mut Grid<bool>, row: usize, amount: usize) {
+    let old_row = display.row(row).to_vec();
 
-    for (i, cell) in row.iter_mut().enumerate() {
-        *cell = old_row[(i + WIDTH - amount) % WIDTH];
+    for (x, cell) in old_row.iter().enumerate() {
+        display[Coordinate::from(((x + amount) % WIDTH) as i32, row as i32)] = *cell;
     }
 }
 
-fn build_display() -> [[bool; WIDTH]; HEIGHT] {
-    [[false; 50]; 6]
-}
-
-fn parse(input: &str) -> Vec<Operation> {
-    input.lines().map(Operation::from).collect()
+fn build_display() -> Grid<bool> {
+    Grid::new(WIDTH, HEIGHT, false)
 }
 
 #[cfg(test)]
@@ -121,40 +128,40 @@ mod tests {
 
         op.execute(&mut display);
 
-        assert!(display[0][0]);
-        assert!(display[0][2]);
-        assert!(display[1][0]);
-        assert!(display[1][2]);
-        assert!(!display[2][0]);
+        assert!(display[Coordinate::from(0, 0)]);
+        assert!(display[Coordinate::from(2, 0)]);
+        assert!(display[Coordinate::from(0, 1)]);
+        assert!(display[Coordinate::from(2, 1)]);
+        assert!(!display[Coordinate::from(0, 2)]);
     }
 
     #[test]
     fn rotate_row_test() {
         let op = Operation::from("rotate row y=0 by 1");
         let mut display = build_display();
-        display[0][WIDTH - 1] = true;
-        display[0][4] = true;
+        display[Coordinate::from(WIDTH as i32 - 1, 0)] = true;
+        display[Coordinate::from(4, 0)] = true;
 
         op.execute(&mut display);
 
-        assert!(!display[0][WIDTH - 1]);
-        assert!(display[0][0]);
-        assert!(!display[0][4]);
-        assert!(display[0][5]);
+        assert!(!display[Coordinate::from(WIDTH as i32 - 1, 0)]);
+        assert!(display[Coordinate::from(0, 0)]);
+        assert!(!display[Coordinate::from(4, 0)]);
+        assert!(display[Coordinate::from(5, 0)]);
     }
 
     #[test]
     fn rotate_column_test() {
         let op = Operation::from("rotate column x=0 by 1");
         let mut display = build_display();
-        display[HEIGHT - 1][0] = true;
-        display[1][0] = true;
+        display[Coordinate::from(0, HEIGHT as i32 - 1)] = true;
+        display[Coordinate::from(0, 1)] = true;
 
         op.execute(&mut display);
 
-        assert!(!display[HEIGHT - 1][0]);
-        assert!(display[0][0]);
-        assert!(!display[1][0]);
-        assert!(display[2][0]);
+        assert!(!display[Coordinate::from(0, HEIGHT as i32 - 1)]);
+        assert!(display[Coordinate::from(0, 0)]);
+        assert!(!display[Coordinate::from(0, 1)]);
+        assert!(display[Coordinate::from(0, 2)]);
     }
 }
@@ -1,9 +1,22 @@
-pub fn part1(input: &str) -> usize {
-    input.lines().map(decompress).map(|s| s.len()).sum()
-}
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.lines().map(decompress).map(|s| s.len()).sum::<usize>().into()
+    }
 
-pub fn part2(input: &str) -> usize {
-    input.lines().map(fully_decompressed_length).sum()
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        input.lines().map(fully_decompressed_length).sum::<usize>().into()
+    }
 }
 
 fn decompress(compressed: &str) -> String {
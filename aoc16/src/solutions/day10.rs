@@ -1,6 +1,8 @@
 use itertools::Itertools;
 use regex::Regex;
 use std::{cell::RefCell, collections::HashMap, rc::Rc};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 struct Bot {
     id: u32,
@@ -80,40 +82,50 @@ impl Give for Output {
     }
 }
 
-pub fn part1(input: &str) -> u32 {
-    let test_for = (17, 61);
-    let (bots, _) = parse(input);
+pub struct Day10;
 
-    loop {
-        for bot in bots.iter() {
-            if bot.borrow().test_for_value(test_for) {
-                return bot.borrow().id;
-            }
+impl Solution for Day10 {
+    // Bots hand their microchips on through shared cells, so every part builds them anew.
+    type Input<'a> = &'a str;
 
-            bot.borrow_mut().evaluate();
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
     }
-}
 
-pub fn part2(input: &str) -> u32 {
-    let (bots, outputs) = parse(input);
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let test_for = (17, 61);
+        let (bots, _) = build_factory(input);
+        Answer::from('search: loop {
+            for bot in bots.iter() {
+                if bot.borrow().test_for_value(test_for) {
+                    break 'search bot.borrow().id;
+                }
 
-    loop {
-        for bot in bots.iter() {
-            bot.borrow_mut().evaluate();
-        }
-        if (0..3).all(|i| !outputs.get(&i).unwrap().borrow().microchips.is_empty()) {
-            return (0..3)
-                .map(|i| outputs.get(&i).unwrap().borrow().microchips[0])
-                .product();
-        }
+                bot.borrow_mut().evaluate();
+            }
+        })
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (bots, outputs) = build_factory(input);
+
+        Answer::from(loop {
+            for bot in bots.iter() {
+                bot.borrow_mut().evaluate();
+            }
+            if (0..3).all(|i| !outputs.get(&i).unwrap().borrow().microchips.is_empty()) {
+                break (0..3)
+                    .map(|i| outputs.get(&i).unwrap().borrow().microchips[0])
+                    .product::<u32>();
+            }
+        })
     }
 }
 
 type OutputMap = HashMap<u32, Rc<RefCell<Output>>>;
 type BotVec = Vec<Rc<RefCell<Bot>>>;
 
-fn parse(input: &str) -> (BotVec, OutputMap) {
+fn build_factory(input: &str) -> (BotVec, OutputMap) {
     let mut bots = HashMap::new();
     let mut outputs = HashMap::new();
 
@@ -199,13 +211,13 @@ mod tests {
 
     const INPUT: &str = include_str!("../../examples/day10.txt");
 
-    fn get_by_id(bots: &Vec<Rc<RefCell<Bot>>>, id: u32) -> &RefCell<Bot> {
+    fn get_by_id(bots: &[Rc<RefCell<Bot>>], id: u32) -> &RefCell<Bot> {
         bots.iter().find(|b| b.borrow().id == id).unwrap()
     }
 
     #[test]
     fn components_created_successfully() {
-        let (bots, outputs) = parse(INPUT);
+        let (bots, outputs) = build_factory(INPUT);
 
         assert_eq!(3, bots.len());
         assert_eq!(3, outputs.values().len());
@@ -219,7 +231,7 @@ mod tests {
 
     #[test]
     fn handing_off_values_works_successfully() {
-        let (bots, _) = parse(INPUT);
+        let (bots, _) = build_factory(INPUT);
 
         get_by_id(&bots, 2).borrow_mut().evaluate();
 
@@ -233,7 +245,7 @@ mod tests {
 
     #[test]
     fn handing_off_full_example_arrives_at_correct_end_state() {
-        let (bots, outputs) = parse(INPUT);
+        let (bots, outputs) = build_factory(INPUT);
 
         get_by_id(&bots, 2).borrow_mut().evaluate();
         get_by_id(&bots, 1).borrow_mut().evaluate();
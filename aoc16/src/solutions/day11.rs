@@ -3,6 +3,8 @@ use std::hash::{Hash, Hasher};
 
 use itertools::Itertools;
 use regex::Regex;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(Debug, PartialEq, Eq)]
 struct State {
@@ -29,19 +31,50 @@ impl Hash for State {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let floors = parse(input);
-    let goal = generate_goal(&floors);
+pub struct Day11;
 
-    solve(floors, goal)
-}
+impl Solution for Day11 {
+    type Input<'a> = Vec<HashSet<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut floors = vec![HashSet::new(); input.lines().count()];
+        let mut resource_map = HashMap::new();
+        let mut resource_num = 1;
+
+        let chip_pattern = Regex::new(r"(\w+)-compatible microchip").unwrap();
+        for (i, line) in input.lines().enumerate() {
+            for capture in chip_pattern.captures_iter(line) {
+                let resource = capture.get(1).unwrap().as_str();
+                resource_map.insert(resource, resource_num);
+                floors[i].insert(resource_num);
+                resource_num += 1;
+            }
+        }
+
+        let generator_pattern = Regex::new(r"(\w+) generator").unwrap();
+        for (i, line) in input.lines().enumerate() {
+            for capture in generator_pattern.captures_iter(line) {
+                let resource = capture.get(1).unwrap().as_str();
+                floors[i].insert(-1 * resource_map.get(resource).unwrap());
+            }
+        }
+
+        Ok(floors)
+    }
+
+    fn part1(floors: &Self::Input<'_>) -> Answer {
+        let goal = generate_goal(floors);
 
-pub fn part2(input: &str) -> usize {
-    let mut floors = parse(input);
-    insert_additional_values(&mut floors);
-    let goal = generate_goal(&floors);
+        solve(floors.clone(), goal).into()
+    }
 
-    solve(floors, goal)
+    fn part2(floors: &Self::Input<'_>) -> Answer {
+        let mut floors = floors.clone();
+        insert_additional_values(&mut floors);
+        let goal = generate_goal(&floors);
+
+        solve(floors, goal).into()
+    }
 }
 
 fn generate_goal(floors: &[HashSet<i32>]) -> HashSet<i32> {
@@ -125,32 +158,6 @@ fn is_valid_floor(floor: &HashSet<i32>) -> bool {
             .all(|i| floor.contains(&-i))
 }
 
-fn parse(input: &str) -> Vec<HashSet<i32>> {
-    let mut floors = vec![HashSet::new(); input.lines().count()];
-    let mut resource_map = HashMap::new();
-    let mut resource_num = 1;
-
-    let chip_pattern = Regex::new(r"(\w+)-compatible microchip").unwrap();
-    for (i, line) in input.lines().enumerate() {
-        for capture in chip_pattern.captures_iter(line) {
-            let resource = capture.get(1).unwrap().as_str();
-            resource_map.insert(resource, resource_num);
-            floors[i].insert(resource_num);
-            resource_num += 1;
-        }
-    }
-
-    let generator_pattern = Regex::new(r"(\w+) generator").unwrap();
-    for (i, line) in input.lines().enumerate() {
-        for capture in generator_pattern.captures_iter(line) {
-            let resource = capture.get(1).unwrap().as_str();
-            floors[i].insert(-1 * resource_map.get(resource).unwrap());
-        }
-    }
-
-    floors
-}
-
 fn insert_additional_values(floors: &mut [HashSet<i32>]) {
     let all_values = floors.iter().flatten();
     let max_value = all_values.max().unwrap();
@@ -202,6 +209,6 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(11, part1(INPUT));
+        assert_eq!(11, Day11::part1(&Day11::parse(INPUT).unwrap()));
     }
 }
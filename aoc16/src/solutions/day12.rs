@@ -1,24 +1,30 @@
 use crate::solutions::computer::{Computer, Operation};
+use util::parse::{self, ParseError};
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> i32 {
-    let instructions = parse(input);
-    let mut computer = Computer::new(instructions);
-    computer.run();
+pub struct Day12;
 
-    computer.get("a").unwrap()
-}
+impl Solution for Day12 {
+    type Input<'a> = Vec<Operation>;
 
-pub fn part2(input: &str) -> i32 {
-    let instructions = parse(input);
-    let mut computer = Computer::new(instructions);
-    computer.insert("c".to_string(), 1);
-    computer.run();
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::map_lines(input, Operation::from)
+    }
 
-    computer.get("a").unwrap()
-}
+    fn part1(instructions: &Self::Input<'_>) -> Answer {
+        let mut computer = Computer::new(instructions.clone());
+        computer.run();
+
+        computer.get('a').into()
+    }
+
+    fn part2(instructions: &Self::Input<'_>) -> Answer {
+        let mut computer = Computer::new(instructions.clone());
+        computer.insert('c', 1);
+        computer.run();
 
-fn parse(input: &str) -> Vec<Operation> {
-    input.lines().map(Operation::from).collect()
+        computer.get('a').into()
+    }
 }
 
 #[cfg(test)]
@@ -29,6 +35,14 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(42, part1(INPUT));
+        assert_eq!(42, Day12::part1(&Day12::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn invalid_instructions_are_reported_with_their_line() {
+        let error = util::solution::solve::<Day12>("cpy 41 a\ninc a\nmul a 2").unwrap_err();
+
+        assert_eq!(Some(3), error.line());
+        assert_eq!("mul a 2", error.text());
     }
 }
@@ -1,5 +1,7 @@
-use std::collections::{HashSet, VecDeque};
 use util::grid::Coordinate;
+use util::parse::ParseError;
+use util::search;
+use util::solution::{Answer, Solution};
 
 struct WallCalculator {
     value: i32,
@@ -19,68 +21,54 @@ impl WallCalculator {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let value = input.parse().unwrap();
-    let walls = WallCalculator::new(value);
+pub struct Day13;
 
-    find_path(Coordinate::from(31, 39), walls)
-}
+impl Solution for Day13 {
+    type Input<'a> = &'a str;
 
-pub fn part2(input: &str) -> usize {
-    let value = input.parse().unwrap();
-    let walls = WallCalculator::new(value);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
 
-    expand_up_to_steps(walls, 50)
-}
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let value = input.parse().unwrap();
+        let walls = WallCalculator::new(value);
 
-fn find_path(goal: Coordinate, walls: WallCalculator) -> usize {
-    let mut queue = VecDeque::from([(0, Coordinate::from(1, 1))]);
-    let mut visited = HashSet::new();
-
-    while let Some((step, coordinate)) = queue.pop_front() {
-        if coordinate == goal {
-            return step;
-        }
-
-        if visited.contains(&coordinate) {
-            continue;
-        }
-        visited.insert(coordinate);
-
-        for adjacent in coordinate.get_adjacent() {
-            if adjacent.x < 0 || adjacent.y < 0 || visited.contains(&adjacent) {
-                continue;
-            }
-            if !walls.calculate(&adjacent) {
-                queue.push_back((step + 1, adjacent));
-            }
-        }
+        find_path(Coordinate::from(31, 39), walls).into()
     }
 
-    unreachable!()
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let value = input.parse().unwrap();
+        let walls = WallCalculator::new(value);
+
+        expand_up_to_steps(walls, 50).into()
+    }
+}
+
+fn find_path(goal: Coordinate, walls: WallCalculator) -> usize {
+    search::bfs(
+        [Coordinate::from(1, 1)],
+        |coordinate| open_adjacent(coordinate, &walls),
+        |coordinate| *coordinate == goal,
+    )
+    .unwrap()
+    .cost
 }
 
 fn expand_up_to_steps(walls: WallCalculator, limit: usize) -> usize {
-    let mut queue = VecDeque::from([(0, Coordinate::from(1, 1))]);
-    let mut visited = HashSet::new();
-
-    while let Some((step, coordinate)) = queue.pop_front() {
-        if visited.contains(&coordinate) || step > limit {
-            continue;
-        }
-        visited.insert(coordinate);
-
-        for adjacent in coordinate.get_adjacent() {
-            if adjacent.x < 0 || adjacent.y < 0 || visited.contains(&adjacent) {
-                continue;
-            }
-            if !walls.calculate(&adjacent) {
-                queue.push_back((step + 1, adjacent));
-            }
-        }
-    }
+    search::count_reachable_within(
+        [Coordinate::from(1, 1)],
+        |coordinate| open_adjacent(coordinate, &walls),
+        limit,
+    )
+}
 
-    visited.len()
+fn open_adjacent(coordinate: &Coordinate, walls: &WallCalculator) -> Vec<Coordinate> {
+    coordinate
+        .get_adjacent()
+        .into_iter()
+        .filter(|adjacent| adjacent.x >= 0 && adjacent.y >= 0 && !walls.calculate(adjacent))
+        .collect()
 }
 
 #[cfg(test)]
@@ -2,12 +2,13 @@ use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
 use md5::{self};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 const KEY_COUNT: usize = 64;
 const STRETCHED_ITERATIONS: usize = 2016;
 const KEY_VERIFICATION_ITERATIONS: usize = 1000;
 
-
 struct CachedHashCalculator {
     cache: HashMap<String, String>,
     hash_algorithm: Box<dyn Hashing>,
@@ -55,14 +56,24 @@ impl Hashing for StretchedHash {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let hasher = CachedHashCalculator::new(BasicHash);
-    generate_keys(input, hasher)
-}
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let hasher = CachedHashCalculator::new(BasicHash);
+        generate_keys(input, hasher).into()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let hasher = CachedHashCalculator::new(StretchedHash);
-    generate_keys(input, hasher)
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let hasher = CachedHashCalculator::new(StretchedHash);
+        generate_keys(input, hasher).into()
+    }
 }
 
 fn generate_keys(salt: &str, mut hasher: CachedHashCalculator) -> usize {
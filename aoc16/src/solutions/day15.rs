@@ -1,19 +1,42 @@
 use regex::Regex;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-struct Disc {
+#[derive(Clone)]
+pub struct Disc {
     positions: usize,
     start_position: usize,
 }
 
-pub fn part1(input: &str) -> usize {
-    let discs = parse(input);
-    find_winning_time(&discs)
-}
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Disc>;
 
-pub fn part2(input: &str) -> usize {
-    let mut discs = parse(input);
-    discs.push(Disc { positions: 11, start_position: 0 });
-    find_winning_time(&discs)
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let disc_regex =
+            Regex::new(r"Disc #\d+ has (\d+) positions; at time=0, it is at position (\d+).").unwrap();
+        Ok(input
+            .lines()
+            .map(move |line| {
+                let captures = disc_regex.captures(line).unwrap();
+                Disc {
+                    positions: captures.get(1).unwrap().as_str().parse().unwrap(),
+                    start_position: captures.get(2).unwrap().as_str().parse().unwrap(),
+                }
+            })
+            .collect())
+    }
+
+    fn part1(discs: &Self::Input<'_>) -> Answer {
+        find_winning_time(discs).into()
+    }
+
+    fn part2(discs: &Self::Input<'_>) -> Answer {
+        let mut discs = discs.clone();
+        discs.push(Disc { positions: 11, start_position: 0 });
+        find_winning_time(&discs).into()
+    }
 }
 
 fn find_winning_time(discs: &[Disc]) -> usize {
@@ -30,22 +53,7 @@ fn wins_capsule(discs: &[Disc], time: usize) -> bool {
     discs
         .iter()
         .enumerate()
-        .all(|(dt, disc)| (disc.start_position + time + dt + 1) % disc.positions == 0)
-}
-
-fn parse(input: &str) -> Vec<Disc> {
-    let disc_regex =
-        Regex::new(r"Disc #\d+ has (\d+) positions; at time=0, it is at position (\d+).").unwrap();
-    input
-        .lines()
-        .map(move |line| {
-            let captures = disc_regex.captures(line).unwrap();
-            Disc {
-                positions: captures.get(1).unwrap().as_str().parse().unwrap(),
-                start_position: captures.get(2).unwrap().as_str().parse().unwrap(),
-            }
-        })
-        .collect()
+        .all(|(dt, disc)| (disc.start_position + time + dt + 1).is_multiple_of(disc.positions))
 }
 
 #[cfg(test)]
@@ -56,6 +64,6 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(5, part1(INPUT));
+        assert_eq!(5, Day15::part1(&Day15::parse(INPUT).unwrap()));
     }
 }
@@ -1,14 +1,26 @@
 use itertools::Itertools;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 const LENGTH_1: usize = 272;
 const LENGTH_2: usize = 35651584;
 
-pub fn part1(input: &str) -> String {
-    generate_checksum_to_fill(input, LENGTH_1)
-}
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
 
-pub fn part2(input: &str) -> String {
-    generate_checksum_to_fill(input, LENGTH_2)
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        generate_checksum_to_fill(input, LENGTH_1).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        generate_checksum_to_fill(input, LENGTH_2).into()
+    }
 }
 
 fn generate_checksum_to_fill(initial_data: &str, limit: usize) -> String {
@@ -46,7 +58,7 @@ fn generate_checksum(data: &str, limit: usize) -> String {
         result.push(ch);
     });
 
-    if result.len() % 2 == 0 {
+    if result.len().is_multiple_of(2) {
         result = generate_checksum(&result, result.len());
     }
 
@@ -1,57 +1,75 @@
-use std::collections::VecDeque;
-
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use util::grid::{Bounds, Coordinate, Direction};
+use util::parse::ParseError;
+use util::search;
+use util::solution::{Answer, Solution};
 
 lazy_static!(
     static ref GOAL: Coordinate = Coordinate::from(3, 3);
     static ref BOUNDS: Bounds = Bounds::from(0..4, 0..4);
 );
 
-pub fn part1(input: &str) -> String {
-    find_shortest_path(*GOAL, input, &BOUNDS)
-}
+pub struct Day17;
 
-pub fn part2(input: &str) -> usize {
-    find_longest_path(*GOAL, input, &BOUNDS).len()
-}
+impl Solution for Day17 {
+    type Input<'a> = &'a str;
 
-fn find_shortest_path(goal: Coordinate, passcode: &str, bounds: &Bounds) -> String {
-    let mut queue = VecDeque::from([(Coordinate::default(), "".to_string())]);
-    while let Some((coordinate, path)) = queue.pop_front() {
-        if coordinate == goal {
-            return path;
-        }
-
-        for (coordinate, next_direction_char) in
-            get_valid_adjacent(&coordinate, bounds, passcode, &path)
-        {
-            queue.push_back((coordinate, format!("{path}{next_direction_char}")));
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        find_shortest_path(*GOAL, input, &BOUNDS).into()
     }
 
-    unreachable!()
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        find_longest_path(*GOAL, input, &BOUNDS).len().into()
+    }
+}
+
+fn find_shortest_path(goal: Coordinate, passcode: &str, bounds: &Bounds) -> String {
+    let (_, path) = search::bfs(
+        [(Coordinate::default(), String::new())],
+        |(coordinate, path)| next_states(coordinate, path, passcode, bounds),
+        |(coordinate, _)| *coordinate == goal,
+    )
+    .unwrap()
+    .nodes
+    .pop()
+    .unwrap();
+
+    path
 }
 
 fn find_longest_path(goal: Coordinate, passcode: &str, bounds: &Bounds) -> String {
-    let mut queue = VecDeque::from([(Coordinate::default(), "".to_string())]);
-    let mut longest_path = String::new();
-
-    while let Some((coordinate, path)) = queue.pop_front() {
-        if coordinate == goal {
-            longest_path = path.clone();
-            continue;
-        }
-
-        for (coordinate, next_direction_char) in
-            get_valid_adjacent(&coordinate, bounds, passcode, &path)
-        {
-            queue.push_back((coordinate, format!("{path}{next_direction_char}")));
-        }
-    }
+    // the vault can not be left again, so every path ends there
+    search::distances(
+        [(Coordinate::default(), String::new())],
+        |(coordinate, path)| match *coordinate == goal {
+            true => Vec::new(),
+            false => next_states(coordinate, path, passcode, bounds),
+        },
+    )
+    .into_keys()
+    .filter(|(coordinate, _)| *coordinate == goal)
+    .map(|(_, path)| path)
+    .max_by_key(String::len)
+    .unwrap_or_default()
+}
 
-    longest_path
+fn next_states(
+    coordinate: &Coordinate,
+    path: &str,
+    passcode: &str,
+    bounds: &Bounds,
+) -> Vec<(Coordinate, String)> {
+    get_valid_adjacent(coordinate, bounds, passcode, path)
+        .into_iter()
+        .map(|(coordinate, next_direction_char)| {
+            (coordinate, format!("{path}{next_direction_char}"))
+        })
+        .collect()
 }
 
 fn get_valid_adjacent(
@@ -108,18 +126,24 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!("DDRRRD".to_string(), part1("ihgpwlah"));
-        assert_eq!("DDUDRLRRUDRD".to_string(), part1("kglvqrro"));
+        assert_eq!(
+            "DDRRRD".to_string(),
+            Day17::part1(&Day17::parse("ihgpwlah").unwrap())
+        );
+        assert_eq!(
+            "DDUDRLRRUDRD".to_string(),
+            Day17::part1(&Day17::parse("kglvqrro").unwrap())
+        );
         assert_eq!(
             "DRURDRUDDLLDLUURRDULRLDUUDDDRR".to_string(),
-            part1("ulqzkmiv")
+            Day17::part1(&Day17::parse("ulqzkmiv").unwrap())
         );
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(370, part2("ihgpwlah"));
-        assert_eq!(492, part2("kglvqrro"));
-        assert_eq!(830, part2("ulqzkmiv"));
+        assert_eq!(370, Day17::part2(&Day17::parse("ihgpwlah").unwrap()));
+        assert_eq!(492, Day17::part2(&Day17::parse("kglvqrro").unwrap()));
+        assert_eq!(830, Day17::part2(&Day17::parse("ulqzkmiv").unwrap()));
     }
 }
@@ -1,15 +1,32 @@
 use std::collections::HashMap;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> usize {
-    let mut traps = parse(input);
-    build_grid(&mut traps, 40, input.len());
-    count_safe(&traps)
-}
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input<'a> = HashMap<(i32, i32), bool>;
 
-pub fn part2(input: &str) -> usize {
-    let mut traps = parse(input);
-    build_grid(&mut traps, 400_000, input.len());
-    count_safe(&traps)
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .char_indices()
+            .map(|(x, ch)| ((x as i32, 0), ch == '^'))
+            .collect())
+    }
+
+    fn part1(traps: &Self::Input<'_>) -> Answer {
+        let width = traps.len();
+        let mut traps = traps.clone();
+        build_grid(&mut traps, 40, width);
+        count_safe(&traps).into()
+    }
+
+    fn part2(traps: &Self::Input<'_>) -> Answer {
+        let width = traps.len();
+        let mut traps = traps.clone();
+        build_grid(&mut traps, 400_000, width);
+        count_safe(&traps).into()
+    }
 }
 
 fn build_grid(traps: &mut HashMap<(i32, i32), bool>, rows_to_add: usize, width: usize) {
@@ -39,13 +56,6 @@ fn count_safe(traps: &HashMap<(i32, i32), bool>) -> usize {
     traps.values().filter(|b| !**b).count()
 }
 
-fn parse(input: &str) -> HashMap<(i32, i32), bool> {
-    input
-        .char_indices()
-        .map(|(x, ch)| ((x as i32, 0), ch == '^'))
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -54,7 +64,7 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        let mut traps = parse(INPUT);
+        let mut traps = Day18::parse(INPUT).unwrap();
         build_grid(&mut traps, 10, INPUT.len());
         count_safe(&traps);
         assert_eq!(38, count_safe(&traps));
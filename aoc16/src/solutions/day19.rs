@@ -1,40 +1,50 @@
 use std::collections::VecDeque;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> usize {
-    let amount = input.parse().unwrap();
-    let mut elves = build_deque(amount, 0);
+pub struct Day19;
 
-    while elves.len() > 1 {
-        // elf taking presents is added to the back of the list
-        elves.rotate_left(1);
-        // elf which has their presents taken is removed from the list
-        elves.pop_front();
+impl Solution for Day19 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
     }
 
-    *elves.front().unwrap()
-}
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let amount = input.parse().unwrap();
+        let mut elves = build_deque(amount, 0);
+
+        while elves.len() > 1 {
+            // elf taking presents is added to the back of the list
+            elves.rotate_left(1);
+            // elf which has their presents taken is removed from the list
+            elves.pop_front();
+        }
+        Answer::from(*elves.front().unwrap())
+    }
 
-pub fn part2(input: &str) -> usize {
-    let amount = input.parse().unwrap();
-    // do not track the middle element, but divide the elements in two different lists
-    let mut left = build_deque(amount / 2, 0);
-    let mut right = build_deque(amount, amount / 2);
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let amount = input.parse().unwrap();
+        // do not track the middle element, but divide the elements in two different lists
+        let mut left = build_deque(amount / 2, 0);
+        let mut right = build_deque(amount, amount / 2);
 
-    while left.len() + right.len() > 1 {
-        let current = left.pop_front().unwrap();
+        while left.len() + right.len() > 1 {
+            let current = left.pop_front().unwrap();
 
-        if left.len() == right.len() {
-            left.pop_back();
-        } else {
-            right.pop_front();
-        }
+            if left.len() == right.len() {
+                left.pop_back();
+            } else {
+                right.pop_front();
+            }
 
-        right.push_back(current);
-        // move the first right value to the back of the left values, as we are moving to the right
-        left.push_back(right.pop_front().unwrap());
+            right.push_back(current);
+            // move the first right value to the back of the left values, as we are moving to the right
+            left.push_back(right.pop_front().unwrap());
+        }
+        Answer::from(*left.front().unwrap())
     }
-
-    *left.front().unwrap()
 }
 
 fn build_deque(amount: usize, starting_from: usize) -> VecDeque<usize> {
@@ -49,11 +59,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(3, part1(INPUT));
+        assert_eq!(3, Day19::part1(&Day19::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(2, part2(INPUT));
+        assert_eq!(2, Day19::part2(&Day19::parse(INPUT).unwrap()));
     }
 }
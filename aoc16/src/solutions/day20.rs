@@ -1,55 +1,45 @@
-pub fn part1(input: &str) -> u32 {
-    let ranges = parse(input);
-    find_lowest_matching(&ranges).unwrap()
-}
-
-pub fn part2(input: &str) -> usize {
-    let ranges = parse(input);
-    count_allowed(&ranges)
-}
+use util::interval::IntervalSet;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-fn find_lowest_matching(ranges: &[(u32, u32)]) -> Option<u32> {
-    let mut last_high = 0;
+const ADDRESS_COUNT: u64 = 1 << 32;
 
-    for &(low, high) in ranges {
-        if last_high < low {
-            return Some(last_high);
-        }
-        // panics if high == u32::MAX. But this does not happen in my case and therefore I wont handle it
-        last_high = last_high.max(high + 1);
-    }
+pub struct Day20;
 
-    None
-}
+impl Solution for Day20 {
+    type Input<'a> = IntervalSet<u64>;
 
-fn count_allowed(ranges: &[(u32, u32)]) -> usize {
-    let mut allowed_count = 0;
-    let mut last_high = 0;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let (low, high) = line.split_once('-').unwrap();
+                let (low, high): (u64, u64) = (low.parse().unwrap(), high.parse().unwrap());
 
-    for &(low, high) in ranges {
-        if last_high < low {
-            allowed_count += (low - last_high) as usize;
-        }
+                low..high + 1
+            })
+            .collect())
+    }
 
-        last_high = if high == u32::MAX {
-            u32::MAX
-        } else {
-            last_high.max(high + 1)
-        }
+    fn part1(blocked: &Self::Input<'_>) -> Answer {
+        blocked.first_gap(0).into()
     }
 
-    allowed_count + (u32::MAX - last_high) as usize
+    fn part2(blocked: &Self::Input<'_>) -> Answer {
+        let (blocked, _) = blocked.split_at(ADDRESS_COUNT);
+
+        (ADDRESS_COUNT - blocked.total_length()).into()
+    }
 }
 
-fn parse(input: &str) -> Vec<(u32, u32)> {
-    let mut ranges: Vec<(u32, u32)> = input
-        .lines()
-        .map(|line| {
-            let (low, high) = line.split_once('-').unwrap();
-            (low.parse().unwrap(), high.parse().unwrap())
-        })
-        .collect();
-    ranges.sort();
-
-    ranges
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/day20.txt");
+
+    #[test]
+    fn part1_ex() {
+        assert_eq!(3, Day20::part1(&Day20::parse(INPUT).unwrap()));
+    }
 }
@@ -2,6 +2,8 @@ use std::collections::VecDeque;
 
 use itertools::Itertools;
 use util::grid::Direction;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 const INITIAL_PASSWORD: &str = "abcdefgh";
 const SCRAMBLED_PASSWORD: &str = "fbgdceah";
@@ -13,7 +15,7 @@ struct RotatePosition(char);
 struct Reverse(usize, usize);
 struct Move(usize, usize);
 
-trait Execute {
+pub trait Execute {
     fn execute(&self, data: &mut VecDeque<char>);
 }
 
@@ -79,14 +81,28 @@ impl Execute for Move {
     }
 }
 
-pub fn part1(input: &str) -> String {
-    let operations = parse(input);
-    scramble_password(INITIAL_PASSWORD, &operations)
-}
+pub struct Day21;
+
+impl Solution for Day21 {
+    type Input<'a> = Vec<Box<dyn Execute>>;
 
-pub fn part2(input: &str) -> String {
-    let operations = parse(input);
-    unscramble_password(INITIAL_PASSWORD, SCRAMBLED_PASSWORD, &operations).unwrap()
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let parts = line.split_whitespace().collect_vec();
+                build_operation(&parts)
+            })
+            .collect())
+    }
+
+    fn part1(operations: &Self::Input<'_>) -> Answer {
+        scramble_password(INITIAL_PASSWORD, operations).into()
+    }
+
+    fn part2(operations: &Self::Input<'_>) -> Answer {
+        unscramble_password(INITIAL_PASSWORD, SCRAMBLED_PASSWORD, operations).unwrap().into()
+    }
 }
 
 fn scramble_password(initial_password: &str, operations: &[Box<dyn Execute>]) -> String {
@@ -127,16 +143,6 @@ fn position(data: &VecDeque<char>, searching: char) -> Option<usize> {
     None
 }
 
-fn parse(input: &str) -> Vec<Box<dyn Execute>> {
-    input
-        .lines()
-        .map(|line| {
-            let parts = line.split_whitespace().collect_vec();
-            build_operation(&parts)
-        })
-        .collect()
-}
-
 fn build_operation(parts: &[&str]) -> Box<dyn Execute> {
     match parts[0] {
         "swap" => match parts[1] {
@@ -260,7 +266,7 @@ mod tests {
 
     #[test]
     fn scramble_password_example() {
-        let operations = parse(INPUT);
+        let operations = Day21::parse(INPUT).unwrap();
         assert_eq!(
             "decab".to_string(),
             scramble_password(INITIAL_PASSWORD, &operations)
@@ -1,46 +1,33 @@
 use crate::solutions::computer::{Computer, Operation};
+use util::parse::{self, ParseError};
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> i32 {
-    let instructions = parse(input);
-    let mut computer = Computer::new(instructions);
-    computer.insert("a".to_string(), 7);
-    computer.run();
+pub struct Day23;
 
-    computer.get("a").unwrap()
-}
+impl Solution for Day23 {
+    type Input<'a> = Vec<Operation>;
 
-pub fn part2(input: &str) -> i32 {
-    /*
-    Added support for a multiplication instruction as hinted in the puzzle description.
-    A section like:
-
-    cpy b c
-    inc a
-    dec c
-    jnz c -2
-    dec d
-    jnz d -5
-
-    can now be replaced with:
-    mul a d b
-    cpy 0 c
-    cpy 0 d
-    noop
-    noop
-    noop
-
-    This only works when no toggle instruction is executed on that block of instructions.
-    */
-    let instructions = parse(input);
-    let mut computer = Computer::new(instructions);
-    computer.insert("a".to_string(), 12);
-    computer.run();
-
-    computer.get("a").unwrap()
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::map_lines(input, Operation::from)
+    }
+
+    fn part1(instructions: &Self::Input<'_>) -> Answer {
+        let mut computer = Computer::new(instructions.clone());
+        computer.insert('a', 7);
+        computer.run();
+
+        computer.get('a').into()
+    }
 
-fn parse(input: &str) -> Vec<Operation> {
-    input.lines().map(Operation::from).collect()
+    fn part2(instructions: &Self::Input<'_>) -> Answer {
+        // the multiplication hinted at in the puzzle description is found by the optimiser of
+        // the computer, so the nested loops take a single step
+        let mut computer = Computer::new(instructions.clone());
+        computer.insert('a', 12);
+        computer.run();
+
+        computer.get('a').into()
+    }
 }
 
 #[cfg(test)]
@@ -48,9 +35,18 @@ mod tests {
     use super::*;
 
     const INPUT: &str = include_str!("../../examples/day23.txt");
+    const FACTORIAL: &str = include_str!("../../examples/day23_factorial.txt");
 
     #[test]
     fn part1_ex() {
-        assert_eq!(3, part1(INPUT));
+        assert_eq!(3, Day23::part1(&Day23::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn factorial_is_calculated_with_toggled_loops() {
+        let program = Day23::parse(FACTORIAL).unwrap();
+
+        assert_eq!(5040 + 94 * 99, Day23::part1(&program));
+        assert_eq!(479_001_600 + 94 * 99, Day23::part2(&program));
     }
 }
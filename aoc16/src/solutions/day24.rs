@@ -1,7 +1,9 @@
-use itertools::Itertools;
 use rayon::prelude::*;
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
 use util::grid::Coordinate;
+use util::parse::ParseError;
+use util::search;
+use util::solution::{Answer, Solution};
 
 struct Pathfinder {
     valid: HashSet<Coordinate>,
@@ -38,24 +40,22 @@ impl Pathfinder {
     }
 
     fn compute_distances(&mut self) {
-        let distances: Vec<(char, char, usize)> = self
+        let distances: Vec<((char, char), usize)> = self
             .named_coordinates
-            .keys()
-            .tuple_combinations()
+            .iter()
             .par_bridge()
-            .map(|(start, goal)| {
-                let start_coordinate = *self.named_coordinates.get(start).unwrap();
-                let goal_coordinate = *self.named_coordinates.get(goal).unwrap();
-                let distance = self.shortest_path_between(start_coordinate, goal_coordinate);
-
-                (*start, *goal, distance)
+            .flat_map_iter(|(start, start_coordinate)| {
+                let reachable = self.distances_from(*start_coordinate);
+
+                self.named_coordinates
+                    .iter()
+                    .map(move |(goal, goal_coordinate)| {
+                        ((*start, *goal), *reachable.get(goal_coordinate).unwrap())
+                    })
             })
             .collect();
 
-        for (ch1, ch2, distance) in distances {
-            self.distances.insert((ch1, ch2), distance);
-            self.distances.insert((ch2, ch1), distance);
-        }
+        self.distances.extend(distances);
     }
 
     /// depth first search which prunes branches taking longer than the current shortest path
@@ -97,63 +97,52 @@ impl Pathfinder {
             .collect()
     }
 
-    fn shortest_path_between(&self, start: Coordinate, goal: Coordinate) -> usize {
-        let mut queue = VecDeque::from([(0, start)]);
-        let mut visited = HashSet::new();
-
-        while let Some((steps, coordinate)) = queue.pop_front() {
-            if coordinate == goal {
-                return steps;
-            }
+    fn distances_from(&self, start: Coordinate) -> HashMap<Coordinate, usize> {
+        search::distances([start], |coordinate| {
+            coordinate
+                .get_adjacent()
+                .into_iter()
+                .filter(|adjacent| self.valid.contains(adjacent))
+        })
+    }
+}
 
-            if visited.contains(&coordinate) {
-                continue;
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = (HashSet<Coordinate>, HashMap<char, Coordinate>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut valid = HashSet::new();
+        let mut named_coordinates = HashMap::new();
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, ch) in line.chars().enumerate() {
+                if ch == '#' {
+                    continue;
+                }
+                let coordinate = Coordinate::from(x as i32, y as i32);
+                valid.insert(coordinate);
+                if ch.is_ascii_digit() {
+                    named_coordinates.insert(ch, coordinate);
+                }
             }
-            visited.insert(coordinate);
-
-            queue.extend(
-                coordinate
-                    .get_adjacent()
-                    .into_iter()
-                    .filter(|adjacent| self.valid.contains(adjacent))
-                    .map(|adjacent| (steps + 1, adjacent)),
-            );
         }
 
-        unreachable!()
+        Ok((valid, named_coordinates))
     }
-}
 
-pub fn part1(input: &str) -> usize {
-    let (valid, named_coordinates) = parse(input);
-    let mut pathfinder = Pathfinder::new(valid, named_coordinates, false);
-    pathfinder.shortest_path_visiting_all()
-}
-
-pub fn part2(input: &str) -> usize {
-    let (valid, named_coordinates) = parse(input);
-    let mut pathfinder = Pathfinder::new(valid, named_coordinates, true);
-    pathfinder.shortest_path_visiting_all()
-}
-
-fn parse(input: &str) -> (HashSet<Coordinate>, HashMap<char, Coordinate>) {
-    let mut valid = HashSet::new();
-    let mut named_coordinates = HashMap::new();
-
-    for (y, line) in input.lines().enumerate() {
-        for (x, ch) in line.chars().enumerate() {
-            if ch == '#' {
-                continue;
-            }
-            let coordinate = Coordinate::from(x as i32, y as i32);
-            valid.insert(coordinate);
-            if ch.is_ascii_digit() {
-                named_coordinates.insert(ch, coordinate);
-            }
-        }
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (valid, named_coordinates) = input;
+        let mut pathfinder = Pathfinder::new(valid.clone(), named_coordinates.clone(), false);
+        pathfinder.shortest_path_visiting_all().into()
     }
 
-    (valid, named_coordinates)
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (valid, named_coordinates) = input;
+        let mut pathfinder = Pathfinder::new(valid.clone(), named_coordinates.clone(), true);
+        pathfinder.shortest_path_visiting_all().into()
+    }
 }
 
 #[cfg(test)]
@@ -164,6 +153,6 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(14, part1(INPUT));
+        assert_eq!(14, Day24::part1(&Day24::parse(INPUT).unwrap()));
     }
 }
@@ -1,26 +1,75 @@
+use std::collections::HashSet;
+
 use crate::solutions::computer::{Computer, Operation};
+use util::parse::{self, ParseError};
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> i32 {
-    let instructions = parse(input);
+pub struct Day25;
 
-    let mut i = 0;
-    loop {
-        let mut computer = Computer::new(instructions.clone());
-        computer.insert("a".to_string(), i);
+impl Solution for Day25 {
+    type Input<'a> = Vec<Operation>;
 
-        if computer.run_to_output_length(1_000) {
-            break;
-        }
-        i += 1;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::map_lines(input, Operation::from)
     }
 
-    i
+    fn part1(instructions: &Self::Input<'_>) -> Answer {
+        (0..)
+            .find(|&a| produces_clock_signal(instructions, a))
+            .unwrap()
+            .into()
+    }
+
+    fn part2(_: &Self::Input<'_>) -> Answer {
+        "The Easter Bunny 🐇 has been defeated. Christmas wins 🎅".into()
+    }
 }
 
-pub fn part2(_: &str) -> &str {
-    "The Easter Bunny 🐇 has been defeated. Christmas wins 🎅"
+/// Whether the program outputs `0, 1, 0, 1, ...` forever when `a` starts with the value.
+/// Once the computer is back in a state it had after an output of the same value, the outputs
+/// repeat from there, so the program only runs until then.
+fn produces_clock_signal(instructions: &[Operation], a: i64) -> bool {
+    let mut computer = Computer::new(instructions.to_vec());
+    computer.insert('a', a);
+
+    let mut seen = HashSet::new();
+    let mut expected = 0;
+    while let Some(output) = computer.next_output() {
+        if output != expected {
+            return false;
+        }
+        if !seen.insert((computer.state(), output)) {
+            return true;
+        }
+        expected = 1 - expected;
+    }
+
+    false
 }
 
-fn parse(input: &str) -> Vec<Operation> {
-    input.lines().map(Operation::from).collect()
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = include_str!("../../examples/day25.txt");
+
+    #[test]
+    fn part1_ex() {
+        assert_eq!(2, Day25::part1(&Day25::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn programs_ending_are_no_clock_signal() {
+        let instructions = Day25::parse("out 0\nout 1\nout 0").unwrap();
+
+        assert!(!produces_clock_signal(&instructions, 0));
+    }
+
+    #[test]
+    fn toggled_programs_only_repeat_with_the_same_instructions() {
+        // the second round skips `out 1` and the `tgl` restores the first round's program
+        let instructions = Day25::parse("out 0\ncpy 1 2\nout 1\ntgl -2\njnz 1 -4").unwrap();
+
+        assert!(!produces_clock_signal(&instructions, 0));
+    }
 }
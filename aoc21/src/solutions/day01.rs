@@ -1,35 +1,43 @@
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
 fn convert(line: &str) -> i32 {
     line.parse().unwrap()
 }
 
-fn parse(input: &str) -> Vec<i32> {
-    input.lines()
-        .map(convert)
-        .collect()
-}
+pub struct Day01;
 
-pub fn part1(input: &str) -> i32 {
-    let nums = parse(input);
-    let mut count = 0;
+impl Solution for Day01 {
+    type Input<'a> = Vec<i32>;
 
-    for (cur, next) in nums.iter().zip(nums.iter().skip(1)) {
-        if next > cur {
-            count += 1;
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines()
+            .map(convert)
+            .collect())
     }
 
-    count
-}
-
-pub fn part2(input: &str) -> i32 {
-    let nums = parse(input);
-    let mut count = 0;
+    fn part1(nums: &Self::Input<'_>) -> Answer {
+        let mut count = 0;
 
-    for (cur, next) in nums.iter().zip(nums.iter().skip(3)) {
-        if next > cur {
-            count += 1;
+        for (cur, next) in nums.iter().zip(nums.iter().skip(1)) {
+            if next > cur {
+                count += 1;
+            }
         }
+
+        count.into()
     }
 
-    count
+    fn part2(nums: &Self::Input<'_>) -> Answer {
+        let mut count = 0;
+
+        for (cur, next) in nums.iter().zip(nums.iter().skip(3)) {
+            if next > cur {
+                count += 1;
+            }
+        }
+
+        count.into()
+    }
 }
+
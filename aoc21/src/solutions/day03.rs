@@ -1,27 +1,38 @@
-pub fn part1(input: &str) -> u32 {
-    let numbers = parse(input);
-
-    let mut gamma: u32 = 0;
-    let mut epsilon: u32 = 0;
-    for i in 0..numbers[0].len() {
-        let b = most_common_at(&numbers, i);
-        gamma <<= 1;
-        gamma += b as u32;
-
-        epsilon <<= 1;
-        epsilon += (b as u32 + 1) % 2;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input<'a> = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines()
+            .map(|l| l.chars()
+                .map(|c| c.to_digit(10).unwrap() as u8).collect())
+            .collect())
     }
 
-    gamma * epsilon
-}
+    fn part1(numbers: &Self::Input<'_>) -> Answer {
+        let mut gamma: u32 = 0;
+        let mut epsilon: u32 = 0;
+        for i in 0..numbers[0].len() {
+            let b = most_common_at(numbers, i);
+            gamma <<= 1;
+            gamma += b as u32;
 
-pub fn part2(input: &str) -> u32 {
-    let numbers = parse(input);
+            epsilon <<= 1;
+            epsilon += (b as u32 + 1) % 2;
+        }
+        (gamma * epsilon).into()
+    }
+
+    fn part2(numbers: &Self::Input<'_>) -> Answer {
+        let oxygen = find_rating(numbers.clone(), false);
+        let co2 = find_rating(numbers.clone(), true);
 
-    let oxygen = find_rating(numbers.clone(), false);
-    let co2 = find_rating(numbers, true);
-    
-    oxygen * co2
+        (oxygen * co2).into()
+    }
 }
 
 fn find_rating(mut list: Vec<Vec<u8>>, co2: bool) -> u32 {
@@ -61,9 +72,3 @@ fn most_common_at(list: &[Vec<u8>], pos: usize) -> u8 {
     u8::from(count >= list.len() as f64 / 2.0)
 }
 
-fn parse(input: &str) -> Vec<Vec<u8>> {
-    input.lines()
-        .map(|l| l.chars()
-            .map(|c| c.to_digit(10).unwrap() as u8).collect())
-        .collect()
-}
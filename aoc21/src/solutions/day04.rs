@@ -1,6 +1,9 @@
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
 #[allow(unused)]
-#[derive(Debug)]
-struct Bingo {
+#[derive(Debug, Clone)]
+pub struct Bingo {
     numbers: Vec<i32>,
     boards: Vec<Board>,
 }
@@ -26,8 +29,8 @@ impl Bingo {
     }
 }
 
-#[derive(Debug)]
-struct Board {
+#[derive(Debug, Clone)]
+pub struct Board {
     grid: [[i32; 5]; 5],
     guessed: [[bool; 5]; 5],
 }
@@ -93,40 +96,46 @@ impl Board {
     }
 }
 
-pub fn part1(input: &str) -> i32 {
-    let mut bingo = parse(input);
-    bingo.play_game(true).unwrap()
-}
+pub struct Day04;
 
-pub fn part2(input: &str) -> i32 {
-    let mut bingo = parse(input);
-    bingo.play_game(false).unwrap()
-}
+impl Solution for Day04 {
+    type Input<'a> = Bingo;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let input = input.replace('\r', ""); // remove windows carriage return for files read in from windows
+        let mut input = input.split("\n\n");
 
-fn parse(input: &str) -> Bingo {
-    let input = input.replace('\r', ""); // remove windows carriage return for files read in from windows
-    let mut input = input.split("\n\n");
-
-    let numbers: Vec<i32> = input
-        .next()
-        .unwrap()
-        .split(',')
-        .map(|n| n.parse().unwrap())
-        .collect();
-
-    let boards: Vec<Board> = input
-        .map(|chunk| {
-            let mut grid = [[0; 5]; 5];
-            for (y, line) in chunk.lines().enumerate() {
-                for (x, num) in line.split_whitespace().enumerate() {
-                    grid[y][x] = num.parse().unwrap();
+        let numbers: Vec<i32> = input
+            .next()
+            .unwrap()
+            .split(',')
+            .map(|n| n.parse().unwrap())
+            .collect();
+
+        let boards: Vec<Board> = input
+            .map(|chunk| {
+                let mut grid = [[0; 5]; 5];
+                for (y, line) in chunk.lines().enumerate() {
+                    for (x, num) in line.split_whitespace().enumerate() {
+                        grid[y][x] = num.parse().unwrap();
+                    }
                 }
-            }
-            Board::new(grid)
-        })
-        .collect();
+                Board::new(grid)
+            })
+            .collect();
+
+        Ok(Bingo { numbers, boards })
+    }
 
-    Bingo { numbers, boards }
+    fn part1(bingo: &Self::Input<'_>) -> Answer {
+        let mut bingo = bingo.clone();
+        bingo.play_game(true).unwrap().into()
+    }
+
+    fn part2(bingo: &Self::Input<'_>) -> Answer {
+        let mut bingo = bingo.clone();
+        bingo.play_game(false).unwrap().into()
+    }
 }
 
 #[cfg(test)]
@@ -156,7 +165,7 @@ mod tests {
 22 11 13  6  5
  2  0 12  3  7";
 
-        assert_eq!(part1(input), 4512);
+        assert_eq!(Day04::part1(&Day04::parse(input).unwrap()), 4512);
 
     }
 
@@ -171,7 +180,7 @@ mod tests {
  6 10  3 18  5
  1 12 20 15 19";
 
-        let bingo = parse(input);
+        let bingo = Day04::parse(input).unwrap();
         
         assert_eq!(bingo.boards[0].sum_unmarked(), 300);
     }
@@ -187,7 +196,7 @@ mod tests {
  6 10  3 18  5
  1 12 20 15 19";
 
-        let mut bingo = parse(input);
+        let mut bingo = Day04::parse(input).unwrap();
         bingo.boards[0].guess(&23);
         bingo.boards[0].guess(&16);
         bingo.boards[0].guess(&6);
@@ -219,7 +228,7 @@ mod tests {
 22 11 13  6  5
  2  0 12  3  7";
 
-        assert_eq!(part2(input), 1924);
+        assert_eq!(Day04::part2(&Day04::parse(input).unwrap()), 1924);
 
     }
 
@@ -1,7 +1,9 @@
 use regex::Regex;
 use std::collections::HashMap;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-struct Line {
+pub struct Line {
     start: (i32, i32),
     end: (i32, i32),
 }
@@ -27,49 +29,53 @@ impl Line {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let lines = parse(input);
-    let mut map: HashMap<(i32, i32), i32> = HashMap::new();
+pub struct Day05;
 
-    for line in lines {
-        if line.is_straight() {
-            for point in line.points_between() {
-                *map.entry(point).or_insert(0) += 1;
-            }
-        }
-    }
+impl Solution for Day05 {
+    type Input<'a> = Vec<Line>;
 
-    map.values().filter(|v| *v > &1).count()
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let re = Regex::new(r"(\d+),(\d+) -> (\d+),(\d+)").unwrap();
 
-pub fn part2(input: &str) -> usize {
-    let lines = parse(input);
-    let mut map: HashMap<(i32, i32), i32> = HashMap::new();
+        Ok(input
+            .lines()
+            .map(|line| {
+                let capture = re.captures_iter(line).next().unwrap();
+                let coords: Vec<i32> = (1..5).map(|i| capture[i].parse().unwrap()).collect();
 
-    for line in lines {
-        for point in line.points_between() {
-            *map.entry(point).or_insert(0) += 1;
-        }
+                let start = (coords[0], coords[1]);
+                let end = (coords[2], coords[3]);
+
+                Line { start, end }
+            })
+            .collect())
     }
 
-    map.values().filter(|v| *v > &1).count()
-}
+    fn part1(lines: &Self::Input<'_>) -> Answer {
+        let mut map: HashMap<(i32, i32), i32> = HashMap::new();
 
-fn parse(input: &str) -> Vec<Line> {
-    let re = Regex::new(r"(\d+),(\d+) -> (\d+),(\d+)").unwrap();
+        for line in lines {
+            if line.is_straight() {
+                for point in line.points_between() {
+                    *map.entry(point).or_insert(0) += 1;
+                }
+            }
+        }
 
-    input
-        .lines()
-        .map(|line| {
-            let capture = re.captures_iter(line).next().unwrap();
-            let coords: Vec<i32> = (1..5).map(|i| capture[i].parse().unwrap()).collect();
+        map.values().filter(|v| *v > &1).count().into()
+    }
 
-            let start = (coords[0], coords[1]);
-            let end = (coords[2], coords[3]);
+    fn part2(lines: &Self::Input<'_>) -> Answer {
+        let mut map: HashMap<(i32, i32), i32> = HashMap::new();
 
-            Line { start, end }
-        })
-        .collect()
+        for line in lines {
+            for point in line.points_between() {
+                *map.entry(point).or_insert(0) += 1;
+            }
+        }
+
+        map.values().filter(|v| *v > &1).count().into()
+    }
 }
 
 #[cfg(test)]
@@ -90,7 +96,7 @@ mod tests {
 0,0 -> 8,8
 5,5 -> 8,2";
 
-        assert_eq!(part1(input), 5);
+        assert_eq!(Day05::part1(&Day05::parse(input).unwrap()), 5);
     }
 
     #[test]
@@ -107,6 +113,6 @@ mod tests {
 0,0 -> 8,8
 5,5 -> 8,2";
 
-        assert_eq!(part2(input), 12);
+        assert_eq!(Day05::part2(&Day05::parse(input).unwrap()), 12);
     }
 }
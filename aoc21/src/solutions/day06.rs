@@ -1,13 +1,29 @@
 use std::collections::LinkedList;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> u64 {
-    let arr = parse(input);
-    day_step(arr, 80)
-}
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = [u64; 9];
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut arr = [0; 9];
+
+        for n in input.trim().split(',') {
+            arr[n.parse::<usize>().unwrap()] += 1;
+        }
+
+        Ok(arr)
+    }
+
+    fn part1(arr: &Self::Input<'_>) -> Answer {
+        day_step(*arr, 80).into()
+    }
 
-pub fn part2(input: &str) -> u64 {
-    let arr = parse(input); 
-    day_step(arr, 256)
+    fn part2(arr: &Self::Input<'_>) -> Answer {
+        day_step(*arr, 256).into()
+    }
 }
 
 fn day_step(arr: [u64; 9], num: usize) -> u64 {
@@ -26,17 +42,6 @@ fn sum_fish(list: LinkedList<u64>) -> u64 {
     list.iter().sum()
 }
 
-fn parse(input: &str) -> [u64; 9] {
-    let mut arr = [0; 9];
-        
-    for n in input.trim().split(',') {
-        arr[n.parse::<usize>().unwrap()] += 1;
-    }
-
-    arr
-}
-
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -45,13 +50,13 @@ mod tests {
     fn part1_ex() {
         let input = "3,4,3,1,2";
 
-        assert_eq!(part1(input), 5934);
+        assert_eq!(Day06::part1(&Day06::parse(input).unwrap()), 5934);
     }
 
     #[test]
     fn part2_ex() {
         let input = "3,4,3,1,2";
 
-        assert_eq!(part2(input), 26984457539);
+        assert_eq!(Day06::part2(&Day06::parse(input).unwrap()), 26984457539);
     }
 }
\ No newline at end of file
@@ -1,27 +1,34 @@
-pub fn part1(input: &str) -> i32 {
-    let nums = parse(input);
-    let median = util::median(&nums[..]) as i32;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-    nums.iter().map(|n| (n - median).abs()).sum()
-}
+pub struct Day07;
 
-pub fn part2(input: &str) -> i32 {
-    let mut nums = parse(input);
-    nums.sort();
+impl Solution for Day07 {
+    type Input<'a> = Vec<i32>;
 
-    let range = nums[0]..nums[nums.len() - 1];
-    range.map(|i| {
-        nums.iter().map(|n| util::triangular(n.abs_diff(i)))
-            .sum::<u32>()
-    }).min().unwrap() as i32
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .trim()
+            .split(',')
+            .map(|n| n.parse().unwrap())
+            .collect())
+    }
 
-fn parse(input: &str) -> Vec<i32> {
-    input
-        .trim()
-        .split(',')
-        .map(|n| n.parse().unwrap())
-        .collect()
+    fn part1(nums: &Self::Input<'_>) -> Answer {
+        let median = util::median(&nums[..]) as i32;
+        nums.iter().map(|n| (n - median).abs()).sum::<i32>().into()
+    }
+
+    fn part2(nums: &Self::Input<'_>) -> Answer {
+        let mut nums = nums.clone();
+        nums.sort();
+
+        let range = nums[0]..nums[nums.len() - 1];
+        Answer::from(range.map(|i| {
+            nums.iter().map(|n| util::triangular(n.abs_diff(i)))
+                .sum::<u32>()
+        }).min().unwrap() as i32)
+    }
 }
 
 #[cfg(test)]
@@ -32,13 +39,13 @@ mod tests {
     fn part1_ex() {
         let input = "16,1,2,0,4,2,7,1,2,14";
 
-        assert_eq!(part1(input), 37);
+        assert_eq!(Day07::part1(&Day07::parse(input).unwrap()), 37);
     }
 
     #[test]
     fn part2_ex() {
         let input = "16,1,2,0,4,2,7,1,2,14";
 
-        assert_eq!(part2(input), 168);
+        assert_eq!(Day07::part2(&Day07::parse(input).unwrap()), 168);
     }
 }
@@ -1,58 +1,65 @@
 use std::collections::HashMap;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> usize {
-    input
-        .lines()
-        .map(|line| line.split_once(" | ").unwrap().1)
-        .map(|output| {
-            output
-                .split_whitespace()
-                .filter(|seg| {
-                    let chars = seg.chars().count();
-                    chars == 2 || chars == 3 || chars == 4 || chars == 7
-                })
-                .count()
-        })
-        .sum()
-}
+pub struct Day08;
 
-pub fn part2(input: &str) -> usize {
-    let count_to_number = HashMap::from([
-        ("467889".to_string(), 0),
-        ("89".to_string(), 1),
-        ("47788".to_string(), 2),
-        ("77889".to_string(), 3),
-        ("6789".to_string(), 4),
-        ("67789".to_string(), 5),
-        ("467789".to_string(), 6),
-        ("889".to_string(), 7),
-        ("4677889".to_string(), 8),
-        ("677889".to_string(), 9),
-    ]);
+impl Solution for Day08 {
+    /// The signal patterns and the output value of every display.
+    type Input<'a> = Vec<(&'a str, &'a str)>;
 
-    let list = parse(input);
-    list.iter()
-        .map(|digits| {
-            digits
-                .iter()
-                .map(|count| count_to_number.get(count).unwrap().to_string())
-                .collect::<Vec<String>>()
-                .join("")
-                .parse::<usize>()
-                .unwrap()
-        })
-        .sum::<usize>()
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| line.split_once(" | ").unwrap())
+            .collect())
+    }
 
-fn parse(input: &str) -> Vec<Vec<String>> {
-    input
-        .lines()
-        .map(|line| {
-            let (first, second) = line.split_once(" | ").unwrap();
-            let map = count_segments(first);
-            convert_to_sorted_counts(&map, second)
-        })
-        .collect()
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input
+            .iter()
+            .map(|(_, output)| {
+                output
+                    .split_whitespace()
+                    .filter(|seg| {
+                        let chars = seg.chars().count();
+                        chars == 2 || chars == 3 || chars == 4 || chars == 7
+                    })
+                    .count()
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let count_to_number = HashMap::from([
+            ("467889".to_string(), 0),
+            ("89".to_string(), 1),
+            ("47788".to_string(), 2),
+            ("77889".to_string(), 3),
+            ("6789".to_string(), 4),
+            ("67789".to_string(), 5),
+            ("467789".to_string(), 6),
+            ("889".to_string(), 7),
+            ("4677889".to_string(), 8),
+            ("677889".to_string(), 9),
+        ]);
+
+        input
+            .iter()
+            .map(|(patterns, output)| convert_to_sorted_counts(&count_segments(patterns), output))
+            .map(|digits| {
+                digits
+                    .iter()
+                    .map(|count| count_to_number.get(count).unwrap().to_string())
+                    .collect::<Vec<String>>()
+                    .join("")
+                    .parse::<usize>()
+                    .unwrap()
+            })
+            .sum::<usize>()
+            .into()
+    }
 }
 
 fn convert_to_sorted_counts(count_map: &HashMap<char, usize>, part: &str) -> Vec<String> {
@@ -1,23 +1,38 @@
 use itertools::Itertools;
 use std::collections::HashSet;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> u32 {
-    let grid = parse(input); 
+pub struct Day09;
 
-    find_low_points(&grid).iter()
-        .map(|&(x, y)| grid[y][x] + 1)
-        .sum()
-}
+impl Solution for Day09 {
+    type Input<'a> = Vec<Vec<u32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| line.chars()
+                .map(|c| c.to_digit(10).unwrap())
+                .collect())
+            .collect())
+    }
 
-pub fn part2(input: &str) -> u32 {
-    let grid = parse(input); 
+    fn part1(grid: &Self::Input<'_>) -> Answer {
+        find_low_points(grid).iter()
+            .map(|&(x, y)| grid[y][x] + 1)
+            .sum::<u32>()
+            .into()
+    }
 
-    find_low_points(&grid).iter()
-        .map(|&(x, y)| calculate_basin_area(x, y, &grid))
-        .sorted()
-        .rev()
-        .take(3)
-        .product()
+    fn part2(grid: &Self::Input<'_>) -> Answer {
+        find_low_points(grid).iter()
+            .map(|&(x, y)| calculate_basin_area(x, y, grid))
+            .sorted()
+            .rev()
+            .take(3)
+            .product::<u32>()
+            .into()
+    }
 }
 
 fn calculate_basin_area(x: usize, y: usize, grid: &[Vec<u32>]) -> u32 {
@@ -54,15 +69,6 @@ fn find_low_points(grid: &[Vec<u32>]) -> Vec<(usize, usize)> {
         .collect()
 }
 
-fn parse(input: &str) -> Vec<Vec<u32>> {
-    input
-        .lines()
-        .map(|line| line.chars()
-            .map(|c| c.to_digit(10).unwrap())
-            .collect())
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -76,7 +82,7 @@ mod tests {
 8767896789
 9899965678";
 
-        assert_eq!(15, part1(input));
+        assert_eq!(15, Day09::part1(&Day09::parse(input).unwrap()));
     } 
 
     #[test]
@@ -88,10 +94,9 @@ mod tests {
 8767896789
 9899965678";
 
-        assert_eq!(1134, part2(input));
+        assert_eq!(1134, Day09::part2(&Day09::parse(input).unwrap()));
     }
 
-
     #[test]
     fn find_correct_basin_size() {
         let input = "\
@@ -100,7 +105,7 @@ mod tests {
 9856789892
 8767896789
 9899965678";
-        let grid = parse(input);
+        let grid = Day09::parse(input).unwrap();
 
         assert_eq!(calculate_basin_area(2, 2, &grid), 14);
 
@@ -1,40 +1,52 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 enum BraketResult {
     Error(char),
     NotClosed(Vec<char>),
 }
 
-pub fn part1(input: &str) -> u32 {
-    let values: HashMap<char, u32> = HashMap::from([
-        (')', 3),
-        (']', 57),
-        ('}', 1197),
-        ('>', 25137),
-    ]);
-    input.lines()
-        .map(find_error)
-        .filter_map(|opt| match opt {
-            BraketResult::Error(c) => Some(c),
-            BraketResult::NotClosed(_) => None,
-        })
-        .map(|c| values.get(&c).unwrap())
-        .sum()
-}
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let values: HashMap<char, u32> = HashMap::from([
+            (')', 3),
+            (']', 57),
+            ('}', 1197),
+            ('>', 25137),
+        ]);
+        input.lines()
+            .map(find_error)
+            .filter_map(|opt| match opt {
+                BraketResult::Error(c) => Some(c),
+                BraketResult::NotClosed(_) => None,
+            })
+            .map(|c| values.get(&c).unwrap())
+            .sum::<u32>()
+            .into()
+    }
 
-pub fn part2(input: &str) -> u64 {
-    let sorted_scores: Vec<u64> = input.lines()
-        .filter_map(|line| match find_error(line) {
-            BraketResult::NotClosed(vec) => Some(vec),
-            BraketResult::Error(_) => None,
-        })
-        .map(calc_closing)
-        .sorted()
-        .collect();
-
-    sorted_scores[sorted_scores.len() / 2]
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let sorted_scores: Vec<u64> = input.lines()
+            .filter_map(|line| match find_error(line) {
+                BraketResult::NotClosed(vec) => Some(vec),
+                BraketResult::Error(_) => None,
+            })
+            .map(calc_closing)
+            .sorted()
+            .collect();
+        sorted_scores[sorted_scores.len() / 2].into()
+    }
 }
 
 fn calc_closing(stack: Vec<char>) -> u64 {
@@ -92,7 +104,7 @@ mod tests {
 <{([([[(<>()){}]>(<<{{
 <{([{{}}[<[[[<>{}]]]>[]]";
 
-        assert_eq!(part1(input), 26397);
+        assert_eq!(Day10::part1(&Day10::parse(input).unwrap()), 26397);
     }
 
     #[test]
@@ -109,7 +121,7 @@ mod tests {
 <{([([[(<>()){}]>(<<{{
 <{([{{}}[<[[[<>{}]]]>[]]";
 
-        assert_eq!(part2(input), 288957);
+        assert_eq!(Day10::part2(&Day10::parse(input).unwrap()), 288957);
     }
 
     #[test]
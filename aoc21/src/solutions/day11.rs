@@ -1,27 +1,46 @@
 use itertools::Itertools;
 use std::collections::HashSet;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Vec<u8>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| c.to_digit(10).unwrap() as u8)
+                    .collect()
+            })
+            .collect())
+    }
 
-pub fn part1(input: &str) -> usize {
-    let mut matrix = parse(input);
+    fn part1(matrix: &Self::Input<'_>) -> Answer {
+        let mut matrix = matrix.clone();
 
-    let mut res = 0;
-    for _ in 0..100 {
-        res += step(&mut matrix);
-    }
+        let mut res = 0;
+        for _ in 0..100 {
+            res += step(&mut matrix);
+        }
 
-    res
-}
+        res.into()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let mut matrix = parse(input);
-    let size = matrix.iter().flatten().count();
+    fn part2(matrix: &Self::Input<'_>) -> Answer {
+        let mut matrix = matrix.clone();
+        let size = matrix.iter().flatten().count();
 
-    let mut i = 0;
-    loop {
-        i += 1;
-        if step(&mut matrix) == size {
-            return i;
-        }
+        let mut i = 0;
+        Answer::from(loop {
+            i += 1;
+            if step(&mut matrix) == size {
+                break i;
+            }
+        })
     }
 }
 
@@ -61,17 +80,6 @@ fn step(matrix: &mut [Vec<u8>]) -> usize {
     flashed.len()
 }
 
-fn parse(input: &str) -> Vec<Vec<u8>> {
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as u8)
-                .collect()
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -90,7 +98,7 @@ mod tests {
 4846848554
 5283751526";
 
-        assert_eq!(part1(input), 1656);
+        assert_eq!(Day11::part1(&Day11::parse(input).unwrap()), 1656);
     }
 
     #[test]
@@ -107,6 +115,6 @@ mod tests {
 4846848554
 5283751526";
 
-        assert_eq!(part2(input), 195);
+        assert_eq!(Day11::part2(&Day11::parse(input).unwrap()), 195);
     }
 }
@@ -1,13 +1,31 @@
 use std::collections::{HashMap, HashSet};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> i32 {
-    let map = parse(input);
-    count_solutions(&map, "start", HashSet::new(), false)
-}
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = HashMap<&'a str, HashSet<&'a str>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut map: HashMap<&str, HashSet<&str>> = HashMap::new();
+
+        for line in input.lines() {
+            let (a, b) = line.split_once('-').unwrap();
+            map.entry(a).or_default().insert(b);
+            map.entry(b).or_default().insert(a);
+        }
+
+        Ok(map)
+    }
+
+    fn part1(map: &Self::Input<'_>) -> Answer {
+        count_solutions(map, "start", HashSet::new(), false).into()
+    }
 
-pub fn part2(input: &str) -> i32 {
-    let map = parse(input);
-    count_solutions(&map, "start", HashSet::new(), true)
+    fn part2(map: &Self::Input<'_>) -> Answer {
+        count_solutions(map, "start", HashSet::new(), true).into()
+    }
 }
 
 fn count_solutions<'a>(
@@ -41,18 +59,6 @@ fn count_solutions<'a>(
         .sum()
 }
 
-fn parse(input: &str) -> HashMap<&str, HashSet<&str>> {
-    let mut map: HashMap<&str, HashSet<&str>> = HashMap::new();
-
-    for line in input.lines() {
-        let (a, b) = line.split_once('-').unwrap();
-        map.entry(a).or_default().insert(b);
-        map.entry(b).or_default().insert(a);
-    }
-
-    map
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,9 +106,9 @@ zg-he
 pj-fs
 start-RW";
 
-        assert_eq!(part1(input1), 10);
-        assert_eq!(part1(input2), 19);
-        assert_eq!(part1(input3), 226);
+        assert_eq!(Day12::part1(&Day12::parse(input1).unwrap()), 10);
+        assert_eq!(Day12::part1(&Day12::parse(input2).unwrap()), 19);
+        assert_eq!(Day12::part1(&Day12::parse(input3).unwrap()), 226);
     }
 
     #[test]
@@ -148,8 +154,8 @@ zg-he
 pj-fs
 start-RW";
 
-        assert_eq!(part2(input1), 36);
-        assert_eq!(part2(input2), 103);
-        assert_eq!(part2(input3), 3509);
+        assert_eq!(Day12::part2(&Day12::parse(input1).unwrap()), 36);
+        assert_eq!(Day12::part2(&Day12::parse(input2).unwrap()), 103);
+        assert_eq!(Day12::part2(&Day12::parse(input3).unwrap()), 3509);
     }
 }
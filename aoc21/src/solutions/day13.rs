@@ -1,26 +1,70 @@
 use regex::Regex;
 use std::collections::HashSet;
+use util::grid::Coordinate;
+use util::ocr;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(Debug)]
-struct Fold {
+pub struct Fold {
     axis: String,
     num: u32,
 }
 
-pub fn part1(input: &str) -> usize {
-    let (points, folds) = parse(input);
-    let points = fold_points(points, &folds[0]);
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = (HashSet<(u32, u32)>, Vec<Fold>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let input = input.replace('\r', "");
+        let (points, instructions) = input.split_once("\n\n").unwrap();
+
+        let points: HashSet<(u32, u32)> = points
+            .lines()
+            .map(|line| line.split_once(',').unwrap())
+            .map(|(x, y)| (x.parse::<u32>().unwrap(), y.parse::<u32>().unwrap()))
+            .collect();
+
+        let fold_regex = Regex::new(r"fold along (y|x)=(\d+)").unwrap();
+        let instructions: Vec<Fold> = instructions
+            .lines()
+            .map(|line| fold_regex.captures(line).unwrap())
+            .map(|caps| {
+                (
+                    caps.get(1).unwrap().as_str().to_string(),
+                    caps[2].parse::<u32>().unwrap(),
+                )
+            })
+            .map(|(axis, num)| Fold { axis, num })
+            .collect();
+
+        Ok((points, instructions))
+    }
 
-    points.len()
-}
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (points, folds) = input;
+        let points = fold_points(points.clone(), &folds[0]);
 
-pub fn part2(input: &str) -> String {
-    let (mut points, folds) = parse(input);
-    for fold in folds {
-        points = fold_points(points, &fold);
+        points.len().into()
     }
 
-    string_to_print(points)
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (points, folds) = input;
+        let mut points = points.clone();
+        for fold in folds {
+            points = fold_points(points, fold);
+        }
+
+        let letters = points
+            .iter()
+            .map(|&(x, y)| Coordinate::from(x as i32, y as i32));
+
+        // show the picture if the letters can not be read
+        ocr::recognize_points(letters)
+            .unwrap_or_else(|| string_to_print(points))
+            .into()
+    }
 }
 
 fn string_to_print(points: HashSet<(u32, u32)>) -> String {
@@ -56,32 +100,6 @@ fn do_fold(coord: &(u32, u32), fold: &Fold) -> (u32, u32) {
     (x, y)
 }
 
-fn parse(input: &str) -> (HashSet<(u32, u32)>, Vec<Fold>) {
-    let input = input.replace('\r', "");
-    let (points, instructions) = input.split_once("\n\n").unwrap();
-
-    let points: HashSet<(u32, u32)> = points
-        .lines()
-        .map(|line| line.split_once(',').unwrap())
-        .map(|(x, y)| (x.parse::<u32>().unwrap(), y.parse::<u32>().unwrap()))
-        .collect();
-
-    let fold_regex = Regex::new(r"fold along (y|x)=(\d+)").unwrap();
-    let instructions: Vec<Fold> = instructions
-        .lines()
-        .map(|line| fold_regex.captures(line).unwrap())
-        .map(|caps| {
-            (
-                caps.get(1).unwrap().as_str().to_string(),
-                caps[2].parse::<u32>().unwrap(),
-            )
-        })
-        .map(|(axis, num)| Fold { axis, num })
-        .collect();
-
-    (points, instructions)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,6 +139,6 @@ mod tests {
 fold along y=7
 fold along x=5";
 
-        assert_eq!(part1(input), 17);
+        assert_eq!(Day13::part1(&Day13::parse(input).unwrap()), 17);
     }
 }
@@ -1,13 +1,54 @@
 use std::collections::HashMap;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input<'a> = ComplexReturn;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let input = input.replace('\r', "");
+        let (polymer, transformations) = input.split_once("\n\n").unwrap();
+
+        let polymer_map =
+            polymer
+                .chars()
+                .zip(polymer.chars().skip(1))
+                .fold(HashMap::new(), |mut map, (c1, c2)| {
+                    *map.entry((c1, c2)).or_insert(0) += 1;
+                    map
+                });
+
+        let letter_counts = polymer.chars().fold(HashMap::new(), |mut map, c| {
+            *map.entry(c).or_insert(0) += 1;
+            map
+        });
+
+        let transformations = transformations
+            .lines()
+            .map(|line| line.split_once(" -> ").unwrap())
+            .map(|(s1, s2)| (s1.chars(), s2.chars()))
+            .map(|(mut ch1, mut ch2)| {
+                (
+                    (ch1.next().unwrap(), ch1.next().unwrap()),
+                    ch2.next().unwrap(),
+                )
+            })
+            .collect();
+
+        Ok((polymer_map, letter_counts, transformations))
+    }
 
-pub fn part1(input: &str) -> u64 {
-    let (mut polymer_map, mut letter_counts, transformations) = parse(input);
-    calc_iterations(&mut polymer_map, &mut letter_counts, &transformations, 10)
-}
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (mut polymer_map, mut letter_counts, transformations) = input.clone();
+        calc_iterations(&mut polymer_map, &mut letter_counts, &transformations, 10).into()
+    }
 
-pub fn part2(input: &str) -> u64 {
-    let (mut polymer_map, mut letter_counts, transformations) = parse(input);
-    calc_iterations(&mut polymer_map, &mut letter_counts, &transformations, 40)
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (mut polymer_map, mut letter_counts, transformations) = input.clone();
+        calc_iterations(&mut polymer_map, &mut letter_counts, &transformations, 40).into()
+    }
 }
 
 fn calc_iterations(
@@ -45,39 +86,6 @@ type ComplexReturn = (
     HashMap<(char, char), char>,
 );
 
-fn parse(input: &str) -> ComplexReturn {
-    let input = input.replace('\r', "");
-    let (polymer, transformations) = input.split_once("\n\n").unwrap();
-
-    let polymer_map =
-        polymer
-            .chars()
-            .zip(polymer.chars().skip(1))
-            .fold(HashMap::new(), |mut map, (c1, c2)| {
-                *map.entry((c1, c2)).or_insert(0) += 1;
-                map
-            });
-
-    let letter_counts = polymer.chars().fold(HashMap::new(), |mut map, c| {
-        *map.entry(c).or_insert(0) += 1;
-        map
-    });
-
-    let transformations = transformations
-        .lines()
-        .map(|line| line.split_once(" -> ").unwrap())
-        .map(|(s1, s2)| (s1.chars(), s2.chars()))
-        .map(|(mut ch1, mut ch2)| {
-            (
-                (ch1.next().unwrap(), ch1.next().unwrap()),
-                ch2.next().unwrap(),
-            )
-        })
-        .collect();
-
-    (polymer_map, letter_counts, transformations)
-}
-
 #[cfg(test)]
 mod test {
     use super::*;
@@ -104,7 +112,7 @@ BC -> B
 CC -> N
 CN -> C";
 
-        assert_eq!(part1(input), 1588)
+        assert_eq!(Day14::part1(&Day14::parse(input).unwrap()), 1588)
     }
 
     #[test]
@@ -129,6 +137,6 @@ BC -> B
 CC -> N
 CN -> C";
 
-        assert_eq!(part2(input), 2188189693529)
+        assert_eq!(Day14::part2(&Day14::parse(input).unwrap()), 2188189693529)
     }
 }
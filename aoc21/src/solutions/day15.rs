@@ -1,106 +1,49 @@
-use std::cmp::Ordering;
-use std::collections::{BinaryHeap, HashSet};
+use util::grid::{Coordinate, Grid};
+use util::parse::ParseError;
+use util::search;
+use util::solution::{Answer, Solution};
 
-#[derive(Copy, Clone, Eq, PartialEq)]
-struct State {
-    cost: u32,
-    pos: (usize, usize),
-}
+pub struct Day15;
 
-// implement so the heap becomes a min heap
-impl Ord for State {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other
-            .cost
-            .cmp(&self.cost)
-            .then_with(|| self.pos.cmp(&other.pos))
-    }
-}
+impl Solution for Day15 {
+    type Input<'a> = Grid<u32>;
 
-impl PartialOrd for State {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse_digits(input)
     }
-}
-
-pub fn part1(input: &str) -> u32 {
-    let grid = parse(input);
-    lowest_risk(&grid, (grid[0].len() - 1, grid.len() - 1))
-}
-
-pub fn part2(input: &str) -> u32 {
-    let mut grid = parse(input);
-    larger_area(&mut grid);
-    lowest_risk(&grid, (grid[0].len() - 1, grid.len() - 1))
-}
-
-fn lowest_risk(grid: &[Vec<u32>], goal: (usize, usize)) -> u32 {
-    let height = grid.len();
-    let width = grid[0].len();
-    let mut heap = BinaryHeap::new();
-    let mut seen: HashSet<(usize, usize)> = HashSet::new();
-    heap.push(State {
-        cost: 0,
-        pos: (0, 0),
-    });
 
-    while let Some(State { cost, pos }) = heap.pop() {
-        if pos == goal {
-            return cost;
-        }
-        if seen.contains(&pos) {
-            continue;
-        }
-        seen.insert(pos);
-
-        for (dx, dy) in util::grid::get_adjacent(pos.0, pos.1, width, height) {
-            if !seen.contains(&(dx, dy)) {
-                heap.push(State {
-                    cost: cost + grid[dy][dx],
-                    pos: (dx, dy),
-                });
-            }
-        }
+    fn part1(grid: &Self::Input<'_>) -> Answer {
+        lowest_risk(grid).into()
     }
 
-    unreachable!()
+    fn part2(grid: &Self::Input<'_>) -> Answer {
+        lowest_risk(&larger_area(grid)).into()
+    }
 }
 
-fn larger_area(grid: &mut Vec<Vec<u32>>) {
-    let original_height = grid.len();
-    let original_width = grid[0].len();
-
-    // add five rows
-    for _ in 0..4 * original_height {
-        let v: Vec<u32> = grid[grid.len() - original_height]
-            .iter()
-            .map(calc_new_val)
-            .collect();
-        grid.push(v);
-    }
+fn lowest_risk(grid: &Grid<u32>) -> u32 {
+    let goal = Coordinate::from(grid.width() as i32 - 1, grid.height() as i32 - 1);
 
-    // fill all rows to correct length
-    for row in grid.iter_mut() {
-        for _ in 0..4 * original_width {
-            row.push(calc_new_val(&row[row.len() - original_width]));
-        }
-    }
+    search::dijkstra(
+        [Coordinate::from(0, 0)],
+        |position| grid.neighbours(position).map(|(next, &risk)| (next, risk)),
+        |position| *position == goal,
+    )
+    .unwrap()
+    .cost
 }
 
-fn calc_new_val(n: &u32) -> u32 {
-    let val = n + 1;
-    if val > 9 {
-        val - 9
-    } else {
-        val
-    }
-}
+/// The grid repeated five times in both directions, each repetition one risk level higher.
+fn larger_area(grid: &Grid<u32>) -> Grid<u32> {
+    let width = grid.width() as i32;
+    let height = grid.height() as i32;
+
+    Grid::from_fn(grid.width() * 5, grid.height() * 5, |c| {
+        let original = grid[Coordinate::from(c.x % width, c.y % height)];
+        let increase = (c.x / width + c.y / height) as u32;
 
-fn parse(input: &str) -> Vec<Vec<u32>> {
-    input
-        .lines()
-        .map(|line| line.chars().map(|c| c.to_digit(10).unwrap()).collect())
-        .collect()
+        (original + increase - 1) % 9 + 1
+    })
 }
 
 #[cfg(test)]
@@ -121,7 +64,7 @@ mod tests {
 1293138521
 2311944581";
 
-        assert_eq!(part1(input), 40);
+        assert_eq!(Day15::part1(&Day15::parse(input).unwrap()), 40);
     }
 
     #[test]
@@ -138,6 +81,6 @@ mod tests {
 1293138521
 2311944581";
 
-        assert_eq!(part2(input), 315);
+        assert_eq!(Day15::part2(&Day15::parse(input).unwrap()), 315);
     }
 }
@@ -1,3 +1,6 @@
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
 enum Payload {
     Operator(Vec<Packet>),
     Literal(u64),
@@ -40,18 +43,34 @@ fn calc_subpackets(type_id: u32, subpackets: &[Packet]) -> u64 {
     }
 } 
 
-pub fn part1(input: &str) -> u32 {
-    let bits = parse(input);
-    let packet = parse_packet(&mut bits.into_iter());
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = Vec<u8>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        // convert hex-input into series of bits
+        Ok(input
+            .trim()
+            .chars()
+            .flat_map(|c| {
+                let d = c.to_digit(16).unwrap() as u8;
+                (0..4).rev().map(move |i| (d >> i) & 0b1)
+            })
+            .collect())
+    }
+
+    fn part1(bits: &Self::Input<'_>) -> Answer {
+        let packet = parse_packet(&mut bits.iter().copied());
 
-    packet.sum_versions()
-}
+        packet.sum_versions().into()
+    }
 
-pub fn part2(input: &str) -> u64 {
-    let bits = parse(input);
-    let packet = parse_packet(&mut bits.into_iter());
+    fn part2(bits: &Self::Input<'_>) -> Answer {
+        let packet = parse_packet(&mut bits.iter().copied());
 
-    packet.calculate()
+        packet.calculate().into()
+    }
 }
 
 fn parse_packet(bits: &mut dyn Iterator<Item = u8>) -> Packet {
@@ -126,18 +145,6 @@ fn read_num(bits: &mut dyn Iterator<Item = u8>) -> u64 {
     bits.fold(0, |n, b| (n << 1) + b as u64)
 }
 
-fn parse(input: &str) -> Vec<u8> {
-    // convert hex-input into series of bits
-    input
-        .trim()
-        .chars()
-        .flat_map(|c| {
-            let d = c.to_digit(16).unwrap() as u8;
-            (0..4).rev().map(move |i| (d >> i) & 0b1)
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,7 +222,7 @@ mod tests {
             ("C0015000016115A2E0802F182340", 23),
             ("A0016C880162017C3686B18A3D4780", 31),
         ] {
-            assert_eq!(part1(packet), result);
+            assert_eq!(Day16::part1(&Day16::parse(packet).unwrap()), result);
         }
     }
 
@@ -232,7 +239,7 @@ mod tests {
             ("9C0141080250320F1802104A08", 1),
             ("38006F45291200", 1),
         ] {
-            assert_eq!(part2(packet), result);
+            assert_eq!(Day16::part2(&Day16::parse(packet).unwrap()), result);
         }
     }
 }
@@ -1,7 +1,9 @@
 use regex::Regex;
 use std::ops::RangeInclusive;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-struct Target {
+pub struct Target {
     x_range: RangeInclusive<i32>,
     y_range: RangeInclusive<i32>,
 }
@@ -57,37 +59,41 @@ impl State {
     }
 }
 
-pub fn part1(input: &str) -> u32 {
-    let target = parse(input);
-    util::triangular(target.y_range.start().unsigned_abs() - 1)
-}
+pub struct Day17;
 
-pub fn part2(input: &str) -> u32 {
-    let target = parse(input);
-    let mut count = 0;
+impl Solution for Day17 {
+    type Input<'a> = Target;
 
-    for x_vel in 0..=*target.x_range.end() {
-        for y_vel in *target.y_range.start()..target.y_range.start().abs() {
-            let mut state = State::new(x_vel, y_vel);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let vals: Vec<i32> = Regex::new(r"(-?\d+)")
+            .unwrap()
+            .find_iter(input)
+            .map(|cap| cap.as_str().parse::<i32>().unwrap())
+            .collect();
 
-            if state.reaches(&target) {
-                count += 1;
-            }
-        }
+        Ok(Target {
+            x_range: vals[0]..=vals[1],
+            y_range: vals[2]..=vals[3],
+        })
     }
-    count
-}
 
-fn parse(input: &str) -> Target {
-    let vals: Vec<i32> = Regex::new(r"(-?\d+)")
-        .unwrap()
-        .find_iter(input)
-        .map(|cap| cap.as_str().parse::<i32>().unwrap())
-        .collect();
+    fn part1(target: &Self::Input<'_>) -> Answer {
+        util::triangular(target.y_range.start().unsigned_abs() - 1).into()
+    }
 
-    Target {
-        x_range: vals[0]..=vals[1],
-        y_range: vals[2]..=vals[3],
+    fn part2(target: &Self::Input<'_>) -> Answer {
+        let mut count = 0;
+
+        for x_vel in 0..=*target.x_range.end() {
+            for y_vel in *target.y_range.start()..target.y_range.start().abs() {
+                let mut state = State::new(x_vel, y_vel);
+
+                if state.reaches(target) {
+                    count += 1;
+                }
+            }
+        }
+        count.into()
     }
 }
 
@@ -97,7 +103,7 @@ mod tests {
 
     #[test]
     fn test_reach() {
-        let target = parse("target area: x=20..30, y=-10..-5");
+        let target = Day17::parse("target area: x=20..30, y=-10..-5").unwrap();
 
         assert!(State::new(21, -10).reaches(&target));
         assert!(State::new(8, 0).reaches(&target));
@@ -111,13 +117,13 @@ mod tests {
     fn part1_ex() {
         let input = "target area: x=20..30, y=-10..-5";
 
-        assert_eq!(part1(input), 45);
+        assert_eq!(Day17::part1(&Day17::parse(input).unwrap()), 45);
     }
 
     #[test]
     fn part2_ex() {
         let input = "target area: x=20..30, y=-10..-5";
 
-        assert_eq!(part2(input), 112);
+        assert_eq!(Day17::part2(&Day17::parse(input).unwrap()), 112);
     }
 }
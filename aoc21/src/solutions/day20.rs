@@ -1,78 +1,64 @@
-use std::collections::VecDeque;
+use util::grid::{Coordinate, Grid};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> usize {
-    let (algorithm, mut grid) = parse(input);
-    enhance(algorithm, &mut grid, 2);
+pub struct Day20;
 
-    count_pixels(&grid)
-}
+impl Solution for Day20 {
+    type Input<'a> = (Vec<bool>, Grid<bool>);
 
-pub fn part2(input: &str) -> usize {
-    let (algorithm, mut grid) = parse(input);
-    enhance(algorithm, &mut grid, 50);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let (algorithm, img) = input.split_once("\n\n").unwrap();
 
-    count_pixels(&grid)
-}
+        let algorithm = algorithm.chars().map(|c| c == '#').collect();
+        let grid = Grid::parse(img, |c| Ok(c == '#'))?;
 
-fn enhance(algorithm: Vec<bool>, grid: &mut VecDeque<VecDeque<bool>>, iterations: usize) {
-    for i in 0..iterations {
-        let background = algorithm[0] && i % 2 == 1;
-        pad(grid, background);
+        Ok((algorithm, grid))
+    }
 
-        let old: VecDeque<VecDeque<bool>> = grid.clone();
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (algorithm, grid) = input;
 
-        for y in 1..grid.len() - 1 {
-            for x in 1..grid[0].len() - 1 {
-                let index = get_binary(x, y, &old, background);
-                grid[y][x] = algorithm[index];
-            }
-        }
-        trim_outer(grid);
+        count_pixels(&enhance(algorithm, grid, 2)).into()
     }
-}
 
-fn pad(grid: &mut VecDeque<VecDeque<bool>>, background: bool) {
-    for _ in 0..3 {
-        for row in grid.iter_mut() {
-            row.push_front(background);
-            row.push_back(background);
-        }
-        let to_add = VecDeque::from(vec![background; grid[0].len()]);
-        grid.push_front(to_add.clone());
-        grid.push_back(to_add.clone());
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (algorithm, grid) = input;
+
+        count_pixels(&enhance(algorithm, grid, 50)).into()
     }
 }
 
-fn trim_outer(grid: &mut VecDeque<VecDeque<bool>>) {
-    grid.pop_back();
-    grid.pop_front();
-    for row in grid.iter_mut() {
-        row.pop_back();
-        row.pop_front();
+fn enhance(algorithm: &[bool], grid: &Grid<bool>, iterations: usize) -> Grid<bool> {
+    let mut grid = grid.clone();
+    // every pixel outside of the grid has the same value
+    let mut background = false;
+
+    for _ in 0..iterations {
+        // the image grows by one pixel in every direction
+        grid = Grid::from_fn(grid.width() + 2, grid.height() + 2, |c| {
+            let center = Coordinate::from(c.x - 1, c.y - 1);
+            algorithm[get_binary(&center, &grid, background)]
+        });
+        background = match background {
+            true => algorithm[511],
+            false => algorithm[0],
+        };
     }
+
+    grid
 }
 
-fn get_binary(x: usize, y: usize, grid: &VecDeque<VecDeque<bool>>, background: bool) -> usize {
-    let bin_string = get_surrounding(x, y)
+fn get_binary(center: &Coordinate, grid: &Grid<bool>, background: bool) -> usize {
+    get_surrounding(center)
         .iter()
-        .map(|&(dx, dy)| {
-            if dx == 0 || dy == 0 || dx > grid[0].len() + 1 || dy > grid.len() + 1 {
-                if background {
-                    '1'
-                } else {
-                    '0'
-                }
-            } else if grid[dy][dx] {
-                '1'
-            } else {
-                '0'
-            }
-        })
-        .collect::<String>();
-    usize::from_str_radix(&bin_string, 2).unwrap()
+        .map(|coordinate| *grid.get(coordinate).unwrap_or(&background))
+        .fold(0, |index, pixel| index << 1 | pixel as usize)
 }
 
-fn get_surrounding(x: usize, y: usize) -> [(usize, usize); 9] {
+fn get_surrounding(center: &Coordinate) -> [Coordinate; 9] {
+    let Coordinate { x, y } = *center;
+
     [
         (x - 1, y - 1),
         (x, y - 1),
@@ -84,23 +70,11 @@ fn get_surrounding(x: usize, y: usize) -> [(usize, usize); 9] {
         (x, y + 1),
         (x + 1, y + 1),
     ]
+    .map(|(x, y)| Coordinate::from(x, y))
 }
 
-fn count_pixels(grid: &VecDeque<VecDeque<bool>>) -> usize {
-    grid.iter().flatten().filter(|b| **b).count()
-}
-
-fn parse(input: &str) -> (Vec<bool>, VecDeque<VecDeque<bool>>) {
-    let (algorithm, img) = input.split_once("\n\n").unwrap();
-
-    let algorithm = algorithm.chars().map(|c| c == '#').collect();
-
-    let grid = img
-        .lines()
-        .map(|line| line.chars().map(|c| c == '#').collect())
-        .collect();
-
-    (algorithm, grid)
+fn count_pixels(grid: &Grid<bool>) -> usize {
+    grid.values().filter(|b| **b).count()
 }
 
 #[cfg(test)]
@@ -118,7 +92,7 @@ mod tests {
 ..#..
 ..###";
 
-        assert_eq!(part1(input), 35);
+        assert_eq!(Day20::part1(&Day20::parse(input).unwrap()), 35);
     }
 
     #[test]
@@ -132,6 +106,6 @@ mod tests {
 ..#..
 ..###";
 
-        assert_eq!(part2(input), 3351);
+        assert_eq!(Day20::part2(&Day20::parse(input).unwrap()), 3351);
     }
 }
@@ -1,9 +1,23 @@
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
 // solved by hand. See python solutions for a pointer to the solution
 
-pub fn part1(_: &str) -> u64 {
-    91297395919993
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = ();
+
+    fn parse(_: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(())
+    }
+
+    fn part1(_: &Self::Input<'_>) -> Answer {
+        91297395919993_u64.into()
+    }
+
+    fn part2(_: &Self::Input<'_>) -> Answer {
+        71131151917891_u64.into()
+    }
 }
 
-pub fn part2(_: &str) -> u64 {
-    71131151917891
-}
\ No newline at end of file
@@ -1,6 +1,9 @@
 use std::collections::HashSet;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-struct Seafloor {
+#[derive(Clone)]
+pub struct Seafloor {
     eastwards: HashSet<(usize, usize)>,
     southwards: HashSet<(usize, usize)>,
     right_edge: usize,
@@ -70,47 +73,52 @@ impl PartialEq for Seafloor {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let mut seafloor = parse(input);
-    let mut count = 0;
+pub struct Day25;
 
-    loop {
-        let next_seafloor = seafloor.next_state();
-        count += 1;
+impl Solution for Day25 {
+    type Input<'a> = Seafloor;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut eastwards = HashSet::new();
+        let mut southwards = HashSet::new();
+        let (mut max_x, mut max_y) = (0, 0);
 
-        if next_seafloor == seafloor {
-            break;
+        for (y, line) in input.lines().enumerate() {
+            max_y = max_y.max(y);
+            for (x, cell) in line.chars().enumerate() {
+                max_x = max_x.max(x);
+                match cell {
+                    '>' => {eastwards.insert((x, y));}
+                    'v' => {southwards.insert((x, y));}
+                    _ => {}
+                }
+            }
         }
 
-        seafloor = next_seafloor;
+        Ok(Seafloor::new(eastwards, southwards, max_x + 1, max_y + 1))
     }
 
-    count
-}
+    fn part1(seafloor: &Self::Input<'_>) -> Answer {
+        let mut seafloor = seafloor.clone();
+        let mut count = 0;
 
-pub fn part2(_: &str) -> &'static str {
-    "All done :D"
-}
+        loop {
+            let next_seafloor = seafloor.next_state();
+            count += 1;
 
-fn parse(input: &str) -> Seafloor {
-    let mut eastwards = HashSet::new();
-    let mut southwards = HashSet::new();
-    let (mut max_x, mut max_y) = (0, 0);
-
-
-    for (y, line) in input.lines().enumerate() {
-        max_y = max_y.max(y);
-        for (x, cell) in line.chars().enumerate() {
-            max_x = max_x.max(x);
-            match cell {
-                '>' => {eastwards.insert((x, y));}
-                'v' => {southwards.insert((x, y));}
-                _ => {}
+            if next_seafloor == seafloor {
+                break;
             }
+
+            seafloor = next_seafloor;
         }
+
+        count.into()
     }
 
-    Seafloor::new(eastwards, southwards, max_x + 1, max_y + 1)
+    fn part2(_: &Self::Input<'_>) -> Answer {
+        "All done :D".into()
+    }
 }
 
 #[cfg(test)]
@@ -124,7 +132,7 @@ mod tests {
 
     #[test]
     fn next_state_test() {
-        let seafloor = parse(SIMPLE_MOVEMENT);
+        let seafloor = Day25::parse(SIMPLE_MOVEMENT).unwrap();
         let next_seafloor = seafloor.next_state();
 
         assert!(seafloor.eastwards.contains(&(1, 1)));
@@ -142,7 +150,7 @@ mod tests {
 
     #[test]
     fn wrap_around_test() {
-        let seafloor = parse(WRAP_AROUNT);
+        let seafloor = Day25::parse(WRAP_AROUNT).unwrap();
 
         assert!(seafloor.eastwards.contains(&(6, 0)));
         assert!(seafloor.eastwards.contains(&(0, 1)));
@@ -158,7 +166,7 @@ mod tests {
 
     #[test]
     fn reaching_equal_state() {
-        let seafloor = parse(REACHING_STABLE_STATE);
+        let seafloor = Day25::parse(REACHING_STABLE_STATE).unwrap();
         let next_seafloor = seafloor.next_state();
 
         assert!(seafloor == next_seafloor);
@@ -166,6 +174,6 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(58, part1(INPUT_EXAMPLE));
+        assert_eq!(58, Day25::part1(&Day25::parse(INPUT_EXAMPLE).unwrap()));
     }
 }
@@ -1,26 +1,33 @@
-pub fn part1(input: &str) -> usize {
-    let loads = parse(input);
-
-    *loads.iter().max().unwrap()
-}
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = Vec<usize>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .split("\n\n")
+            .map(|batch| {
+                batch
+                    .lines()
+                    .map(|line| line.parse::<usize>().unwrap())
+                    .sum()
+            })
+            .collect())
+    }
 
-pub fn part2(input: &str) -> usize {
-    let mut loads = parse(input);
-    loads.sort();
+    fn part1(loads: &Self::Input<'_>) -> Answer {
+        (*loads.iter().max().unwrap()).into()
+    }
 
-    loads.iter().rev().take(3).sum()
-}
+    fn part2(loads: &Self::Input<'_>) -> Answer {
+        let mut loads = loads.clone();
+        loads.sort();
 
-fn parse(input: &str) -> Vec<usize> {
-    input
-        .split("\n\n")
-        .map(|batch| {
-            batch
-                .lines()
-                .map(|line| line.parse::<usize>().unwrap())
-                .sum()
-        })
-        .collect()
+        loads.iter().rev().take(3).sum::<usize>().into()
+    }
 }
 
 #[cfg(test)]
@@ -31,11 +38,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(24000, part1(INPUT));
+        assert_eq!(24000, Day01::part1(&Day01::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(45000, part2(INPUT));
+        assert_eq!(45000, Day01::part2(&Day01::parse(INPUT).unwrap()));
     }
 }
@@ -1,3 +1,5 @@
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 use RpsResult::*;
 use RPS::*;
 
@@ -10,11 +12,11 @@ enum RPS {
 }
 
 impl RPS {
-    fn from(letter: &str) -> RPS {
+    fn from(letter: char) -> RPS {
         match letter {
-            "A" | "X" => RPS::Rock,
-            "B" | "Y" => RPS::Paper,
-            "C" | "Z" => RPS::Scissors,
+            'A' | 'X' => RPS::Rock,
+            'B' | 'Y' => RPS::Paper,
+            'C' | 'Z' => RPS::Scissors,
             _ => unreachable!(),
         }
     }
@@ -52,11 +54,11 @@ enum RpsResult {
 }
 
 impl RpsResult {
-    fn from(letter: &str) -> RpsResult {
+    fn from(letter: char) -> RpsResult {
         match letter {
-            "X" => Self::Loss,
-            "Y" => Self::Draw,
-            "Z" => Self::Win,
+            'X' => Self::Loss,
+            'Y' => Self::Draw,
+            'Z' => Self::Win,
             _ => unreachable!(),
         }
     }
@@ -70,38 +72,48 @@ impl RpsResult {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let strategy_guide = parse(input);
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input<'a> = Vec<(char, char)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .trim()
+            .lines()
+            .map(|line| {
+                let (opponent, second) = line.split_once(' ').unwrap();
+                (
+                    opponent.chars().next().unwrap(),
+                    second.chars().next().unwrap(),
+                )
+            })
+            .collect())
+    }
 
-    strategy_guide
-        .iter()
-        .map(|&(opponent, choice)| {
-            let (opponent, choice) = (RPS::from(opponent), RPS::from(choice));
+    fn part1(strategy_guide: &Self::Input<'_>) -> Answer {
+        strategy_guide
+            .iter()
+            .map(|&(opponent, choice)| {
+                let (opponent, choice) = (RPS::from(opponent), RPS::from(choice));
 
-            choice.score() + RPS::play(&choice, &opponent).score()
-        })
-        .sum()
-}
+                choice.score() + RPS::play(&choice, &opponent).score()
+            })
+            .sum::<usize>()
+            .into()
+    }
 
-pub fn part2(input: &str) -> usize {
-    let strategy_guide = parse(input);
-
-    strategy_guide
-        .iter()
-        .map(|&(opponent, result)| {
-            let (opponent, result) = (RPS::from(opponent), RpsResult::from(result));
-            
-            result.score() + RPS::from_result(&opponent, &result).score()
-        })
-        .sum()
-}
+    fn part2(strategy_guide: &Self::Input<'_>) -> Answer {
+        strategy_guide
+            .iter()
+            .map(|&(opponent, result)| {
+                let (opponent, result) = (RPS::from(opponent), RpsResult::from(result));
 
-fn parse(input: &str) -> Vec<(&str, &str)> {
-    input
-        .trim()
-        .lines()
-        .map(|line| line.split_once(' ').unwrap())
-        .collect()
+                result.score() + RPS::from_result(&opponent, &result).score()
+            })
+            .sum::<usize>()
+            .into()
+    }
 }
 
 #[cfg(test)]
@@ -112,11 +124,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(15, part1(INPUT));
+        assert_eq!(15, Day02::part1(&Day02::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(12, part2(INPUT));
+        assert_eq!(12, Day02::part2(&Day02::parse(INPUT).unwrap()));
     }
 }
@@ -1,33 +1,48 @@
 use std::collections::HashSet;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> usize {
-    input.lines()
-        .map(|line| {
-            let mid_point = line.len() / 2;
-            let (left, right) = line.split_at(mid_point);
+pub struct Day03;
 
-            let left: HashSet<char> = HashSet::from_iter(left.chars());
-            let right: HashSet<char> = HashSet::from_iter(right.chars());
+impl Solution for Day03 {
+    type Input<'a> = Vec<String>;
 
-            let item = left.intersection(&right).next().unwrap();
-            get_priority(item)
-        })
-        .sum()
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines().map(str::to_string).collect())
+    }
+
+    fn part1(rucksacks: &Self::Input<'_>) -> Answer {
+        rucksacks
+            .iter()
+            .map(|line| {
+                let mid_point = line.len() / 2;
+                let (left, right) = line.split_at(mid_point);
+
+                let left: HashSet<char> = HashSet::from_iter(left.chars());
+                let right: HashSet<char> = HashSet::from_iter(right.chars());
 
-pub fn part2(input: &str) -> usize {
-    input.lines()
-        .collect::<Vec<&str>>()
-        .chunks(3)
-        .map(|chunk| {
-            let items = chunk.iter()
-                .map(|items| HashSet::from_iter(items.chars()))
-                .reduce(|left, right| left.intersection(&right).copied().collect::<HashSet<char>>())
-                .unwrap();
-            let item = items.iter().next().unwrap();
-            get_priority(item)
-        })
-        .sum()
+                let item = left.intersection(&right).next().unwrap();
+                get_priority(item)
+            })
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(rucksacks: &Self::Input<'_>) -> Answer {
+        rucksacks
+            .chunks(3)
+            .map(|chunk| {
+                let items = chunk
+                    .iter()
+                    .map(|items| HashSet::from_iter(items.chars()))
+                    .reduce(|left, right| left.intersection(&right).copied().collect::<HashSet<char>>())
+                    .unwrap();
+                let item = items.iter().next().unwrap();
+                get_priority(item)
+            })
+            .sum::<usize>()
+            .into()
+    }
 }
 
 fn get_priority(item: &char) -> usize {
@@ -47,11 +62,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(157, part1(INPUT));
+        assert_eq!(157, Day03::part1(&Day03::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(70, part2(INPUT));
+        assert_eq!(70, Day03::part2(&Day03::parse(INPUT).unwrap()));
     }
 }
@@ -1,4 +1,6 @@
 use num_traits::PrimInt;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 use regex::Regex;
 use std::ops::RangeInclusive;
 
@@ -20,39 +22,43 @@ impl<T: PrimInt> OverlappingRange<T> for RangeInclusive<T> {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let ranges = parse(input);
+pub struct Day04;
 
-    ranges
-        .iter()
-        .filter(|(range1, range2)| range1.contains_range(range2) || range2.contains_range(range1))
-        .count()
-}
+impl Solution for Day04 {
+    type Input<'a> = Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>;
 
-pub fn part2(input: &str) -> usize {
-    let ranges = parse(input);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let number_regex = Regex::new(r"(\d+)-(\d+),(\d+)-(\d+)").unwrap();
 
-    ranges
-        .iter()
-        .filter(|(range1, range2)| range1.partial_overlap(range2) || range2.partial_overlap(range1))
-        .count()
-}
+        Ok(input
+            .lines()
+            .map(|line| {
+                let capture = number_regex.captures_iter(line).next().unwrap();
+                let numbers: Vec<u32> = (1..5).map(|i| capture[i].parse().unwrap()).collect();
 
-fn parse(input: &str) -> Vec<(RangeInclusive<u32>, RangeInclusive<u32>)> {
-    let number_regex = Regex::new(r"(\d+)-(\d+),(\d+)-(\d+)").unwrap();
+                let range1 = numbers[0]..=numbers[1];
+                let range2 = numbers[2]..=numbers[3];
 
-    input
-        .lines()
-        .map(|line| {
-            let capture = number_regex.captures_iter(line).next().unwrap();
-            let numbers: Vec<u32> = (1..5).map(|i| capture[i].parse().unwrap()).collect();
+                (range1, range2)
+            })
+            .collect())
+    }
 
-            let range1 = numbers[0]..=numbers[1];
-            let range2 = numbers[2]..=numbers[3];
+    fn part1(ranges: &Self::Input<'_>) -> Answer {
+        ranges
+            .iter()
+            .filter(|(range1, range2)| range1.contains_range(range2) || range2.contains_range(range1))
+            .count()
+            .into()
+    }
 
-            (range1, range2)
-        })
-        .collect()
+    fn part2(ranges: &Self::Input<'_>) -> Answer {
+        ranges
+            .iter()
+            .filter(|(range1, range2)| range1.partial_overlap(range2) || range2.partial_overlap(range1))
+            .count()
+            .into()
+    }
 }
 
 #[cfg(test)]
@@ -63,11 +69,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(2, part1(INPUT));
+        assert_eq!(2, Day04::part1(&Day04::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(4, part2(INPUT));
+        assert_eq!(4, Day04::part2(&Day04::parse(INPUT).unwrap()));
     }
 }
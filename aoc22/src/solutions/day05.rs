@@ -1,7 +1,10 @@
 use regex::Regex;
 use std::collections::VecDeque;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-struct CargoCrane {
+#[derive(Clone)]
+pub struct CargoCrane {
     stacks: Vec<Vec<char>>,
 }
 
@@ -32,33 +35,39 @@ impl CargoCrane {
     }
 }
 
-pub fn part1(input: &str) -> String {
-    let (mut crane, instructions) = parse(input);
+pub struct Day05;
 
-    for (num, from, to) in instructions {
-        crane.move_crates(num, from, to);
+impl Solution for Day05 {
+    type Input<'a> = (CargoCrane, Vec<(usize, usize, usize)>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let (stack_input, instruction_input) = input.split_once("\n\n").unwrap();
+
+        Ok((
+            parse_stacks(stack_input),
+            parse_instructions(instruction_input),
+        ))
     }
 
-    crane.view_top()
-}
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (mut crane, instructions) = input.clone();
 
-pub fn part2(input: &str) -> String {
-    let (mut crane, instructions) = parse(input);
+        for (num, from, to) in instructions {
+            crane.move_crates(num, from, to);
+        }
 
-    for (num, from, to) in instructions {
-        crane.move_crates_simultaniously(num, from, to);
+        crane.view_top().into()
     }
 
-    crane.view_top()
-}
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (mut crane, instructions) = input.clone();
 
-fn parse(input: &str) -> (CargoCrane, Vec<(usize, usize, usize)>) {
-    let (stack_input, instruction_input) = input.split_once("\n\n").unwrap();
+        for (num, from, to) in instructions {
+            crane.move_crates_simultaniously(num, from, to);
+        }
 
-    (
-        parse_stacks(stack_input),
-        parse_instructions(instruction_input),
-    )
+        crane.view_top().into()
+    }
 }
 
 fn parse_stacks(stack_input: &str) -> CargoCrane {
@@ -101,11 +110,17 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!("CMZ".to_string(), part1(INPUT));
+        assert_eq!(
+            "CMZ".to_string(),
+            Day05::part1(&Day05::parse(INPUT).unwrap())
+        );
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!("MCD".to_string(), part2(INPUT));
+        assert_eq!(
+            "MCD".to_string(),
+            Day05::part2(&Day05::parse(INPUT).unwrap())
+        );
     }
 }
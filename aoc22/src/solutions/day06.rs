@@ -1,11 +1,23 @@
 use std::collections::HashSet;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> usize {
-    find_start(input, 4).unwrap()
-}
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
 
-pub fn part2(input: &str) -> usize {
-    find_start(input, 14).unwrap()
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        find_start(input, 4).unwrap().into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        find_start(input, 14).unwrap().into()
+    }
 }
 
 fn find_start(input: &str, window_width: usize) -> Option<usize> {
@@ -28,19 +40,19 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(7, part1(INPUT1));
-        assert_eq!(5, part1(INPUT2));
-        assert_eq!(6, part1(INPUT3));
-        assert_eq!(10, part1(INPUT4));
-        assert_eq!(11, part1(INPUT5));
+        assert_eq!(7, Day06::part1(&Day06::parse(INPUT1).unwrap()));
+        assert_eq!(5, Day06::part1(&Day06::parse(INPUT2).unwrap()));
+        assert_eq!(6, Day06::part1(&Day06::parse(INPUT3).unwrap()));
+        assert_eq!(10, Day06::part1(&Day06::parse(INPUT4).unwrap()));
+        assert_eq!(11, Day06::part1(&Day06::parse(INPUT5).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(19, part2(INPUT1));
-        assert_eq!(23, part2(INPUT2));
-        assert_eq!(23, part2(INPUT3));
-        assert_eq!(29, part2(INPUT4));
-        assert_eq!(26, part2(INPUT5));
+        assert_eq!(19, Day06::part2(&Day06::parse(INPUT1).unwrap()));
+        assert_eq!(23, Day06::part2(&Day06::parse(INPUT2).unwrap()));
+        assert_eq!(23, Day06::part2(&Day06::parse(INPUT3).unwrap()));
+        assert_eq!(29, Day06::part2(&Day06::parse(INPUT4).unwrap()));
+        assert_eq!(26, Day06::part2(&Day06::parse(INPUT5).unwrap()));
     }
 }
@@ -1,7 +1,9 @@
 use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(Debug)]
-struct Node {
+pub struct Node {
     _name: String,
     size: u32,
     children: Vec<Rc<RefCell<Node>>>,
@@ -36,33 +38,76 @@ impl Node {
     }
 }
 
-pub fn part1(input: &str) -> u32 {
-    let root = Rc::clone(&parse(input).borrow().children[0]);
-    let dirs = flattened_dirs(Rc::clone(&root));
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input<'a> = Rc<RefCell<Node>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let parent = Rc::new(RefCell::new(Node::new("".to_string(), 0)));
+        let mut current = Rc::clone(&parent);
+        let mut parents: Vec<Rc<RefCell<Node>>> = Vec::from([Rc::clone(&parent)]);
+
+        for line in input.lines() {
+            let contents: Vec<&str> = line.split_whitespace().collect();
+
+            if contents[0] == "$" {
+                if contents[1] == "cd" {
+                    if contents[2] == ".." {
+                        current = parents.pop().unwrap();
+                        continue;
+                    }
+                    parents.push(Rc::clone(&current));
+                    let name = contents[2].to_string();
+                    let child = Rc::new(RefCell::new(Node::new(name, 0)));
+                    current.borrow_mut().children.push(Rc::clone(&child));
+                    current = child;
+                }
+                // ignore $ ls
+            } else if let Ok(n) = contents[0].parse::<u32>() {
+                let name = contents[1].to_string();
+                current
+                    .borrow_mut()
+                    .children
+                    .push(Rc::new(RefCell::new(Node::new(name, n))));
+            }
+            // ignore dir <xyz>
+        }
 
-    dirs.iter()
-        .filter(|dir| dir.borrow().size <= 100_000)
-        .map(|dir| dir.borrow().size)
-        .sum()
-}
+        parent.borrow_mut().update_sizes();
+        Ok(parent)
+    }
 
-const TOTAL_SPACE: u32 = 70_000_000;
-const SPACE_NEEDED: u32 = 30_000_000;
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let root = Rc::clone(&input.borrow().children[0]);
+        let dirs = flattened_dirs(Rc::clone(&root));
+
+        dirs.iter()
+            .filter(|dir| dir.borrow().size <= 100_000)
+            .map(|dir| dir.borrow().size)
+            .sum::<u32>()
+            .into()
+    }
 
-pub fn part2(input: &str) -> u32 {
-    let root = Rc::clone(&parse(input).borrow().children[0]);
-    let dirs = flattened_dirs(Rc::clone(&root));
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let root = Rc::clone(&input.borrow().children[0]);
+        let dirs = flattened_dirs(Rc::clone(&root));
 
-    let unused_space = TOTAL_SPACE - root.borrow().size;
+        let unused_space = TOTAL_SPACE - root.borrow().size;
 
-    let mut sizes: Vec<u32> = dirs.iter().map(|dir| dir.borrow().size).collect();
-    sizes.sort();
-    *sizes
-        .iter()
-        .find(|&&size| size + unused_space >= SPACE_NEEDED)
-        .unwrap()
+        let mut sizes: Vec<u32> = dirs.iter().map(|dir| dir.borrow().size).collect();
+        sizes.sort();
+        sizes
+            .into_iter()
+            .find(|&size| size + unused_space >= SPACE_NEEDED)
+            .unwrap()
+            .into()
+    }
 }
 
+const TOTAL_SPACE: u32 = 70_000_000;
+const SPACE_NEEDED: u32 = 30_000_000;
+
 fn flattened_dirs(root: Rc<RefCell<Node>>) -> Vec<Rc<RefCell<Node>>> {
     // visit all nodes and collect the directories into a Vec
     let mut directories: Vec<Rc<RefCell<Node>>> = Vec::new();
@@ -79,41 +124,6 @@ fn flattened_dirs(root: Rc<RefCell<Node>>) -> Vec<Rc<RefCell<Node>>> {
     directories
 }
 
-fn parse(input: &str) -> Rc<RefCell<Node>> {
-    let parent = Rc::new(RefCell::new(Node::new("".to_string(), 0)));
-    let mut current = Rc::clone(&parent);
-    let mut parents: Vec<Rc<RefCell<Node>>> = Vec::from([Rc::clone(&parent)]);
-
-    for line in input.lines() {
-        let contents: Vec<&str> = line.split_whitespace().collect();
-
-        if contents[0] == "$" {
-            if contents[1] == "cd" {
-                if contents[2] == ".." {
-                    current = parents.pop().unwrap();
-                    continue;
-                }
-                parents.push(Rc::clone(&current));
-                let name = contents[2].to_string();
-                let child = Rc::new(RefCell::new(Node::new(name, 0)));
-                current.borrow_mut().children.push(Rc::clone(&child));
-                current = child;
-            }
-            // ignore $ ls
-        } else if let Ok(n) = contents[0].parse::<u32>() {
-            let name = contents[1].to_string();
-            current
-                .borrow_mut()
-                .children
-                .push(Rc::new(RefCell::new(Node::new(name, n))));
-        }
-        // ignore dir <xyz>
-    }
-
-    parent.borrow_mut().update_sizes();
-    parent
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -122,11 +132,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(95437, part1(INPUT));
+        assert_eq!(95437, Day07::part1(&Day07::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(24933642, part2(INPUT));
+        assert_eq!(24933642, Day07::part2(&Day07::parse(INPUT).unwrap()));
     }
 }
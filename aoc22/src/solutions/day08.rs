@@ -1,32 +1,50 @@
 use itertools::Itertools;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input<'a> = Vec<Vec<i32>>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                line.chars()
+                    .map(|c| c.to_digit(10).unwrap() as i32)
+                    .collect()
+            })
+            .collect())
+    }
 
-pub fn part1(input: &str) -> usize {
-    let grid = parse(input);
-    let (width, height) = (grid[0].len(), grid.len());
-    let mut visible = vec![vec![false; width]; height];
+    fn part1(grid: &Self::Input<'_>) -> Answer {
+        let (width, height) = (grid[0].len(), grid.len());
+        let mut visible = vec![vec![false; width]; height];
 
-    for y in 0..height {
-        mark_visible(&grid, &mut visible, 0, y, (1, 0));
-        mark_visible(&grid, &mut visible, width - 1, y, (-1, 0));
-    }
+        for y in 0..height {
+            mark_visible(grid, &mut visible, 0, y, (1, 0));
+            mark_visible(grid, &mut visible, width - 1, y, (-1, 0));
+        }
 
-    for x in 0..width {
-        mark_visible(&grid, &mut visible, x, 0, (0, 1));
-        mark_visible(&grid, &mut visible, x, height - 1, (0, -1));
-    }
+        for x in 0..width {
+            mark_visible(grid, &mut visible, x, 0, (0, 1));
+            mark_visible(grid, &mut visible, x, height - 1, (0, -1));
+        }
 
-    visible.iter().flatten().filter(|b| **b).count()
-}
+        visible.iter().flatten().filter(|b| **b).count().into()
+    }
 
-pub fn part2(input: &str) -> i32 {
-    let grid = parse(input);
-    let (width, height) = (grid[0].len(), grid.len());
+    fn part2(grid: &Self::Input<'_>) -> Answer {
+        let (width, height) = (grid[0].len(), grid.len());
 
-    (0..width)
-        .cartesian_product(0..height)
-        .map(|(x, y)| scenic_score(&grid, x, y))
-        .max()
-        .unwrap()
+        (0..width)
+            .cartesian_product(0..height)
+            .map(|(x, y)| scenic_score(grid, x, y))
+            .max()
+            .unwrap()
+            .into()
+    }
 }
 
 fn scenic_score(grid: &[Vec<i32>], start_x: usize, start_y: usize) -> i32 {
@@ -82,17 +100,6 @@ fn mark_visible(
     }
 }
 
-fn parse(input: &str) -> Vec<Vec<i32>> {
-    input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| c.to_digit(10).unwrap() as i32)
-                .collect()
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,11 +108,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(21, part1(INPUT));
+        assert_eq!(21, Day08::part1(&Day08::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(8, part2(INPUT));
+        assert_eq!(8, Day08::part2(&Day08::parse(INPUT).unwrap()));
     }
 }
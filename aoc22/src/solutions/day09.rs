@@ -1,41 +1,54 @@
 use std::collections::HashSet;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> usize {
-    let mut head_pos = (0, 0);
-    let mut tail_pos = head_pos;
+pub struct Day09;
 
-    let instructions = parse(input);
-    let mut tail_positions: HashSet<(i32, i32)> = HashSet::from([tail_pos]); 
+impl Solution for Day09 {
+    type Input<'a> = Vec<(char, i32)>;
 
-    for (direction, num) in instructions {
-        for _ in 0..num {
-            change_head_position(&mut head_pos, direction);
-            change_knot_position(&head_pos, &mut tail_pos);
-            tail_positions.insert(tail_pos);
-        }
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines()
+            .map(|line| line.split_once(' ').unwrap())
+            .map(|(dir, num)| (dir.parse().unwrap(), num.parse().unwrap()))
+            .collect())
     }
 
-    tail_positions.len()
-}
-
-pub fn part2(input: &str) -> usize {
-    let mut knot_positions = [(0, 0); 10];
+    fn part1(instructions: &Self::Input<'_>) -> Answer {
+        let mut head_pos = (0, 0);
+        let mut tail_pos = head_pos;
 
-    let instructions = parse(input);
-    let mut tail_positions: HashSet<(i32, i32)> = HashSet::from([knot_positions[9]]); 
+        let mut tail_positions: HashSet<(i32, i32)> = HashSet::from([tail_pos]); 
 
-    for (direction, num) in instructions {
-        for _ in 0..num {
-            change_head_position(&mut knot_positions[0], direction);
-            for i in 1..knot_positions.len() {
-                let leading_knot = knot_positions[i - 1];
-                change_knot_position(&leading_knot, &mut knot_positions[i]);
+        for &(direction, num) in instructions {
+            for _ in 0..num {
+                change_head_position(&mut head_pos, direction);
+                change_knot_position(&head_pos, &mut tail_pos);
+                tail_positions.insert(tail_pos);
             }
-            tail_positions.insert(knot_positions[9]);
         }
+
+        tail_positions.len().into()
     }
 
-    tail_positions.len()
+    fn part2(instructions: &Self::Input<'_>) -> Answer {
+        let mut knot_positions = [(0, 0); 10];
+
+        let mut tail_positions: HashSet<(i32, i32)> = HashSet::from([knot_positions[9]]); 
+
+        for &(direction, num) in instructions {
+            for _ in 0..num {
+                change_head_position(&mut knot_positions[0], direction);
+                for i in 1..knot_positions.len() {
+                    let leading_knot = knot_positions[i - 1];
+                    change_knot_position(&leading_knot, &mut knot_positions[i]);
+                }
+                tail_positions.insert(knot_positions[9]);
+            }
+        }
+
+        tail_positions.len().into()
+    }
 }
 
 fn change_head_position(head_pos: &mut (i32, i32), direction: char) {
@@ -60,13 +73,6 @@ fn change_knot_position(head_pos: &(i32, i32), tail_pos: &mut (i32, i32)) {
     *tail_pos = (tail_pos.0 + change_x, tail_pos.1 + change_y);
 }
 
-fn parse(input: &str) -> Vec<(char, i32)> {
-    input.lines()
-        .map(|line| line.split_once(' ').unwrap())
-        .map(|(dir, num)| (dir.parse().unwrap(), num.parse().unwrap()))
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -77,12 +83,12 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(13, part1(INPUT));
+        assert_eq!(13, Day09::part1(&Day09::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(1, part2(INPUT));
-        assert_eq!(36, part2(INPUT2));
+        assert_eq!(1, Day09::part2(&Day09::parse(INPUT).unwrap()));
+        assert_eq!(36, Day09::part2(&Day09::parse(INPUT2).unwrap()));
     }
 }
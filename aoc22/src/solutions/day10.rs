@@ -1,55 +1,64 @@
 use std::collections::HashSet;
+use util::grid::{Coordinate, Grid};
+use util::ocr;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> i32 {
-    let values = parse(input);
-    let cycles = HashSet::from([20, 60, 100, 140, 180, 220]);
+pub struct Day10;
 
-    let mut x = 1;
-    let mut cycle = 1;
-    let mut result = 0;
+impl Solution for Day10 {
+    type Input<'a> = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        // When transforming the words into 0, the resulting numbers can be added to x each step, where each step represents a cycle.
+        // Idea taken from: https://www.reddit.com/r/adventofcode/comments/zhjfo4/2022_day_10_solutions/izmspl7/ 
+        Ok(input
+            .split_whitespace()
+            .map(|element| element.parse().unwrap_or_default())
+            .collect())
+    }
+
+    fn part1(values: &Self::Input<'_>) -> Answer {
+        let cycles = HashSet::from([20, 60, 100, 140, 180, 220]);
+
+        let mut x = 1;
+        let mut result = 0;
 
-    for value in values {
-        if cycles.contains(&cycle) {
-            result += x * cycle;
+        for (cycle, value) in (1..).zip(values) {
+            if cycles.contains(&cycle) {
+                result += x * cycle;
+            }
+
+            x += value;
         }
+        result.into()
+    }
 
-        cycle += 1;
-        x += value;
+    fn part2(values: &Self::Input<'_>) -> Answer {
+        let screen = draw_screen(values);
+        if let Some(letters) = ocr::recognize(&screen) {
+            return letters.into();
+        }
+
+        // show the picture if the letters can not be read
+        let screen = screen.map(|&lit| if lit { '█' } else { ' ' });
+        format!("\n{}", screen).into()
     }
-    result
 }
 
-pub fn part2(input: &str) -> &'static str {
-    let values = parse(input);
-    let mut output = String::new();
-
+fn draw_screen(values: &[i32]) -> Grid<bool> {
+    let mut screen = Grid::new(40, 6, false);
     let mut x = 1;
-    let mut cycle = 1;
 
-    for value in values {
-        let pixel_position = (cycle % 40) - 1;
+    for (pixel, value) in (0..40 * 6).zip(values) {
+        let position = Coordinate::from(pixel % 40, pixel / 40);
         let sprite = (x - 1)..=(x + 1);
-        output += if sprite.contains(&pixel_position) { "█ " } else { "  " };
-        
-        if (cycle) % 40 == 0 {
-            output += "\n";
-        }
+        screen[position] = sprite.contains(&position.x);
 
-        cycle += 1;
         x += value;
     }
-    
-    println!("{}", output);
-    "Printed to stdout"
-}
 
-fn parse(input: &str) -> Vec<i32> {
-    // When transforming the words into 0, the resulting numbers can be added to x each step, where each step represents a cycle.
-    // Idea taken from: https://www.reddit.com/r/adventofcode/comments/zhjfo4/2022_day_10_solutions/izmspl7/ 
-    input
-        .split_whitespace()
-        .map(|element| element.parse().unwrap_or_default())
-        .collect()
+    screen
 }
 
 #[cfg(test)]
@@ -60,6 +69,20 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(13140, part1(INPUT));
+        assert_eq!(13140, Day10::part1(&Day10::parse(INPUT).unwrap()));
+    }
+
+    #[test]
+    fn screen_is_drawn() {
+        let screen = draw_screen(&Day10::parse(INPUT).unwrap()).map(|&lit| if lit { '#' } else { '.' });
+
+        assert_eq!(
+            "##..##..##..##..##..##..##..##..##..##..",
+            screen.to_string().lines().next().unwrap()
+        );
+        assert_eq!(
+            "#######.......#######.......#######.....",
+            screen.to_string().lines().last().unwrap()
+        );
     }
 }
@@ -1,19 +1,23 @@
-use std::{cell::RefCell, collections::VecDeque};
+use std::{cell::RefCell, collections::VecDeque, rc::Rc};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
+#[derive(Clone)]
 enum Op {
     Square,
     Add(u64),
     Mult(u64),
 }
 
-struct Monkey {
+#[derive(Clone)]
+pub struct Monkey {
     items: RefCell<VecDeque<u64>>,
     operation: Op,
     divisor: u64,
     throw_true: usize,
     throw_false: usize,
     throw_count: RefCell<usize>,
-    worry_relief: Box<dyn Fn(u64) -> u64>,
+    worry_relief: Rc<dyn Fn(u64) -> u64>,
 }
 
 impl Monkey {
@@ -32,7 +36,7 @@ impl Monkey {
             throw_true,
             throw_false,
             throw_count: RefCell::new(0),
-            worry_relief: Box::new(worry_relief),
+            worry_relief: Rc::new(worry_relief),
         }
     }
 
@@ -60,25 +64,98 @@ impl Monkey {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let mut monkeys = parse(input);
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = Vec<Monkey>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .split("\n\n")
+            .map(|monkey| {
+                let mut lines = monkey.lines();
+                lines.next().unwrap();
+
+                // starting items
+                let items: VecDeque<u64> = lines
+                    .next()
+                    .unwrap()
+                    .strip_prefix("  Starting items: ")
+                    .unwrap()
+                    .split(", ")
+                    .map(|num| num.parse().unwrap())
+                    .collect();
+                // Operation
+                let operation = lines
+                    .next()
+                    .unwrap()
+                    .strip_prefix("  Operation: new = old ")
+                    .unwrap()
+                    .split_once(' ')
+                    .unwrap();
+                let operation = match operation {
+                        ("*", "old") => Op::Square,
+                        //("+", "old") => value + value,
+                        ("*", num) => Op::Mult(num.parse().unwrap()),
+                        ("+", num) => Op::Add(num.parse().unwrap()),
+                        _ => unreachable!(),
+                };
+                // divisor
+                let divisor: u64 = lines
+                    .next()
+                    .unwrap()
+                    .strip_prefix("  Test: divisible by ")
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                let throw_if_true: usize = lines
+                    .next()
+                    .unwrap()
+                    .strip_prefix("    If true: throw to monkey ")
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                let throw_if_false: usize = lines
+                    .next()
+                    .unwrap()
+                    .strip_prefix("    If false: throw to monkey ")
+                    .unwrap()
+                    .parse()
+                    .unwrap();
+                let worry_relief: fn(u64) -> u64 = |x| x / 3;
+
+                Monkey::new(
+                    items,
+                    operation,
+                    divisor,
+                    throw_if_true,
+                    throw_if_false,
+                    worry_relief,
+                )
+            })
+            .collect())
+    }
 
-    perform_iteration(20, &monkeys);
+    fn part1(monkeys: &Self::Input<'_>) -> Answer {
+        let mut monkeys = monkeys.clone();
 
-    calc_monkey_business(&mut monkeys)
-}
+        perform_iteration(20, &monkeys);
 
-pub fn part2(input: &str) -> usize {
-    let mut monkeys = parse(input);
-    // change worry relief function
-    let divisor_prod: u64 = monkeys.iter().map(|monkey| monkey.divisor).product();
-    for monkey in monkeys.iter_mut() {
-        monkey.worry_relief = Box::new(move |x: u64| x % divisor_prod);
+        calc_monkey_business(&mut monkeys).into()
     }
 
-    perform_iteration(10_000, &monkeys);
+    fn part2(monkeys: &Self::Input<'_>) -> Answer {
+        let mut monkeys = monkeys.clone();
+        // change worry relief function
+        let divisor_prod: u64 = monkeys.iter().map(|monkey| monkey.divisor).product();
+        for monkey in monkeys.iter_mut() {
+            monkey.worry_relief = Rc::new(move |x: u64| x % divisor_prod);
+        }
+
+        perform_iteration(10_000, &monkeys);
 
-    calc_monkey_business(&mut monkeys)
+        calc_monkey_business(&mut monkeys).into()
+    }
 }
 
 fn perform_iteration(limit: usize, monkeys: &[Monkey]) {
@@ -99,73 +176,6 @@ fn calc_monkey_business(monkeys: &mut [Monkey]) -> usize {
         .product()
 }
 
-fn parse(input: &str) -> Vec<Monkey> {
-    input
-        .split("\n\n")
-        .map(|monkey| {
-            let mut lines = monkey.lines();
-            lines.next().unwrap();
-
-            // starting items
-            let items: VecDeque<u64> = lines
-                .next()
-                .unwrap()
-                .strip_prefix("  Starting items: ")
-                .unwrap()
-                .split(", ")
-                .map(|num| num.parse().unwrap())
-                .collect();
-            // Operation
-            let operation = lines
-                .next()
-                .unwrap()
-                .strip_prefix("  Operation: new = old ")
-                .unwrap()
-                .split_once(' ')
-                .unwrap();
-            let operation = match operation {
-                    ("*", "old") => Op::Square,
-                    //("+", "old") => value + value,
-                    ("*", num) => Op::Mult(num.parse().unwrap()),
-                    ("+", num) => Op::Add(num.parse().unwrap()),
-                    _ => unreachable!(),
-            };
-            // divisor
-            let divisor: u64 = lines
-                .next()
-                .unwrap()
-                .strip_prefix("  Test: divisible by ")
-                .unwrap()
-                .parse()
-                .unwrap();
-            let throw_if_true: usize = lines
-                .next()
-                .unwrap()
-                .strip_prefix("    If true: throw to monkey ")
-                .unwrap()
-                .parse()
-                .unwrap();
-            let throw_if_false: usize = lines
-                .next()
-                .unwrap()
-                .strip_prefix("    If false: throw to monkey ")
-                .unwrap()
-                .parse()
-                .unwrap();
-            let worry_relief: fn(u64) -> u64 = |x| x / 3;
-
-            Monkey::new(
-                items,
-                operation,
-                divisor,
-                throw_if_true,
-                throw_if_false,
-                worry_relief,
-            )
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,11 +184,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(10605, part1(INPUT));
+        assert_eq!(10605, Day11::part1(&Day11::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(2713310158, part2(INPUT));
+        assert_eq!(2713310158, Day11::part2(&Day11::parse(INPUT).unwrap()));
     }
 }
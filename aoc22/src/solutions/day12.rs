@@ -1,64 +1,46 @@
-use std::collections::{HashSet, VecDeque};
+use util::grid::{Coordinate, Grid};
+use util::parse::ParseError;
+use util::search;
+use util::solution::{Answer, Solution};
 
-use itertools::Itertools;
+pub struct Day12;
 
-pub fn part1(input: &str) -> u32 {
-    let grid = parse(input);
+impl Solution for Day12 {
+    type Input<'a> = Grid<char>;
 
-    let (start_x, start_y) = find_char(&grid, 'S');
-
-    find_shortest_path(start_x, start_y, &grid, 'E', true)
-}
-
-pub fn part2(input: &str) -> u32 {
-    // go backwards from end to first 'a'
-    let grid = parse(input);
-
-    let (start_x, start_y) = find_char(&grid, 'E');
-
-    find_shortest_path(start_x, start_y, &grid, 'a', false)
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse_chars(input)
+    }
 
-fn find_shortest_path(x: usize, y: usize, grid: &[Vec<char>], goal: char, forwards: bool) -> u32 {
-    // Breadth-first search
-    // state is ((x, y), height, cost)
-    let starting_state = ((x, y), calculate_height(grid[y][x]), 0);
-    let mut expanded: VecDeque<((usize, usize), u8, u32)> = VecDeque::from([starting_state]);
-    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    fn part1(grid: &Self::Input<'_>) -> Answer {
+        let start = grid.find(|&c| c == 'S').unwrap();
 
-    while let Some(((x, y), height, cost)) = expanded.pop_front() {
-        if visited.contains(&(x, y)) {
-            continue;
-        }
-        visited.insert((x, y));
+        find_shortest_path([start], grid).into()
+    }
 
-        if grid[y][x] == goal {
-            return cost;
-        }
+    fn part2(grid: &Self::Input<'_>) -> Answer {
+        let starts = grid
+            .iter()
+            .filter(|(_, &c)| calculate_height(c) == 1)
+            .map(|(coordinate, _)| coordinate);
 
-        for (xx, yy) in util::grid::get_adjacent(x, y, grid[0].len(), grid.len()) {
-            if !visited.contains(&(xx, yy)) {
-                let adjacent_char = grid[yy][xx];
-                // forwards flag to differentiate between part 1 and part 2
-                if forwards {
-                    if calculate_height(adjacent_char) - 1 <= height {
-                        expanded.push_back(((xx, yy), calculate_height(adjacent_char), cost + 1));
-                    }
-                } else if calculate_height(adjacent_char) >= height - 1 {
-                    expanded.push_back(((xx, yy), calculate_height(adjacent_char), cost + 1));
-                }
-            }
-        }
+        find_shortest_path(starts, grid).into()
     }
-
-    unreachable!()
 }
 
-fn find_char(grid: &[Vec<char>], search: char) -> (usize, usize) {
-    (0..grid[0].len())
-        .cartesian_product(0..grid.len())
-        .find(|(x, y)| grid[*y][*x] == search)
-        .unwrap()
+fn find_shortest_path(starts: impl IntoIterator<Item = Coordinate>, grid: &Grid<char>) -> usize {
+    search::bfs(
+        starts,
+        |coordinate| {
+            let height = calculate_height(grid[*coordinate]);
+            grid.neighbours(coordinate)
+                .filter(move |(_, &c)| calculate_height(c) <= height + 1)
+                .map(|(next, _)| next)
+        },
+        |coordinate| grid[*coordinate] == 'E',
+    )
+    .unwrap()
+    .cost
 }
 
 fn calculate_height(c: char) -> u8 {
@@ -70,10 +52,6 @@ fn calculate_height(c: char) -> u8 {
     }
 }
 
-fn parse(input: &str) -> Vec<Vec<char>> {
-    input.lines().map(|line| line.chars().collect()).collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -82,11 +60,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(31, part1(INPUT));
+        assert_eq!(31, Day12::part1(&Day12::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(29, part2(INPUT));
+        assert_eq!(29, Day12::part2(&Day12::parse(INPUT).unwrap()));
     }
 }
@@ -1,9 +1,11 @@
 use std::cmp::Ord;
 use std::cmp::Ordering;
 use std::str::FromStr;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(PartialEq, Eq)]
-enum Packet {
+pub enum Packet {
     Value(u32),
     List(Vec<Packet>),
 }
@@ -86,60 +88,45 @@ impl Ord for Packet {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let pairs = parse1(input);
-    pairs
-        .iter()
-        .enumerate()
-        .filter(|(_, (packet1, packet2))| packet1 < packet2)
-        .map(|(i, _)| i + 1) // index should start at 1
-        .sum()
-}
+pub struct Day13;
 
-pub fn part2(input: &str) -> usize {
-    let mut packets = parse2(input); 
-    let divider1 = Packet::List(vec![Packet::List(vec![Packet::Value(2)])]);
-    let divider2 = Packet::List(vec![Packet::List(vec![Packet::Value(6)])]);
+impl Solution for Day13 {
+    type Input<'a> = Vec<Packet>;
 
-    packets.sort();
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .filter(|line| !line.is_empty())
+            .map(|line| line.parse().unwrap())
+            .collect())
+    }
 
-    let pos1 = packets.iter().position(|packet| packet == &divider1).unwrap() + 1;
-    let pos2 = packets.iter().position(|packet| packet == &divider2).unwrap() + 1;
+    fn part1(packets: &Self::Input<'_>) -> Answer {
+        packets
+            .chunks(2)
+            .enumerate()
+            .filter(|(_, pair)| pair[0] < pair[1])
+            .map(|(i, _)| i + 1) // index should start at 1
+            .sum::<usize>()
+            .into()
+    }
 
-    pos1 * pos2
-}
+    fn part2(packets: &Self::Input<'_>) -> Answer {
+        let divider1 = Packet::List(vec![Packet::List(vec![Packet::Value(2)])]);
+        let divider2 = Packet::List(vec![Packet::List(vec![Packet::Value(6)])]);
 
-fn parse1(input: &str) -> Vec<(Packet, Packet)> {
-    input
-        .split("\n\n")
-        .map(|pairs| {
-            let mut iter = pairs.split('\n');
-            let left = iter.next().unwrap().parse().unwrap();
-            let right = iter.next().unwrap().parse().unwrap();
-
-            (left, right)
-        })
-        .collect()
-}
+        let mut packets = packets.iter().collect::<Vec<_>>();
+        packets.push(&divider1);
+        packets.push(&divider2);
+        packets.sort();
 
-fn parse2(input: &str) -> Vec<Packet> {
-    let mut packets: Vec<Packet> = input
-        .split('\n')
-        .filter_map(|line| {
-            if line.is_empty() {
-                None
-            } else {
-                Some(line.parse().unwrap())
-            }
-        })
-        .collect();
-    packets.push(Packet::List(vec![Packet::List(vec![Packet::Value(2)])]));
-    packets.push(Packet::List(vec![Packet::List(vec![Packet::Value(6)])]));
+        let pos1 = packets.iter().position(|&packet| packet == &divider1).unwrap() + 1;
+        let pos2 = packets.iter().position(|&packet| packet == &divider2).unwrap() + 1;
 
-    packets
+        (pos1 * pos2).into()
+    }
 }
 
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -148,11 +135,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(13, part1(INPUT));
+        assert_eq!(13, Day13::part1(&Day13::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(140, part2(INPUT));
+        assert_eq!(140, Day13::part2(&Day13::parse(INPUT).unwrap()));
     }
 }
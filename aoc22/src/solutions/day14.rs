@@ -1,36 +1,70 @@
 use std::collections::HashMap;
 
 use itertools::Itertools;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-#[derive(PartialEq)]
-enum Tile {
+#[derive(Clone, PartialEq)]
+pub enum Tile {
     Wall,
     Sand,
 }
 
-pub fn part1(input: &str) -> usize {
-    let mut tiles = parse(input);
-    // y-coordinate for the wall the furthest down from the top.
-    let y_cutoff = tiles.keys().max_by_key(|(_, y)| *y).unwrap().1;
+pub struct Day14;
 
-    fill_with_sand1(&mut tiles, y_cutoff);
+impl Solution for Day14 {
+    type Input<'a> = HashMap<(i32, i32), Tile>;
 
-    tiles
-        .iter()
-        .filter(|(_, state)| **state == Tile::Sand)
-        .count()
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut solid_tiles: HashMap<(i32, i32), Tile> = HashMap::new();
+
+        for line in input.lines() {
+            let coordinates = line.split(" -> ").map(|coordinate| {
+                let (x, y) = coordinate.split_once(',').unwrap();
+                (x.parse::<i32>().unwrap(), y.parse::<i32>().unwrap())
+            });
+
+            for ((x1, y1), (x2, y2)) in coordinates.clone().zip(coordinates.skip(1)) {
+                let (x_min, x_max) = (x1.min(x2), x1.max(x2));
+                let (y_min, y_max) = (y1.min(y2), y1.max(y2));
+
+                (x_min..=x_max)
+                    .cartesian_product(y_min..=y_max)
+                    .for_each(|coordinate| {
+                        solid_tiles.insert(coordinate, Tile::Wall);
+                    });
+            }
+        }
 
-pub fn part2(input: &str) -> usize {
-    let mut tiles = parse(input);
-    let bottom = tiles.keys().max_by_key(|(_, y)| *y).unwrap().1 + 2;
+        Ok(solid_tiles)
+    }
+
+    fn part1(tiles: &Self::Input<'_>) -> Answer {
+        let mut tiles = tiles.clone();
+        // y-coordinate for the wall the furthest down from the top.
+        let y_cutoff = tiles.keys().max_by_key(|(_, y)| *y).unwrap().1;
+
+        fill_with_sand1(&mut tiles, y_cutoff);
+
+        tiles
+            .iter()
+            .filter(|(_, state)| **state == Tile::Sand)
+            .count()
+            .into()
+    }
 
-    fill_with_sand2(&mut tiles, bottom);
+    fn part2(tiles: &Self::Input<'_>) -> Answer {
+        let mut tiles = tiles.clone();
+        let bottom = tiles.keys().max_by_key(|(_, y)| *y).unwrap().1 + 2;
 
-    tiles
-        .iter()
-        .filter(|(_, state)| **state == Tile::Sand)
-        .count()
+        fill_with_sand2(&mut tiles, bottom);
+
+        tiles
+            .iter()
+            .filter(|(_, state)| **state == Tile::Sand)
+            .count()
+            .into()
+    }
 }
 
 fn fill_with_sand1(tiles: &mut HashMap<(i32, i32), Tile>, cutoff: i32) {
@@ -84,30 +118,6 @@ fn next_coordinate(x: i32, y: i32, tiles: &HashMap<(i32, i32), Tile>) -> Option<
     None
 }
 
-fn parse(input: &str) -> HashMap<(i32, i32), Tile> {
-    let mut solid_tiles: HashMap<(i32, i32), Tile> = HashMap::new();
-
-    for line in input.lines() {
-        let coordinates = line.split(" -> ").map(|coordinate| {
-            let (x, y) = coordinate.split_once(',').unwrap();
-            (x.parse::<i32>().unwrap(), y.parse::<i32>().unwrap())
-        });
-
-        for ((x1, y1), (x2, y2)) in coordinates.clone().zip(coordinates.skip(1)) {
-            let (x_min, x_max) = (x1.min(x2), x1.max(x2));
-            let (y_min, y_max) = (y1.min(y2), y1.max(y2));
-
-            (x_min..=x_max)
-                .cartesian_product(y_min..=y_max)
-                .for_each(|coordinate| {
-                    solid_tiles.insert(coordinate, Tile::Wall);
-                });
-        }
-    }
-
-    solid_tiles
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -116,11 +126,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(24, part1(INPUT));
+        assert_eq!(24, Day14::part1(&Day14::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(93, part2(INPUT));
+        assert_eq!(93, Day14::part2(&Day14::parse(INPUT).unwrap()));
     }
 }
@@ -1,42 +1,52 @@
-use regex::Regex;
-use std::{collections::HashSet, ops::RangeInclusive};
-
-struct Sensor {
-    position: (isize, isize),
+use std::{
+    collections::HashSet,
+    ops::{Range, RangeInclusive},
+};
+use util::grid::Coordinate;
+use util::interval::IntervalSet;
+use util::parse::{self, ParseError};
+use util::solution::{Answer, Solution};
+
+pub struct Sensor {
+    position: Coordinate<isize>,
+    closest_beacon: Coordinate<isize>,
     distance: isize,
 }
 
 impl Sensor {
-    fn new(position: (isize, isize), closest_beacon: (isize, isize)) -> Sensor {
-        let (x1, y1) = position;
-        let (x2, y2) = closest_beacon;
-        let distance = util::manhatten_distance(x1, y1, x2, y2);
+    fn new(position: Coordinate<isize>, closest_beacon: Coordinate<isize>) -> Sensor {
+        let distance = position.manhatten_distance(&closest_beacon);
 
         Sensor {
             position,
+            closest_beacon,
             distance,
         }
     }
 
-    fn contains_point(&self, point: (isize, isize)) -> bool {
-        util::manhatten_distance(point.0, point.1, self.position.0, self.position.1)
-            <= self.distance
+    // the x coordinates in the sensor's reach, empty if the row is out of reach
+    fn row_coverage(&self, y: isize) -> Range<isize> {
+        let Coordinate { x: x_pos, y: y_pos } = self.position;
+        let width = self.distance - (y - y_pos).abs();
+
+        (x_pos - width)..(x_pos + width + 1)
+    }
+
+    fn contains_point(&self, point: Coordinate<isize>) -> bool {
+        point.manhatten_distance(&self.position) <= self.distance
     }
 
     // get list of coordinates just outside of the sensor's reach
-    fn get_outline(&self, range: &RangeInclusive<isize>) -> HashSet<(isize, isize)> {
-        let mut coordinates: HashSet<(isize, isize)> =
-            HashSet::with_capacity(4 * self.distance as usize);
-        let (x_pos, y_pos) = self.position;
+    fn get_outline(&self, range: &RangeInclusive<isize>) -> HashSet<Coordinate<isize>> {
+        let mut coordinates = HashSet::with_capacity(4 * self.distance as usize);
 
         for dx in 0..=(self.distance + 1) {
             let dy = (self.distance + 1) - dx;
             for (sign_x, sign_y) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
-                let x = x_pos + (dx * sign_x);
-                let y = y_pos + (dy * sign_y);
+                let point = self.position + Coordinate::from(dx * sign_x, dy * sign_y);
 
-                if range.contains(&x) && range.contains(&y) {
-                    coordinates.insert((x, y));
+                if range.contains(&point.x) && range.contains(&point.y) {
+                    coordinates.insert(point);
                 }
             }
         }
@@ -45,43 +55,54 @@ impl Sensor {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    part1_work(input, 2_000_000)
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = Vec<Sensor>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::map_lines(input, |line| match parse::integers(line)?[..] {
+            [x, y, beacon_x, beacon_y] => Ok(Sensor::new(
+                Coordinate::from(x, y),
+                Coordinate::from(beacon_x, beacon_y),
+            )),
+            _ => Err(ParseError::new(line, "expected 4 numbers")),
+        })
+    }
+
+    fn part1(sensors: &Self::Input<'_>) -> Answer {
+        part1_work(sensors, 2_000_000).into()
+    }
+
+    fn part2(sensors: &Self::Input<'_>) -> Answer {
+        part2_work(sensors, 4_000_000).into()
+    }
 }
 
-fn part1_work(input: &str, y: isize) -> usize {
-    let sensors = parse(input);
+fn part1_work(sensors: &[Sensor], y: isize) -> usize {
+    let covered: IntervalSet<isize> = sensors.iter().map(|s| s.row_coverage(y)).collect();
 
-    let x_min = sensors
-        .iter()
-        .map(|s| s.position.0 - s.distance)
-        .min()
-        .unwrap();
-    let x_max = sensors
+    // a position with a beacon can not be without one
+    let beacons: HashSet<Coordinate<isize>> = sensors
         .iter()
-        .map(|s| s.position.0 + s.distance)
-        .max()
-        .unwrap();
-
-    (x_min..x_max)
-        .filter(|&x| sensors.iter().any(|sensor| sensor.contains_point((x, y))))
-        .count()
-        - 1 // 'fixing' the result because there is an off by 1 error that I don't see
-}
+        .map(|s| s.closest_beacon)
+        .filter(|beacon| beacon.y == y && covered.contains(beacon.x))
+        .collect();
 
-pub fn part2(input: &str) -> usize {
-    part2_work(input, 4_000_000)
+    covered.total_length() as usize - beacons.len()
 }
 
-fn part2_work(input: &str, limit: isize) -> usize {
-    let sensors = parse(input);
+fn part2_work(sensors: &[Sensor], limit: isize) -> usize {
     let limit = 0..=limit;
 
     for sensor in sensors.iter().rev() {
         let coordinates = sensor.get_outline(&limit);
 
         for coordinate in coordinates {
-            if sensors.iter().all(|sensor| !sensor.contains_point(coordinate)) {
+            if sensors
+                .iter()
+                .all(|sensor| !sensor.contains_point(coordinate))
+            {
                 return calc_tuning_frequency(coordinate);
             }
         }
@@ -89,27 +110,8 @@ fn part2_work(input: &str, limit: isize) -> usize {
     0
 }
 
-fn calc_tuning_frequency(coordinate: (isize, isize)) -> usize {
-    (coordinate.0 * 4_000_000 + coordinate.1) as usize
-}
-
-fn parse(input: &str) -> Vec<Sensor> {
-    let sensor_regex =
-        Regex::new(r"Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)")
-            .unwrap();
-
-    input
-        .lines()
-        .map(|line| {
-            let capture = sensor_regex.captures_iter(line).next().unwrap();
-            let numbers: Vec<isize> = (1..5).map(|i| capture[i].parse().unwrap()).collect();
-
-            let sensor_position = (numbers[0], numbers[1]);
-            let closest_beacon = (numbers[2], numbers[3]);
-
-            Sensor::new(sensor_position, closest_beacon)
-        })
-        .collect()
+fn calc_tuning_frequency(coordinate: Coordinate<isize>) -> usize {
+    (coordinate.x * 4_000_000 + coordinate.y) as usize
 }
 
 #[cfg(test)]
@@ -120,11 +122,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(26, part1_work(INPUT, 10));
+        assert_eq!(26, part1_work(&Day15::parse(INPUT).unwrap(), 10));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(56000011, part2_work(INPUT, 20));
+        assert_eq!(56000011, part2_work(&Day15::parse(INPUT).unwrap(), 20));
     }
 }
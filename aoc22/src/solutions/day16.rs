@@ -2,88 +2,125 @@ use std::collections::BTreeSet;
 use std::collections::{HashMap, HashSet, VecDeque};
 
 use rayon::prelude::{IntoParallelIterator, ParallelIterator};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(Debug)]
-struct Valve {
+pub struct Valve {
     flow_rate: u32,
     leads_to: Vec<String>,
 }
 
-pub fn part1(input: &str) -> u32 {
-    let valves = parse(input);
-
-    let navigation_map: HashMap<&str, HashMap<&str, u32>> = valves
-        .keys()
-        .map(|&name| (name, build_navigation_map(name, &valves)))
-        .collect();
-    let opened: BTreeSet<&str> = navigation_map
-        .keys()
-        .filter(|&key| valves.get(key).unwrap().flow_rate == 0)
-        .copied()
-        .collect();
-    let mut cache = HashMap::new();
-
-    dfs(
-        "AA",
-        1,
-        0,
-        0,
-        30,
-        &navigation_map,
-        &valves,
-        &opened,
-        &mut cache,
-    )
-}
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input<'a> = HashMap<&'a str, Valve>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut valves: HashMap<&str, Valve> = HashMap::new();
+
+        for line in input.lines() {
+            let (valve_part, tunnel_part) = line.split_once("; ").unwrap();
 
-pub fn part2(input: &str) -> u32 {
-    let valves = parse(input);
-
-    let navigation_map: HashMap<&str, HashMap<&str, u32>> = valves
-        .keys()
-        .map(|&name| (name, build_navigation_map(name, &valves)))
-        .collect();
-    let opened: BTreeSet<&str> = navigation_map
-        .keys()
-        .filter(|&key| valves.get(key).unwrap().flow_rate == 0)
-        .copied()
-        .collect();
-    let mut cache = HashSet::new();
-
-    let mut answer: HashMap<BTreeSet<&str>, u32> = HashMap::new();
-    dfs2(
-        "AA",
-        1,
-        0,
-        0,
-        26,
-        &navigation_map,
-        &valves,
-        &opened,
-        &mut cache,
-        &mut answer,
-    );
-
-    answer
-        .into_par_iter()
-        .map(|(set, result)| {
-            let mut cache = HashMap::new();
-            let result2 = dfs(
-                "AA",
-                1,
-                0,
-                0,
-                26,
-                &navigation_map,
-                &valves,
-                &set,
-                &mut cache,
+            let name = &valve_part[6..=7];
+            let flow_rate: u32 = valve_part[8..]
+                .strip_prefix(" has flow rate=")
+                .unwrap()
+                .parse()
+                .unwrap();
+            let leads_to: Vec<String> = tunnel_part
+                .split_whitespace()
+                .skip(4)
+                .collect::<String>()
+                .split(',')
+                .map(|name| name.to_string())
+                .collect();
+
+            valves.insert(
+                name,
+                Valve {
+                    flow_rate,
+                    leads_to,
+                },
             );
+        }
 
-            result + result2
-        })
-        .max()
-        .unwrap()
+        Ok(valves)
+    }
+
+    fn part1(valves: &Self::Input<'_>) -> Answer {
+        let navigation_map: HashMap<&str, HashMap<&str, u32>> = valves
+            .keys()
+            .map(|&name| (name, build_navigation_map(name, valves)))
+            .collect();
+        let opened: BTreeSet<&str> = navigation_map
+            .keys()
+            .filter(|&key| valves.get(key).unwrap().flow_rate == 0)
+            .copied()
+            .collect();
+        let mut cache = HashMap::new();
+
+        dfs(
+            "AA",
+            1,
+            0,
+            0,
+            30,
+            &navigation_map,
+            valves,
+            &opened,
+            &mut cache,
+        ).into()
+    }
+
+    fn part2(valves: &Self::Input<'_>) -> Answer {
+        let navigation_map: HashMap<&str, HashMap<&str, u32>> = valves
+            .keys()
+            .map(|&name| (name, build_navigation_map(name, valves)))
+            .collect();
+        let opened: BTreeSet<&str> = navigation_map
+            .keys()
+            .filter(|&key| valves.get(key).unwrap().flow_rate == 0)
+            .copied()
+            .collect();
+        let mut cache = HashSet::new();
+
+        let mut answer: HashMap<BTreeSet<&str>, u32> = HashMap::new();
+        dfs2(
+            "AA",
+            1,
+            0,
+            0,
+            26,
+            &navigation_map,
+            valves,
+            &opened,
+            &mut cache,
+            &mut answer,
+        );
+
+        answer
+            .into_par_iter()
+            .map(|(set, result)| {
+                let mut cache = HashMap::new();
+                let result2 = dfs(
+                    "AA",
+                    1,
+                    0,
+                    0,
+                    26,
+                    &navigation_map,
+                    valves,
+                    &set,
+                    &mut cache,
+                );
+
+                result + result2
+            })
+            .max()
+            .unwrap()
+            .into()
+    }
 }
 
 // Now we care about the results at the time limit and the opened valves at that point.
@@ -255,38 +292,6 @@ fn build_navigation_map<'a>(
     map
 }
 
-fn parse(input: &str) -> HashMap<&str, Valve> {
-    let mut valves: HashMap<&str, Valve> = HashMap::new();
-
-    for line in input.lines() {
-        let (valve_part, tunnel_part) = line.split_once("; ").unwrap();
-
-        let name = &valve_part[6..=7];
-        let flow_rate: u32 = valve_part[8..]
-            .strip_prefix(" has flow rate=")
-            .unwrap()
-            .parse()
-            .unwrap();
-        let leads_to: Vec<String> = tunnel_part
-            .split_whitespace()
-            .skip(4)
-            .collect::<String>()
-            .split(',')
-            .map(|name| name.to_string())
-            .collect();
-
-        valves.insert(
-            name,
-            Valve {
-                flow_rate,
-                leads_to,
-            },
-        );
-    }
-
-    valves
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -295,7 +300,7 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(1651, part1(INPUT));
+        assert_eq!(1651, Day16::part1(&Day16::parse(INPUT).unwrap()));
     }
 
     // This implementation assumes one person cannot open more than half of the vents.
@@ -1,4 +1,7 @@
-use std::{collections::{HashSet, HashMap}, iter::{Peekable, Cycle, Enumerate}, str::Chars};
+use std::collections::HashSet;
+use util::cycle;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 struct Block {
     x: usize, // left edge
@@ -114,59 +117,78 @@ impl Block {
 const BLOCK_COUNT: usize = 5;
 const WIDTH: usize = 7;
 
-pub fn part1(input: &str) -> usize {
-    play_tetris(input, 2022)
-}
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Vec<char>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.trim().chars().collect())
+    }
 
-pub fn part2(input: &str) -> usize {
-    play_tetris(input, 1_000_000_000_000)
+    fn part1(jets: &Self::Input<'_>) -> Answer {
+        play_tetris(jets, 2022).into()
+    }
+
+    fn part2(jets: &Self::Input<'_>) -> Answer {
+        play_tetris(jets, 1_000_000_000_000).into()
+    }
 }
 
-fn play_tetris(input: &str, limit: usize) -> usize {
-    let mut directions = parse(input);
-
-    let mut tiles: HashSet<(usize, usize)> = HashSet::new();
-    let mut max_height = 0;
-    let mut start_height = 3;
-
-    // (wind index, block index, [number of tiles down from the highest one; 7])  
-    let mut cache: HashMap<(usize, usize, [usize; 7]), (usize, usize)> = HashMap::new();
-    for i in 0..limit {
-        let block_type = i % BLOCK_COUNT;
-        let wind_index = directions.peek().unwrap().0;
-        
-        let state = (block_type, wind_index, get_top_depths(&tiles, max_height));
-
-        if let Some((old_i, old_max)) = cache.insert(state, (i, max_height)) {
-            let period = i - old_i;
-            
-            // no extra iterations
-            if i % period == limit % period {
-                let amount_in_period = max_height - old_max;
-                let remaining = limit - i;
-
-                return old_max + amount_in_period * ((remaining / period) + 1)
-            }
-        }
+/// The settled blocks and the position in the jet pattern.
+struct Chamber<'a> {
+    jets: &'a [char],
+    jet_index: usize,
+    block_count: usize,
+    tiles: HashSet<(usize, usize)>,
+    max_height: usize,
+}
 
-        let mut block = Block::new(2, start_height, block_type);
+impl Chamber<'_> {
+    fn drop_block(&mut self) {
+        let block_type = self.block_count % BLOCK_COUNT;
+        let mut block = Block::new(2, self.max_height + 3, block_type);
         while !block.settled {
-            match directions.next().unwrap().1 {
-                '<' => block.move_left(&tiles),
-                '>' => block.move_right(&tiles),
+            match self.jets[self.jet_index] {
+                '<' => block.move_left(&self.tiles),
+                '>' => block.move_right(&self.tiles),
                 _ => unreachable!(),
             }
+            self.jet_index = (self.jet_index + 1) % self.jets.len();
 
-            block.move_down(&tiles);
+            block.move_down(&self.tiles);
         }
 
-        tiles.extend(block.tiles.iter());
-
-        max_height = max_height.max(block.y + block.height);
-        start_height = max_height + 3;
+        self.tiles.extend(block.tiles.iter());
+        self.max_height = self.max_height.max(block.y + block.height);
+        self.block_count += 1;
     }
+}
 
-    max_height
+fn play_tetris(jets: &[char], limit: usize) -> usize {
+    let chamber = Chamber {
+        jets,
+        jet_index: 0,
+        block_count: 0,
+        tiles: HashSet::new(),
+        max_height: 0,
+    };
+
+    let (cycle, heights) = cycle::find_cycle(
+        chamber,
+        Chamber::drop_block,
+        // (block index, wind index, [number of tiles down from the highest one; 7])
+        |chamber| {
+            (
+                chamber.block_count % BLOCK_COUNT,
+                chamber.jet_index,
+                get_top_depths(&chamber.tiles, chamber.max_height),
+            )
+        },
+        |chamber| chamber.max_height,
+    );
+
+    cycle.extrapolate(&heights, limit)
 }
 
 fn get_top_depths(tiles: &HashSet<(usize, usize)>, max_height: usize) -> [usize; WIDTH] {
@@ -206,10 +228,6 @@ fn print_tetris(tiles: &HashSet<(usize, usize)>) {
     println!()
 }
 
-fn parse(input: &str) -> Peekable<Cycle<Enumerate<Chars>>> {
-    input.trim().chars().enumerate().cycle().peekable()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -218,11 +236,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(3068, part1(INPUT));
+        assert_eq!(3068, Day17::part1(&Day17::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(1514285714288, part2(INPUT));
+        assert_eq!(1514285714288, Day17::part2(&Day17::parse(INPUT).unwrap()));
     }
 }
@@ -1,9 +1,11 @@
 use std::collections::{VecDeque, HashSet};
 
 use rayon::prelude::*;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(Debug)]
-struct Blueprint {
+pub struct Blueprint {
     id: u32,
     ore_cost: u32,
     clay_cost: u32,
@@ -41,21 +43,40 @@ impl OreCounts {
     }
 }
 
-pub fn part1(input: &str) -> u32 {
-    let blueprints = parse(input);
+pub struct Day19;
 
-    blueprints.par_iter()
-        .map(|bp| bp.id * calc_max_geodes(bp, 24))
-        .sum()
-}
+impl Solution for Day19 {
+    type Input<'a> = Vec<Blueprint>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines().map(|line| {
+            let mut parts = line.split_whitespace();
+            let id = parts.nth(1).unwrap().strip_suffix(':').unwrap().parse().unwrap();
+            let ore_cost = parts.nth(4).unwrap().parse().unwrap();
+            let clay_cost = parts.nth(5).unwrap().parse().unwrap();
+            let obsi_cost_ore = parts.nth(5).unwrap().parse().unwrap();
+            let obsi_cost_clay = parts.nth(2).unwrap().parse().unwrap();
+            let geode_cost_ore = parts.nth(5).unwrap().parse().unwrap();
+            let geode_cost_obsi = parts.nth(2).unwrap().parse().unwrap();
+
+            Blueprint::new(id, ore_cost, clay_cost, (obsi_cost_ore, obsi_cost_clay), (geode_cost_ore, geode_cost_obsi))
+        }).collect())
+    }
 
-pub fn part2(input: &str) -> u32 {
-    let blueprints = parse(input);
+    fn part1(blueprints: &Self::Input<'_>) -> Answer {
+        blueprints.par_iter()
+            .map(|bp| bp.id * calc_max_geodes(bp, 24))
+            .sum::<u32>()
+            .into()
+    }
 
-    blueprints.par_iter()
-        .take(3)
-        .map(|bp| calc_max_geodes(bp, 32))
-        .product()
+    fn part2(blueprints: &Self::Input<'_>) -> Answer {
+        blueprints.par_iter()
+            .take(3)
+            .map(|bp| calc_max_geodes(bp, 32))
+            .product::<u32>()
+            .into()
+    }
 }
 
 fn calc_max_geodes(blueprint: &Blueprint, time_limit: u32) -> u32 {
@@ -153,23 +174,6 @@ fn calc_max_geodes(blueprint: &Blueprint, time_limit: u32) -> u32 {
     max_geodes
 }
 
-fn parse(input: &str) -> Vec<Blueprint> {
-    input.lines().map(|line| {
-        let mut parts = line.split_whitespace();
-        let id = parts.nth(1).unwrap().strip_suffix(':').unwrap().parse().unwrap();
-        let ore_cost = parts.nth(4).unwrap().parse().unwrap();
-        let clay_cost = parts.nth(5).unwrap().parse().unwrap();
-        let obsi_cost_ore = parts.nth(5).unwrap().parse().unwrap();
-        let obsi_cost_clay = parts.nth(2).unwrap().parse().unwrap();
-        let geode_cost_ore = parts.nth(5).unwrap().parse().unwrap();
-        let geode_cost_obsi = parts.nth(2).unwrap().parse().unwrap();
-
-        Blueprint::new(id, ore_cost, clay_cost, (obsi_cost_ore, obsi_cost_clay), (geode_cost_ore, geode_cost_obsi))
-    }).collect()
-
-    
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,12 +182,12 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(33, part1(INPUT));
+        assert_eq!(33, Day19::part1(&Day19::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
         // works but takes 2 minutes
-        // assert_eq!(3472, part2(INPUT));
+        // assert_eq!(3472, Day19::part2(&Day19::parse(INPUT).unwrap()));
     }
 }
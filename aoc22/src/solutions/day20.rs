@@ -1,4 +1,6 @@
 use std::collections::VecDeque;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 struct CyclingVec {
     values: VecDeque<(usize, i64)>,
@@ -27,16 +29,25 @@ impl CyclingVec {
     }
 }
 
-pub fn part1(input: &str) -> i64 {
-    let values = parse(input);
-    
-    solve(&values, 1, 1)
-}
+pub struct Day20;
+
+impl Solution for Day20 {
+    type Input<'a> = Vec<i64>;
 
-pub fn part2(input: &str) -> i64 {
-    let values = parse(input);
-    
-    solve(&values, 811_589_153, 10)
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|n| n.parse().unwrap())
+            .collect())
+    }
+
+    fn part1(values: &Self::Input<'_>) -> Answer {
+        solve(values, 1, 1).into()
+    }
+
+    fn part2(values: &Self::Input<'_>) -> Answer {
+        solve(values, 811_589_153, 10).into()
+    }
 }
 
 fn solve(values: &[i64], key: i64, times: usize) -> i64 {
@@ -57,13 +68,6 @@ fn solve(values: &[i64], key: i64, times: usize) -> i64 {
         .sum()
 }
 
-fn parse(input: &str) -> Vec<i64> {
-    input
-        .lines()
-        .map(|n| n.parse().unwrap())
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -72,11 +76,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(3, part1(INPUT));
+        assert_eq!(3, Day20::part1(&Day20::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(1_623_178_306, part2(INPUT));
+        assert_eq!(1_623_178_306, Day20::part2(&Day20::parse(INPUT).unwrap()));
     }
 }
@@ -1,9 +1,11 @@
 use core::panic;
 use std::collections::HashSet;
 use util::grid::Direction;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(Debug)]
-enum Instr {
+pub enum Instr {
     Walk(u32),
     TurnLeft,
     TurnRight,
@@ -24,59 +26,106 @@ impl Score for Direction {
     }
 }
 
-pub fn part1(input: &str) -> i32 {
-    let ((open_map, wall_map), instructions) = parse(input);
-
-    let all_tiles = open_map.union(&wall_map).collect();
-
-    // start in left most coordinate in the top row, facing right
-    let mut position = *open_map.iter().filter(|(_, y)| *y == 0).min().unwrap();
-    let mut direction = Direction::Right;
+pub struct Day22;
+
+impl Solution for Day22 {
+    type Input<'a> = (TileMaps, Vec<Instr>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut open_tiles = HashSet::new();
+        let mut wall_tiles = HashSet::new();
+
+        let (layout, path) = input.split_once("\n\n").unwrap();
+        for (y, row) in layout.lines().enumerate() {
+            for (x, cell) in row.chars().enumerate() {
+                let (x, y) = (x as i32, y as i32);
+                match cell {
+                    '.' => {
+                        open_tiles.insert((x, y));
+                    }
+                    '#' => {
+                        wall_tiles.insert((x, y));
+                    }
+                    _ => {}
+                }
+            }
+        }
 
-    for instruction in instructions {
-        match instruction {
-            Instr::TurnRight => direction.turn_right(),
-            Instr::TurnLeft => direction.turn_left(),
-            Instr::Walk(n) => {
-                position = take_steps(position, &direction, n, &open_map, &wall_map, &all_tiles);
+        let mut instructions = Vec::new();
+        let mut val = 0;
+        for c in path.trim().chars() {
+            if c.is_ascii_digit() {
+                val = val * 10 + c.to_digit(10).unwrap();
+            } else {
+                instructions.push(Instr::Walk(val));
+                val = 0;
+
+                let turn = match c {
+                    'R' => Instr::TurnRight,
+                    'L' => Instr::TurnLeft,
+                    _ => unreachable!(),
+                };
+                instructions.push(turn);
             }
         }
+        instructions.push(Instr::Walk(val));
+
+        Ok(((open_tiles, wall_tiles), instructions))
     }
 
-    // row and column counts start at 1
-    (position.1 + 1) * 1000 + (position.0 + 1) * 4 + direction.get_score()
-}
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let ((open_map, wall_map), instructions) = input;
+
+        let all_tiles = open_map.union(wall_map).collect();
 
-pub fn part2(input: &str) -> i32 {
-    let ((open_map, wall_map), instructions) = parse(input);
-
-    // start in left most coordinate in the top row, facing right
-    let mut position = *open_map.iter().filter(|(_, y)| *y == 0).min().unwrap();
-    let mut direction = Direction::Right;
-
-    for instruction in instructions {
-        match instruction {
-            Instr::TurnRight => direction.turn_right(),
-            Instr::TurnLeft => direction.turn_left(),
-            Instr::Walk(n) => {
-                position = take_steps_cube(
-                    position,
-                    &mut direction,
-                    n,
-                    &open_map,
-                    &wall_map,
-                );
+        // start in left most coordinate in the top row, facing right
+        let mut position = *open_map.iter().filter(|(_, y)| *y == 0).min().unwrap();
+        let mut direction = Direction::Right;
+
+        for instruction in instructions {
+            match instruction {
+                Instr::TurnRight => direction.turn_right(),
+                Instr::TurnLeft => direction.turn_left(),
+                Instr::Walk(n) => {
+                    position = take_steps(position, &direction, *n, open_map, wall_map, &all_tiles);
+                }
             }
         }
+
+        // row and column counts start at 1
+        ((position.1 + 1) * 1000 + (position.0 + 1) * 4 + direction.get_score()).into()
     }
 
-    // row and column counts start at 1
-    (position.1 + 1) * 1000 + (position.0 + 1) * 4 + direction.get_score()
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let ((open_map, wall_map), instructions) = input;
+
+        // start in left most coordinate in the top row, facing right
+        let mut position = *open_map.iter().filter(|(_, y)| *y == 0).min().unwrap();
+        let mut direction = Direction::Right;
+
+        for instruction in instructions {
+            match instruction {
+                Instr::TurnRight => direction.turn_right(),
+                Instr::TurnLeft => direction.turn_left(),
+                Instr::Walk(n) => {
+                    position = take_steps_cube(
+                        position,
+                        &mut direction,
+                        *n,
+                        open_map,
+                        wall_map,
+                    );
+                }
+            }
+        }
+
+        // row and column counts start at 1
+        ((position.1 + 1) * 1000 + (position.0 + 1) * 4 + direction.get_score()).into()
+    }
 }
 
 const SIZE: i32 = 50;
 
-
 // Take steps on the cube. Manually implemented for my personal input. NOT a general solution.
 fn take_steps_cube(
     position: (i32, i32),
@@ -225,48 +274,6 @@ fn take_steps(
 
 type TileMaps = (HashSet<(i32, i32)>, HashSet<(i32, i32)>);
 
-fn parse(input: &str) -> (TileMaps, Vec<Instr>) {
-    let mut open_tiles = HashSet::new();
-    let mut wall_tiles = HashSet::new();
-
-    let (layout, path) = input.split_once("\n\n").unwrap();
-    for (y, row) in layout.lines().enumerate() {
-        for (x, cell) in row.chars().enumerate() {
-            let (x, y) = (x as i32, y as i32);
-            match cell {
-                '.' => {
-                    open_tiles.insert((x, y));
-                }
-                '#' => {
-                    wall_tiles.insert((x, y));
-                }
-                _ => {}
-            }
-        }
-    }
-
-    let mut instructions = Vec::new();
-    let mut val = 0;
-    for c in path.trim().chars() {
-        if c.is_ascii_digit() {
-            val = val * 10 + c.to_digit(10).unwrap();
-        } else {
-            instructions.push(Instr::Walk(val));
-            val = 0;
-
-            let turn = match c {
-                'R' => Instr::TurnRight,
-                'L' => Instr::TurnLeft,
-                _ => unreachable!(),
-            };
-            instructions.push(turn);
-        }
-    }
-    instructions.push(Instr::Walk(val));
-
-    ((open_tiles, wall_tiles), instructions)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -275,6 +282,6 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(6032, part1(INPUT));
+        assert_eq!(6032, Day22::part1(&Day22::parse(INPUT).unwrap()));
     }
 }
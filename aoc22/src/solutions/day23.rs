@@ -1,51 +1,95 @@
 use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
+use util::grid::{Coordinate, Direction8};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
+/// The directions in which elves propose to move, in the order of the first round.
+const PROPOSALS: [Direction8; 4] = [
+    Direction8::North,
+    Direction8::South,
+    Direction8::West,
+    Direction8::East,
+];
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    type Input<'a> = HashSet<Coordinate>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, line)| {
+                line.chars()
+                    .enumerate()
+                    .filter_map(move |(x, cell)| {
+                        if cell == '#' {
+                            Some(Coordinate::from(x as i32, y as i32))
+                        } else {
+                            None
+                        }
+                    })
+                    .collect::<HashSet<Coordinate>>()
+            })
+            .collect())
+    }
+
+    fn part1(elves: &Self::Input<'_>) -> Answer {
+        let mut elves = elves.clone();
 
-pub fn part1(input: &str) -> usize {
-    let mut elves = parse(input);
+        for i in 0..10 {
+            elves = iteration(&elves, i);
+        }
 
-    for i in 0..10 {
-        elves = iteration(&elves, i);
+        count_empty(&elves).into()
     }
 
-    count_empty(&elves)
-}
+    fn part2(elves: &Self::Input<'_>) -> Answer {
+        let mut elves = elves.clone();
 
-pub fn part2(input: &str) -> u32 {
-    let mut elves = parse(input);
+        let mut i: u32 = 0;
+        loop {
+            let next_elves = iteration(&elves, i);
+            i += 1;
+            if next_elves == elves {
+                return i.into();
+            }
 
-    let mut i = 0;
-    loop {
-        let next_elves = iteration(&elves, i);
-        i += 1;
-        if next_elves == elves {
-            return i;
+            elves = next_elves;
         }
-
-        elves = next_elves;
     }
 }
 
-fn iteration(elves: &HashSet<(i32, i32)>, i: u32) -> HashSet<(i32, i32)> {
+fn iteration(elves: &HashSet<Coordinate>, i: u32) -> HashSet<Coordinate> {
     let mut new_elves = HashSet::with_capacity(elves.len());
 
     // create a map of 'desired pos': Vec<'position of elf who wants this position'>
-    let mut desired_moves: HashMap<(i32, i32), Vec<(i32, i32)>> = HashMap::new();
+    let mut desired_moves: HashMap<Coordinate, Vec<Coordinate>> = HashMap::new();
     'loop_elves: for pos in elves.iter() {
-        let (x, y) = *pos;
         // check all 8 adjacent positions. If all are empty do nothing.
-        if util::grid::get_all_adj_not_checking(x, y).all(|adj| !elves.contains(&adj)) {
+        if pos
+            .get_adjacent_with_diag()
+            .iter()
+            .all(|adj| !elves.contains(adj))
+        {
             new_elves.insert(*pos);
             continue;
         }
 
-        // consider each of the four directions
+        // consider each of the four directions together with their diagonal neighbours
         for j in 0..4 {
-            let n = (i + j) % 4;
-            let directional = get_in_direction(x, y, n);
-            if directional.iter().all(|adj| !elves.contains(adj)) {
-                desired_moves.entry(directional[1]).or_default().push(*pos);
+            let direction = PROPOSALS[((i + j) % 4) as usize];
+            let is_free = [direction.left(), direction, direction.right()]
+                .iter()
+                .all(|looking| !elves.contains(&pos.step(looking, 1)));
+            if is_free {
+                desired_moves
+                    .entry(pos.step(&direction, 1))
+                    .or_default()
+                    .push(*pos);
                 continue 'loop_elves;
             }
         }
@@ -68,51 +112,18 @@ fn iteration(elves: &HashSet<(i32, i32)>, i: u32) -> HashSet<(i32, i32)> {
     new_elves
 }
 
-fn get_in_direction(x: i32, y: i32, n: u32) -> [(i32, i32); 3] {
-    match n {
-        // north
-        0 => [(x - 1, y - 1), (x, y - 1), (x + 1, y - 1)],
-        // south
-        1 => [(x - 1, y + 1), (x, y + 1), (x + 1, y + 1)],
-        // west
-        2 => [(x - 1, y - 1), (x - 1, y), (x - 1, y + 1)],
-        // east
-        3 => [(x + 1, y - 1), (x + 1, y), (x + 1, y + 1)],
-        _ => unreachable!(),
-    }
-}
-
-fn count_empty(elves: &HashSet<(i32, i32)>) -> usize {
-    let min_x = elves.iter().min_by_key(|pos| pos.0).unwrap().0;
-    let max_x = elves.iter().max_by_key(|pos| pos.0).unwrap().0;
-    let min_y = elves.iter().min_by_key(|pos| pos.1).unwrap().1;
-    let max_y = elves.iter().max_by_key(|pos| pos.1).unwrap().1;
+fn count_empty(elves: &HashSet<Coordinate>) -> usize {
+    let min_x = elves.iter().map(|pos| pos.x).min().unwrap();
+    let max_x = elves.iter().map(|pos| pos.x).max().unwrap();
+    let min_y = elves.iter().map(|pos| pos.y).min().unwrap();
+    let max_y = elves.iter().map(|pos| pos.y).max().unwrap();
 
     (min_x..=max_x)
         .cartesian_product(min_y..=max_y)
-        .filter(|pos| !elves.contains(pos))
+        .filter(|&(x, y)| !elves.contains(&Coordinate::from(x, y)))
         .count()
 }
 
-fn parse(input: &str) -> HashSet<(i32, i32)> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.chars()
-                .enumerate()
-                .filter_map(move |(x, cell)| {
-                    if cell == '#' {
-                        Some((x as i32, y as i32))
-                    } else {
-                        None
-                    }
-                })
-                .collect::<HashSet<(i32, i32)>>()
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -121,11 +132,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(110, part1(INPUT));
+        assert_eq!(110, Day23::part1(&Day23::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(20, part2(INPUT));
+        assert_eq!(20, Day23::part2(&Day23::parse(INPUT).unwrap()));
     }
 }
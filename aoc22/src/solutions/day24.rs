@@ -1,24 +1,57 @@
-use std::collections::{HashMap, HashSet, VecDeque};
+use std::collections::{HashMap, HashSet};
+
+use util::parse::ParseError;
+use util::search;
+use util::solution::{Answer, Solution};
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    type Input<'a> = (HashSet<(i32, i32)>, Vec<(i32, i32, char)>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut coordinates = HashSet::new();
+        let mut blizzards = Vec::new();
+
+        for (y, line) in input.lines().enumerate() {
+            for (x, cell) in line.chars().enumerate() {
+                let (x, y) = (x as i32, y as i32);
+                match cell {
+                    '<' | '>' | 'v' | '^' => {
+                        coordinates.insert((x, y));
+                        blizzards.push((x, y, cell));
+                    }
+                    '.' => {
+                        coordinates.insert((x, y));
+                    }
+                    _ => {}
+                }
+            }
+        }
 
-use itertools::Itertools;
+        Ok((coordinates, blizzards))
+    }
 
-pub fn part1(input: &str) -> u32 {
-    let (coordinates, mut blizzards) = parse(input);
-    let (start, goal) = find_start_end(&coordinates);
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (coordinates, blizzards) = input;
+        let mut blizzards = blizzards.clone();
+        let (start, goal) = find_start_end(coordinates);
 
-    find_shortest_path(&coordinates, &mut blizzards, start, goal)
-}
+        find_shortest_path(coordinates, &mut blizzards, start, goal).into()
+    }
 
-pub fn part2(input: &str) -> u32 {
-    let (coordinates, mut blizzards) = parse(input);
-    let (start, goal) = find_start_end(&coordinates);
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (coordinates, blizzards) = input;
+        let mut blizzards = blizzards.clone();
+        let (start, goal) = find_start_end(coordinates);
 
-    // just go from start to end, from end to start and then from start to end.
-    let way1 = find_shortest_path(&coordinates, &mut blizzards, start, goal);
-    let way2 = find_shortest_path(&coordinates, &mut blizzards, goal, start);
-    let way3 = find_shortest_path(&coordinates, &mut blizzards, start, goal);
+        // just go from start to end, from end to start and then from start to end.
+        let way1 = find_shortest_path(coordinates, &mut blizzards, start, goal);
+        let way2 = find_shortest_path(coordinates, &mut blizzards, goal, start);
+        let way3 = find_shortest_path(coordinates, &mut blizzards, start, goal);
 
-    way1 + way2 + way3 + 2
+        (way1 + way2 + way3 + 2).into()
+    }
 }
 
 fn find_shortest_path(
@@ -27,48 +60,32 @@ fn find_shortest_path(
     start: (i32, i32),
     goal: (i32, i32),
 ) -> u32 {
-    // Breadth first search
-
     // lookup which saves the coordinates of all blizzards at a specific time
     let mut blizzard_locations: HashMap<u32, HashSet<(i32, i32)>> =
         HashMap::from([(0, get_blizzard_locations(blizzards))]);
 
     // state := (time, (x, y))
-    let mut queue = VecDeque::from([(0, start)]);
-    let mut visited = HashSet::new();
-
-    while let Some((time, (x, y))) = queue.pop_front() {
-        if (x, y) == goal {
-            return time;
-        }
-        if visited.contains(&(time, (x, y))) {
-            continue;
-        }
-        visited.insert((time, (x, y)));
-
-        let next_time = time + 1;
-        if !blizzard_locations.keys().contains(&next_time) {
-            // simulate next step for the blizzards and add coordinates to the map
-            *blizzards = simulate_next_blizzards(coordinates, blizzards);
-            blizzard_locations.insert(next_time, get_blizzard_locations(blizzards));
-        }
-
-        // add states for the possible subsequent positions to the queue
-        for (xx, yy) in get_possible_moves(x, y) {
-            // next coordinate does not exist or is blocked by a blizzard
-            if !coordinates.contains(&(xx, yy))
-                || blizzard_locations
-                    .get(&next_time)
-                    .unwrap()
-                    .contains(&(xx, yy))
-            {
-                continue;
-            }
-            queue.push_back((next_time, (xx, yy)));
-        }
-    }
-
-    unreachable!()
+    let path = search::bfs(
+        [(0, start)],
+        |&(time, (x, y))| {
+            let next_time = time + 1;
+            let locations = blizzard_locations.entry(next_time).or_insert_with(|| {
+                // simulate next step for the blizzards
+                *blizzards = simulate_next_blizzards(coordinates, blizzards);
+                get_blizzard_locations(blizzards)
+            });
+
+            // next coordinate has to exist and must not be blocked by a blizzard
+            get_possible_moves(x, y)
+                .filter(|next| coordinates.contains(next) && !locations.contains(next))
+                .map(|next| (next_time, next))
+                .collect::<Vec<_>>()
+        },
+        |(_, position)| *position == goal,
+    )
+    .unwrap();
+
+    path.cost as u32
 }
 
 fn simulate_next_blizzards(
@@ -121,30 +138,6 @@ fn get_possible_moves(x: i32, y: i32) -> impl Iterator<Item = (i32, i32)> {
     [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1), (x, y)].into_iter()
 }
 
-#[allow(clippy::type_complexity)]
-fn parse(input: &str) -> (HashSet<(i32, i32)>, Vec<(i32, i32, char)>) {
-    let mut coordinates = HashSet::new();
-    let mut blizzards = Vec::new();
-
-    for (y, line) in input.lines().enumerate() {
-        for (x, cell) in line.chars().enumerate() {
-            let (x, y) = (x as i32, y as i32);
-            match cell {
-                '<' | '>' | 'v' | '^' => {
-                    coordinates.insert((x, y));
-                    blizzards.push((x, y, cell));
-                }
-                '.' => {
-                    coordinates.insert((x, y));
-                }
-                _ => {}
-            }
-        }
-    }
-
-    (coordinates, blizzards)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -153,11 +146,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(18, part1(INPUT));
+        assert_eq!(18, Day24::part1(&Day24::parse(INPUT).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(54, part2(INPUT));
+        assert_eq!(54, Day24::part2(&Day24::parse(INPUT).unwrap()));
     }
 }
@@ -1,11 +1,22 @@
-pub fn part1(input: &str) -> String {
-    let nums = parse(input);
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-    to_snafu(nums.iter().sum())
-}
+pub struct Day25;
+
+impl Solution for Day25 {
+    type Input<'a> = Vec<i64>;
 
-pub fn part2(_: &str) -> &'static str {
-    "All done :)"
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input.lines().map(to_int).collect())
+    }
+
+    fn part1(nums: &Self::Input<'_>) -> Answer {
+        to_snafu(nums.iter().sum()).into()
+    }
+
+    fn part2(_: &Self::Input<'_>) -> Answer {
+        "All done :)".into()
+    }
 }
 
 fn to_snafu(num: i64) -> String {
@@ -51,10 +62,6 @@ fn to_int(snafu: &str) -> i64 {
     num
 }
 
-fn parse(input: &str) -> Vec<i64> {
-    input.lines().map(to_int).collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -95,6 +102,6 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!("2=-1=0", part1(INPUT));
+        assert_eq!("2=-1=0", Day25::part1(&Day25::parse(INPUT).unwrap()));
     }
 }
@@ -1,6 +1,8 @@
 use fancy_regex::{Captures, Regex};
 use lazy_static::lazy_static;
 use std::error::Error;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 trait FindDigit {
     fn find_first_digit(&self, line: &str) -> Option<char>;
@@ -54,12 +56,22 @@ fn get_captured_char(captures: &Captures) -> char {
     }
 }
 
-pub fn part1(input: &str) -> u32 {
-    sum_calibration_numbers(input, &NumbersOnly)
-}
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
 
-pub fn part2(input: &str) -> u32 {
-    sum_calibration_numbers(input, &NumbersAndSpelled)
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        sum_calibration_numbers(input, &NumbersOnly).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        sum_calibration_numbers(input, &NumbersAndSpelled).into()
+    }
 }
 
 fn sum_calibration_numbers(input: &str, finder: &impl FindDigit) -> u32 {
@@ -87,12 +99,12 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(142, part1(EXAMPLE1));
+        assert_eq!(142, Day01::part1(&Day01::parse(EXAMPLE1).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(281, part2(EXAMPLE2));
+        assert_eq!(281, Day01::part2(&Day01::parse(EXAMPLE2).unwrap()));
     }
 
     #[test]
@@ -1,7 +1,10 @@
-use lazy_static::lazy_static;
-use regex::Regex;
+use std::str::FromStr;
 
-struct Game {
+use util::parse::{self, ParseError};
+use util::scan;
+use util::solution::{Answer, Solution};
+
+pub struct Game {
     id: usize,
     measurements: Vec<Measurement>,
 }
@@ -11,17 +14,6 @@ impl Game {
         Game { id, measurements }
     }
 
-    fn from(line: &str) -> Self {
-        let (id, cube_subsets) = line.split_once(": ").unwrap();
-        let id = id.strip_prefix("Game ").unwrap().parse().unwrap();
-        let measurements = cube_subsets
-            .split(';')
-            .map(Measurement::get_from_counts)
-            .collect();
-
-        Game::new(id, measurements)
-    }
-
     fn is_possible(&self) -> bool {
         self.measurements.iter().all(Measurement::is_possible)
     }
@@ -35,6 +27,20 @@ impl Game {
     }
 }
 
+impl FromStr for Game {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let (id, cube_subsets) = scan!(line, "Game {}: {}", usize, String)?;
+        let measurements = cube_subsets
+            .split(';')
+            .map(Measurement::get_from_counts)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Game::new(id, measurements))
+    }
+}
+
 struct Measurement {
     red: usize,
     green: usize,
@@ -46,24 +52,23 @@ impl Measurement {
         Measurement { red, green, blue }
     }
 
-    fn get_from_counts(color_counts: &str) -> Self {
+    fn get_from_counts(color_counts: &str) -> Result<Self, ParseError> {
         let mut red = 0;
         let mut green = 0;
         let mut blue = 0;
 
-        COLORS.captures_iter(color_counts).for_each(|capture| {
-            let amount = capture.get(1).unwrap().as_str().parse::<usize>().unwrap();
-            let color = capture.get(2).unwrap().as_str();
+        for color_count in color_counts.split(',') {
+            let (amount, color) = scan!(color_count.trim(), "{} {}", usize, String)?;
 
-            match color {
+            match color.as_str() {
                 "red" => red = amount,
                 "green" => green = amount,
                 "blue" => blue = amount,
-                _ => unreachable!(),
+                _ => return Err(ParseError::new(&color, "unknown color")),
             }
-        });
+        }
 
-        Measurement::new(red, green, blue)
+        Ok(Measurement::new(red, green, blue))
     }
 
     fn is_possible(&self) -> bool {
@@ -79,32 +84,32 @@ const RED_COUNT: usize = 12;
 const GREEN_COUNT: usize = 13;
 const BLUE_COUNT: usize = 14;
 
-lazy_static! {
-    static ref COLORS: Regex = Regex::new(r"(\d+) (red|green|blue)").unwrap();
-}
+pub struct Day02;
 
-pub fn part1(input: &str) -> usize {
-    let games = parse(input);
+impl Solution for Day02 {
+    type Input<'a> = Vec<Game>;
 
-    games
-        .iter()
-        .filter(|game| game.is_possible())
-        .map(|game| game.id)
-        .sum()
-}
-
-pub fn part2(input: &str) -> usize {
-    let games = parse(input);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        parse::map_lines(input, str::parse)
+    }
 
-    games
-        .iter()
-        .map(Game::minimum_cubes)
-        .map(Measurement::calc_power)
-        .sum()
-}
+    fn part1(games: &Self::Input<'_>) -> Answer {
+        games
+            .iter()
+            .filter(|game| game.is_possible())
+            .map(|game| game.id)
+            .sum::<usize>()
+            .into()
+    }
 
-fn parse(input: &str) -> Vec<Game> {
-    input.lines().map(Game::from).collect()
+    fn part2(games: &Self::Input<'_>) -> Answer {
+        games
+            .iter()
+            .map(Game::minimum_cubes)
+            .map(Measurement::calc_power)
+            .sum::<usize>()
+            .into()
+    }
 }
 
 #[cfg(test)]
@@ -115,8 +120,9 @@ mod tests {
 
     #[test]
     fn parse_game_correctly() {
-        let game =
-            Game::from("Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red");
+        let game: Game = "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red"
+            .parse()
+            .unwrap();
 
         assert_eq!(3, game.id);
         assert_eq!(3, game.measurements.len());
@@ -127,11 +133,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(8, part1(EXAMPLE));
+        assert_eq!(8, Day02::part1(&Day02::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(2286, part2(EXAMPLE));
+        assert_eq!(2286, Day02::part2(&Day02::parse(EXAMPLE).unwrap()));
     }
 }
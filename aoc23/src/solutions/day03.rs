@@ -1,8 +1,10 @@
 use regex::Regex;
 use std::collections::HashMap;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(Debug)]
-struct EnginePart {
+pub struct EnginePart {
     value: usize,
     y: i32,
     x_start: i32,
@@ -23,24 +25,39 @@ impl EnginePart {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let (engine_parts, symbol_locations) = parse(input);
+pub struct Day03;
 
-    engine_parts
-        .iter()
-        .filter(|engine_part| is_part_number(engine_part, &symbol_locations))
-        .map(|engine_part| engine_part.value)
-        .sum()
-}
+impl Solution for Day03 {
+    type Input<'a> = (Vec<EnginePart>, HashMap<(i32, i32), char>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let engine_parts = parse_engine_parts(input);
+        let symbol_locations = parse_symbol_locations(input);
+
+        Ok((engine_parts, symbol_locations))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (engine_parts, symbol_locations) = input;
 
-pub fn part2(input: &str) -> usize {
-    let (engine_parts, symbol_locations) = parse(input);
-    let gear_connections = build_gear_connections(&engine_parts, &symbol_locations);
+        engine_parts
+            .iter()
+            .filter(|engine_part| is_part_number(engine_part, symbol_locations))
+            .map(|engine_part| engine_part.value)
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (engine_parts, symbol_locations) = input;
+        let gear_connections = build_gear_connections(engine_parts, symbol_locations);
 
-    gear_connections.values()
-        .filter(|parts| parts.len() == 2)
-        .map(|parts| parts[0] * parts[1])
-        .sum()
+        gear_connections.values()
+            .filter(|parts| parts.len() == 2)
+            .map(|parts| parts[0] * parts[1])
+            .sum::<usize>()
+            .into()
+    }
 }
 
 fn is_part_number(engine_part: &EnginePart, symbols: &HashMap<(i32, i32), char>) -> bool {
@@ -69,13 +86,6 @@ fn build_gear_connections(
     gear_connected_to
 }
 
-fn parse(input: &str) -> (Vec<EnginePart>, HashMap<(i32, i32), char>) {
-    let engine_parts = parse_engine_parts(input);
-    let symbol_locations = parse_symbol_locations(input);
-
-    (engine_parts, symbol_locations)
-}
-
 fn parse_engine_parts(input: &str) -> Vec<EnginePart> {
     let part_pattern = Regex::new(r"(\d+)").unwrap();
 
@@ -122,11 +132,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(4361, part1(EXAMPLE));
+        assert_eq!(4361, Day03::part1(&Day03::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(467835, part2(EXAMPLE));
+        assert_eq!(467835, Day03::part2(&Day03::parse(EXAMPLE).unwrap()));
     }
 }
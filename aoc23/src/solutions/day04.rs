@@ -1,8 +1,10 @@
 use regex::Regex;
 use std::collections::HashSet;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(Clone)]
-struct Card {
+pub struct Card {
     id: usize,
     winning: HashSet<usize>,
     numbers: HashSet<usize>,
@@ -23,55 +25,59 @@ impl Card {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let cards = parse(input);
-    cards.iter().map(Card::calc_points).sum()
-}
-
-pub fn part2(input: &str) -> usize {
-    let cards = parse(input);
-    let mut card_counts = vec![1; cards.len()];
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input<'a> = Vec<Card>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let card_pattern = Regex::new(r"Card\s*(\d+):\s*([\d\s]+)\s*\|\s*([\d\s]+)").unwrap();
+        Ok(input
+            .lines()
+            .map(|line| {
+                let captures = card_pattern.captures(line).unwrap();
+                let id = captures.get(1).unwrap().as_str().parse().unwrap();
+                let winning = captures
+                    .get(2)
+                    .unwrap()
+                    .as_str()
+                    .split_whitespace()
+                    .map(|num| num.parse().unwrap())
+                    .collect();
+                let numbers = captures
+                    .get(3)
+                    .unwrap()
+                    .as_str()
+                    .split_whitespace()
+                    .map(|num| num.parse().unwrap())
+                    .collect();
+
+                Card {
+                    id,
+                    winning,
+                    numbers,
+                }
+            })
+            .collect())
+    }
 
-    for card in cards {
-        let index = card.id - 1;
-        let count = card_counts[index];
-        for i in 1..=card.matching_count() {
-            card_counts[index + i] += count;
-        }
+    fn part1(cards: &Self::Input<'_>) -> Answer {
+        cards.iter().map(Card::calc_points).sum::<usize>().into()
     }
 
-    card_counts.iter().sum()
-}
+    fn part2(cards: &Self::Input<'_>) -> Answer {
+        let mut card_counts = vec![1; cards.len()];
 
-fn parse(input: &str) -> Vec<Card> {
-    let card_pattern = Regex::new(r"Card\s*(\d+):\s*([\d\s]+)\s*\|\s*([\d\s]+)").unwrap();
-    input
-        .lines()
-        .map(|line| {
-            let captures = card_pattern.captures(line).unwrap();
-            let id = captures.get(1).unwrap().as_str().parse().unwrap();
-            let winning = captures
-                .get(2)
-                .unwrap()
-                .as_str()
-                .split_whitespace()
-                .map(|num| num.parse().unwrap())
-                .collect();
-            let numbers = captures
-                .get(3)
-                .unwrap()
-                .as_str()
-                .split_whitespace()
-                .map(|num| num.parse().unwrap())
-                .collect();
-
-            Card {
-                id,
-                winning,
-                numbers,
+        for card in cards {
+            let index = card.id - 1;
+            let count = card_counts[index];
+            for i in 1..=card.matching_count() {
+                card_counts[index + i] += count;
             }
-        })
-        .collect()
+        }
+
+        card_counts.iter().sum::<usize>().into()
+    }
 }
 
 #[cfg(test)]
@@ -93,11 +99,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(13, part1(EXAMPLE));
+        assert_eq!(13, Day04::part1(&Day04::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(30, part2(EXAMPLE));
+        assert_eq!(30, Day04::part2(&Day04::parse(EXAMPLE).unwrap()));
     }
 }
@@ -1,72 +1,76 @@
-use rayon::prelude::*;
+use util::interval::IntervalSet;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-struct Translation {
+pub struct Translation {
     source_start: usize,
     destination_start: usize,
     range_length: usize,
 }
 
 impl Translation {
-    fn contains(&self, value: &usize) -> bool {
-        let range = self.source_start..(self.source_start + self.range_length);
-        range.contains(value)
+    fn source(&self) -> IntervalSet<usize> {
+        IntervalSet::from(self.source_start..(self.source_start + self.range_length))
     }
 
-    fn translate(&self, value: usize) -> usize {
-        let offset = value - self.source_start;
-        self.destination_start + offset
+    fn translate(&self, values: &IntervalSet<usize>) -> IntervalSet<usize> {
+        values.shift(self.source_start, self.destination_start)
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let (mut seeds, translations) = parse(input);
+pub struct Day05;
 
-    translate(&mut seeds, &translations);
+impl Solution for Day05 {
+    type Input<'a> = (Vec<usize>, Vec<Vec<Translation>>);
 
-    *seeds.iter().min().unwrap()
-}
-
-pub fn part2(input: &str) -> usize {
-    let (mut seeds, translations) = parse(input);
-    seeds = expand_seeds(&seeds);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut parts = input.split("\n\n");
+        let seeds = parse_seeds(parts.next().unwrap());
+        let translations = parts.map(parse_mapping).collect();
 
-    translate(&mut seeds, &translations);
+        Ok((seeds, translations))
+    }
 
-    *seeds.iter().min().unwrap()
-}
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (seeds, translations) = input;
+        let seeds = seeds.iter().map(|&seed| seed..seed + 1).collect();
 
-fn translate(values: &mut Vec<usize>, translations: &[Vec<Translation>]) {
-    for section in translations.iter() {
-        values
-            .par_iter_mut()
-            .for_each(|value| perform_section_translations(value, section));
+        translate(seeds, translations).min().unwrap().into()
     }
-}
 
-fn perform_section_translations(value: &mut usize, translations: &[Translation]) {
-    for translation in translations {
-        if translation.contains(value) {
-            *value = translation.translate(*value);
-            return;
-        }
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (seeds, translations) = input;
+        let seeds = seeds
+            .chunks(2)
+            .map(|chunk| chunk[0]..(chunk[0] + chunk[1]))
+            .collect();
+
+        translate(seeds, translations).min().unwrap().into()
     }
 }
 
-fn expand_seeds(seeds: &[usize]) -> Vec<usize> {
-    let mut new_seeds = Vec::new();
-    seeds
-        .chunks(2)
-        .for_each(|chunk| new_seeds.extend(chunk[0]..(chunk[0] + chunk[1])));
-
-    new_seeds
+/// Translate whole ranges of values through every section.
+fn translate(values: IntervalSet<usize>, translations: &[Vec<Translation>]) -> IntervalSet<usize> {
+    translations.iter().fold(values, |values, section| {
+        perform_section_translations(&values, section)
+    })
 }
 
-fn parse(input: &str) -> (Vec<usize>, Vec<Vec<Translation>>) {
-    let mut parts = input.split("\n\n");
-    let seeds = parse_seeds(parts.next().unwrap());
-    let translations = parts.map(parse_mapping).collect();
+fn perform_section_translations(
+    values: &IntervalSet<usize>,
+    translations: &[Translation],
+) -> IntervalSet<usize> {
+    let mut translated = IntervalSet::new();
+    let mut remaining = values.clone();
+
+    for translation in translations {
+        let source = translation.source();
+        translated = translated.union(&translation.translate(&remaining.intersection(&source)));
+        remaining = remaining.difference(&source);
+    }
 
-    (seeds, translations)
+    // values outside of every source are kept as they are
+    translated.union(&remaining)
 }
 
 fn parse_seeds(line: &str) -> Vec<usize> {
@@ -109,16 +113,18 @@ mod tests {
             range_length: 48,
         };
 
-        assert_eq!(53, translation.translate(51));
+        let translated = translation.translate(&IntervalSet::from(51..60));
+
+        assert_eq!(IntervalSet::from(53..62), translated);
     }
 
     #[test]
     fn part1_ex() {
-        assert_eq!(35, part1(EXAMPLE));
+        assert_eq!(35, Day05::part1(&Day05::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(46, part2(EXAMPLE));
+        assert_eq!(46, Day05::part2(&Day05::parse(EXAMPLE).unwrap()));
     }
 }
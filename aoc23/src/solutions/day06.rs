@@ -1,16 +1,41 @@
 use itertools::Itertools;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> usize {
-    let (times, distances) = parse(input);
+pub struct Day06;
 
-    (0..times.len())
-        .map(|i| count_ways_to_win(times[i], distances[i]))
-        .product()
-}
+impl Solution for Day06 {
+    type Input<'a> = (Vec<usize>, Vec<usize>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let (_, numbers) = line.split_once(':').unwrap();
+                numbers
+                    .split_whitespace()
+                    .map(|num| num.parse().unwrap())
+                    .collect::<Vec<usize>>()
+            })
+            .collect_tuple()
+            .unwrap())
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (times, distances) = input;
+
+        (0..times.len())
+            .map(|i| count_ways_to_win(times[i], distances[i]))
+            .product::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (times, distances) = input;
+        let (time, distance) = (fix_kerning(times), fix_kerning(distances));
 
-pub fn part2(input: &str) -> usize {
-    let (time, distance) = parse_kerning(input);
-    count_ways_to_win(time, distance)
+        count_ways_to_win(time, distance).into()
+    }
 }
 
 fn count_ways_to_win(total_time: usize, record: usize) -> usize {
@@ -25,29 +50,8 @@ fn beats_record(total_time: usize, charge_time: usize, record: usize) -> bool {
     (total_time - charge_time) * charge_time > record
 }
 
-fn parse(input: &str) -> (Vec<usize>, Vec<usize>) {
-    input
-        .lines()
-        .map(|line| {
-            let (_, numbers) = line.split_once(':').unwrap();
-            numbers
-                .split_whitespace()
-                .map(|num| num.parse().unwrap())
-                .collect::<Vec<usize>>()
-        })
-        .collect_tuple()
-        .unwrap()
-}
-
-fn parse_kerning(input: &str) -> (usize, usize) {
-    input
-        .lines()
-        .map(|line| {
-            let (_, numbers) = line.split_once(':').unwrap();
-            numbers.split_whitespace().join("").parse().unwrap()
-        })
-        .collect_tuple()
-        .unwrap()
+fn fix_kerning(numbers: &[usize]) -> usize {
+    numbers.iter().join("").parse().unwrap()
 }
 
 #[cfg(test)]
@@ -65,11 +69,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(288, part1(EXAMPLE));
+        assert_eq!(288, Day06::part1(&Day06::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(71503, part2(EXAMPLE));
+        assert_eq!(71503, Day06::part2(&Day06::parse(EXAMPLE).unwrap()));
     }
 }
@@ -1,6 +1,8 @@
 use std::cmp::Ordering;
 
 use itertools::Itertools;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(Eq)]
 struct Hand {
@@ -185,44 +187,48 @@ impl Ord for HandType {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let mut hands = parse(input);
-    hands.sort();
-    hands
-        .iter()
-        .enumerate()
-        .map(|(i, hand)| (i + 1) * hand.bet)
-        .sum()
-}
+pub struct Day07;
 
-pub fn part2(input: &str) -> usize {
-    let mut hands = parse_wildcard(input);
-    hands.sort();
-    hands
-        .iter()
-        .enumerate()
-        .map(|(i, hand)| (i + 1) * hand.bet)
-        .sum()
-}
+impl Solution for Day07 {
+    type Input<'a> = Vec<(&'a str, usize)>;
 
-fn parse(input: &str) -> Vec<Hand> {
-    input
-        .lines()
-        .map(|line| {
-            let (hand, bet) = line.split_once(' ').unwrap();
-            Hand::new(hand.to_string(), bet.parse().unwrap())
-        })
-        .collect()
-}
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let (hand, bet) = line.split_once(' ').unwrap();
+                (hand, bet.parse().unwrap())
+            })
+            .collect())
+    }
+
+    fn part1(hands: &Self::Input<'_>) -> Answer {
+        let mut hands = hands
+            .iter()
+            .map(|&(hand, bet)| Hand::new(hand.to_string(), bet))
+            .collect::<Vec<_>>();
+        hands.sort();
+        hands
+            .iter()
+            .enumerate()
+            .map(|(i, hand)| (i + 1) * hand.bet)
+            .sum::<usize>()
+            .into()
+    }
 
-fn parse_wildcard(input: &str) -> Vec<Hand> {
-    input
-        .lines()
-        .map(|line| {
-            let (hand, bet) = line.split_once(' ').unwrap();
-            Hand::new_with_wildcard(hand.to_string(), bet.parse().unwrap())
-        })
-        .collect()
+    fn part2(hands: &Self::Input<'_>) -> Answer {
+        let mut hands = hands
+            .iter()
+            .map(|&(hand, bet)| Hand::new_with_wildcard(hand.to_string(), bet))
+            .collect::<Vec<_>>();
+        hands.sort();
+        hands
+            .iter()
+            .enumerate()
+            .map(|(i, hand)| (i + 1) * hand.bet)
+            .sum::<usize>()
+            .into()
+    }
 }
 
 #[cfg(test)]
@@ -273,11 +279,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(6440, part1(EXAMPLE));
+        assert_eq!(6440, Day07::part1(&Day07::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(5905, part2(EXAMPLE));
+        assert_eq!(5905, Day07::part2(&Day07::parse(EXAMPLE).unwrap()));
     }
 }
@@ -2,39 +2,68 @@ use num::Integer;
 use regex::Regex;
 use std::collections::{HashMap, HashSet};
 use util::grid::Direction;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-struct Node {
+pub struct Node {
     left: String,
     right: String,
 }
 
-pub fn part1(input: &str) -> usize {
-    let (directions, transitions) = parse(input);
-    let goal_state = HashSet::from(["ZZZ"]);
+pub struct Day08;
 
-    steps_to_reach("AAA", &goal_state, directions, &transitions)
-}
+impl Solution for Day08 {
+    type Input<'a> = (Vec<Direction>, HashMap<String, Node>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let transition_pattern = Regex::new(r"(.{3}) = \((.{3}), (.{3})\)").unwrap();
+        let (directions, transitions) = input.split_once("\n\n").unwrap();
+        let directions = directions.chars().map(Direction::from).collect();
+
+        let nodes = transitions
+            .lines()
+            .map(|line| {
+                let captures = transition_pattern.captures(line).unwrap();
+                let key = captures.get(1).unwrap().as_str().to_string();
+                let left = captures.get(2).unwrap().as_str().to_string();
+                let right = captures.get(3).unwrap().as_str().to_string();
+
+                (key, Node { left, right })
+            })
+            .collect();
 
-pub fn part2(input: &str) -> usize {
-    let (directions, transitions) = parse(input);
-    let goal_state = transitions
-        .keys()
-        .filter(|key| key.ends_with('Z'))
-        .map(String::as_str)
-        .collect::<HashSet<&str>>();
-    
-    transitions
-        .keys()
-        .filter(|key| key.ends_with('A'))
-        .map(String::as_str)
-        .map(|start| steps_to_reach(start, &goal_state, directions.clone(), &transitions))
-        .fold(1, |x, y| x.lcm(&y))
+        Ok((directions, nodes))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (directions, transitions) = input;
+        let goal_state = HashSet::from(["ZZZ"]);
+
+        steps_to_reach("AAA", &goal_state, directions, transitions).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (directions, transitions) = input;
+        let goal_state = transitions
+            .keys()
+            .filter(|key| key.ends_with('Z'))
+            .map(String::as_str)
+            .collect::<HashSet<&str>>();
+
+        transitions
+            .keys()
+            .filter(|key| key.ends_with('A'))
+            .map(String::as_str)
+            .map(|start| steps_to_reach(start, &goal_state, directions, transitions))
+            .fold(1, |x, y| x.lcm(&y))
+            .into()
+    }
 }
 
 fn steps_to_reach(
     start: &str,
     goal_states: &HashSet<&str>,
-    directions: Vec<Direction>,
+    directions: &[Direction],
     transitions: &HashMap<String, Node>,
 ) -> usize {
     let mut steps = 0;
@@ -63,26 +92,6 @@ fn next_element<'a>(
     }
 }
 
-fn parse(input: &str) -> (Vec<Direction>, HashMap<String, Node>) {
-    let transition_pattern = Regex::new(r"(.{3}) = \((.{3}), (.{3})\)").unwrap();
-    let (directions, transitions) = input.split_once("\n\n").unwrap();
-    let directions = directions.chars().map(Direction::from).collect();
-
-    let nodes = transitions
-        .lines()
-        .map(|line| {
-            let captures = transition_pattern.captures(line).unwrap();
-            let key = captures.get(1).unwrap().as_str().to_string();
-            let left = captures.get(2).unwrap().as_str().to_string();
-            let right = captures.get(3).unwrap().as_str().to_string();
-
-            (key, Node { left, right })
-        })
-        .collect();
-
-    (directions, nodes)
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -92,11 +101,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(6, part1(EXAMPLE_1));
+        assert_eq!(6, Day08::part1(&Day08::parse(EXAMPLE_1).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(6, part2(EXAMPLE_2));
+        assert_eq!(6, Day08::part2(&Day08::parse(EXAMPLE_2).unwrap()));
     }
 }
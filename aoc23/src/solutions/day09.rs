@@ -1,13 +1,30 @@
 use itertools::Itertools;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> i32 {
-    let histories = parse(input);
-    histories.iter().map(|history| predict_next_end(history)).sum()
-}
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input<'a> = Vec<Vec<i32>>;
 
-pub fn part2(input: &str) -> i32 {
-    let histories = parse(input);
-    histories.iter().map(|history| predict_next_start(history)).sum()
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|n| n.parse().unwrap())
+                    .collect()
+            })
+            .collect())
+    }
+
+    fn part1(histories: &Self::Input<'_>) -> Answer {
+        histories.iter().map(|history| predict_next_end(history)).sum::<i32>().into()
+    }
+
+    fn part2(histories: &Self::Input<'_>) -> Answer {
+        histories.iter().map(|history| predict_next_start(history)).sum::<i32>().into()
+    }
 }
 
 fn predict_next_end(history: &[i32]) -> i32 {
@@ -32,17 +49,6 @@ fn predict_next_start(history: &[i32]) -> i32 {
     *history.first().unwrap() - predicted_diff
 }
 
-fn parse(input: &str) -> Vec<Vec<i32>> {
-    input
-        .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|n| n.parse().unwrap())
-                .collect()
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -51,26 +57,26 @@ mod tests {
 
     #[test]
     fn predict_next_end_test() {
-        assert_eq!(18, predict_next_end(&vec![0, 3, 6, 9, 12, 15]));
-        assert_eq!(28, predict_next_end(&vec![1, 3, 6, 10, 15, 21]));
-        assert_eq!(68, predict_next_end(&vec![10, 13, 16, 21, 30, 45]));
-        assert_eq!(-2, predict_next_end(&vec![0, 1, 1, 0]));
+        assert_eq!(18, predict_next_end(&[0, 3, 6, 9, 12, 15]));
+        assert_eq!(28, predict_next_end(&[1, 3, 6, 10, 15, 21]));
+        assert_eq!(68, predict_next_end(&[10, 13, 16, 21, 30, 45]));
+        assert_eq!(-2, predict_next_end(&[0, 1, 1, 0]));
     }
 
      #[test]
     fn predict_next_start_test() {
-        assert_eq!(-3, predict_next_start(&vec![0, 3, 6, 9, 12, 15]));
-        assert_eq!(0, predict_next_start(&vec![1, 3, 6, 10, 15, 21]));
-        assert_eq!(5, predict_next_start(&vec![10, 13, 16, 21, 30, 45]));
+        assert_eq!(-3, predict_next_start(&[0, 3, 6, 9, 12, 15]));
+        assert_eq!(0, predict_next_start(&[1, 3, 6, 10, 15, 21]));
+        assert_eq!(5, predict_next_start(&[10, 13, 16, 21, 30, 45]));
     }
 
     #[test]
     fn part1_ex() {
-        assert_eq!(114, part1(EXAMPLE));
+        assert_eq!(114, Day09::part1(&Day09::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(2, part2(EXAMPLE));
+        assert_eq!(2, Day09::part2(&Day09::parse(EXAMPLE).unwrap()));
     }
 }
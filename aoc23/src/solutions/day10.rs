@@ -1,22 +1,38 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::VecDeque;
 
-use util::grid::{Coordinate, Direction};
+use util::geometry;
+use util::grid::{Coordinate, Direction, Grid};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> usize {
-    let (coordinates, start) = parse(input);
-    let circle = find_circle(start, &coordinates);
+pub struct Day10;
 
-    circle.len() / 2
-}
+impl Solution for Day10 {
+    type Input<'a> = (Grid<char>, Coordinate);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let coordinates = Grid::parse_chars(input)?;
+        let start_position = coordinates.find(|&cell| cell == 'S').unwrap();
+
+        Ok((coordinates, start_position))
+    }
 
-pub fn part2(input: &str) -> usize {
-    let (coordinates, start) = parse(input);
-    let circle = find_circle(start, &coordinates);
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (coordinates, start) = input;
+        let circle = find_circle(*start, coordinates);
 
-    enclosed_area(&circle)
+        (circle.len() / 2).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (coordinates, start) = input;
+        let circle = find_circle(*start, coordinates);
+
+        geometry::interior_points(&circle).into()
+    }
 }
 
-fn find_circle(start: Coordinate, coordinates: &HashMap<Coordinate, char>) -> Vec<Coordinate> {
+fn find_circle(start: Coordinate, coordinates: &Grid<char>) -> Vec<Coordinate> {
     let mut queue = init_queue(&start, coordinates);
 
     while let Some((coordinate, direction, mut path)) = queue.pop_front() {
@@ -24,8 +40,7 @@ fn find_circle(start: Coordinate, coordinates: &HashMap<Coordinate, char>) -> Ve
             return path;
         }
 
-        let pipe = coordinates.get(&coordinate).unwrap();
-        let next_direction = next_direction(*pipe, direction);
+        let next_direction = next_direction(coordinates[coordinate], direction);
         let next_coordinate = coordinate.step(&next_direction, 1);
         path.push(next_coordinate);
 
@@ -37,7 +52,7 @@ fn find_circle(start: Coordinate, coordinates: &HashMap<Coordinate, char>) -> Ve
 
 fn init_queue(
     start: &Coordinate,
-    coordinates: &HashMap<Coordinate, char>,
+    coordinates: &Grid<char>,
 ) -> VecDeque<(Coordinate, Direction, Vec<Coordinate>)> {
     [
         Direction::Up,
@@ -48,8 +63,9 @@ fn init_queue(
     .into_iter()
     .map(|direction| (start.step(&direction, 1), direction))
     .filter(|(coordinate, direction)| {
-        coordinates.contains_key(coordinate)
-            && valid_directions(*coordinates.get(coordinate).unwrap(), direction)
+        coordinates
+            .get(coordinate)
+            .is_some_and(|&pipe| valid_directions(pipe, direction))
     })
     .map(|(coordinate, direction)| (coordinate, direction, vec![coordinate]))
     .collect()
@@ -86,58 +102,6 @@ fn valid_directions(pipe: char, direction: &Direction) -> bool {
     )
 }
 
-fn enclosed_area(circle: &[Coordinate]) -> usize {
-    let area = shoelace(circle);
-    internal_points(circle, area)
-}
-
-fn shoelace(polygon: &[Coordinate]) -> usize {
-    // Use the shoelace formula (https://en.wikipedia.org/wiki/Shoelace_formula) to calculate the area of the polygon.
-
-    let mut polygon: Vec<Coordinate> = polygon.to_vec();
-    polygon.push(polygon[0]);
-
-    let area: i32 = polygon
-        .iter()
-        .zip(polygon.iter().skip(1))
-        .map(|(p1, p2)| (p1.x * p2.y) - (p1.y * p2.x))
-        .sum();
-
-    area.unsigned_abs() as usize / 2
-}
-
-fn internal_points(polygon: &[Coordinate], area: usize) -> usize {
-    // Calculate number of inside points of the polygon defined though the coordinates with the Pick's Theorem (https://en.wikipedia.org/wiki/Pick%27s_theorem).
-
-    area - polygon.len() / 2 + 1
-}
-
-fn parse(input: &str) -> (HashMap<Coordinate, char>, Coordinate) {
-    let mut coordinates = HashMap::new();
-    for (y, row) in input.lines().enumerate() {
-        for (x, cell) in row.chars().enumerate() {
-            if cell != '.' {
-                coordinates.insert(Coordinate::from(x as i32, y as i32), cell);
-            }
-        }
-    }
-
-    let start_position = find_start(input);
-
-    (coordinates, start_position)
-}
-
-fn find_start(input: &str) -> Coordinate {
-    for (y, row) in input.lines().enumerate() {
-        for (x, cell) in row.chars().enumerate() {
-            if cell == 'S' {
-                return Coordinate::from(x as i32, y as i32);
-            }
-        }
-    }
-    unreachable!()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -150,14 +114,14 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(4, part1(EXAMPLE_1_1));
-        assert_eq!(8, part1(EXAMPLE_1_2));
+        assert_eq!(4, Day10::part1(&Day10::parse(EXAMPLE_1_1).unwrap()));
+        assert_eq!(8, Day10::part1(&Day10::parse(EXAMPLE_1_2).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(4, part2(EXAMPLE_2_1));
-        assert_eq!(8, part2(EXAMPLE_2_2));
-        assert_eq!(10, part2(EXAMPLE_2_3));
+        assert_eq!(4, Day10::part2(&Day10::parse(EXAMPLE_2_1).unwrap()));
+        assert_eq!(8, Day10::part2(&Day10::parse(EXAMPLE_2_2).unwrap()));
+        assert_eq!(10, Day10::part2(&Day10::parse(EXAMPLE_2_3).unwrap()));
     }
 }
@@ -1,12 +1,24 @@
 use itertools::Itertools;
 use util::grid::Coordinate;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> u64 {
-    sum_galaxy_distances(input, 2)
-}
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input<'a> = &'a str;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
 
-pub fn part2(input: &str) -> u64 {
-    sum_galaxy_distances(input, 1_000_000)
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        sum_galaxy_distances(input, 2).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        sum_galaxy_distances(input, 1_000_000).into()
+    }
 }
 
 fn sum_galaxy_distances(input: &str, distance_multiplier: i32) -> u64 {
@@ -65,7 +77,7 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(374, part1(EXAMPLE));
+        assert_eq!(374, Day11::part1(&Day11::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
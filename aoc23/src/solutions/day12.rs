@@ -1,12 +1,31 @@
 use itertools::Itertools;
 use memoize::memoize;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> u64 {
-    solve(parse(input))
-}
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input<'a> = Vec<(String, Vec<usize>)>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| {
+                let (spring, rules) = line.split_once(' ').unwrap();
+                let rules = rules.split(',').map(|n| n.parse().unwrap()).collect();
+                (spring.to_string(), rules)
+            })
+            .collect())
+    }
+
+    fn part1(conditional_records: &Self::Input<'_>) -> Answer {
+        solve(conditional_records.clone()).into()
+    }
 
-pub fn part2(input: &str) -> u64 {
-    solve(parse_and_expand(input))
+    fn part2(conditional_records: &Self::Input<'_>) -> Answer {
+        solve(expand(conditional_records)).into()
+    }
 }
 
 fn solve(conditional_records: Vec<(String, Vec<usize>)>) -> u64 {
@@ -61,27 +80,12 @@ fn count_recursive(springs: Vec<char>, damaged_groups: Vec<usize>) -> u64 {
     }
 }
 
-fn parse(input: &str) -> Vec<(String, Vec<usize>)> {
-    input
-        .lines()
-        .map(|line| {
-            let (spring, rules) = line.split_once(' ').unwrap();
-            let rules = rules.split(',').map(|n| n.parse().unwrap()).collect();
-            (spring.to_string(), rules)
-        })
-        .collect()
-}
-
-fn parse_and_expand(input: &str) -> Vec<(String, Vec<usize>)> {
-    input
-        .lines()
-        .map(|line| {
-            let (spring, rules) = line.split_once(' ').unwrap();
+fn expand(conditional_records: &[(String, Vec<usize>)]) -> Vec<(String, Vec<usize>)> {
+    conditional_records
+        .iter()
+        .map(|(spring, rules)| {
             let spring = std::iter::once(spring).cycle().take(5).join("?");
-
-            let rules: Vec<usize> = rules.split(',').map(|n| n.parse().unwrap()).collect();
-            let n = rules.len();
-            let rules = rules.into_iter().cycle().take(5 * n).collect();
+            let rules = rules.repeat(5);
 
             (spring, rules)
         })
@@ -106,11 +110,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(21, part1(EXAMPLE));
+        assert_eq!(21, Day12::part1(&Day12::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(525152, part2(EXAMPLE));
+        assert_eq!(525152, Day12::part2(&Day12::parse(EXAMPLE).unwrap()));
     }
 }
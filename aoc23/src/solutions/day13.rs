@@ -1,4 +1,7 @@
-struct Pattern {
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
+
+pub struct Pattern {
     vertical_lines: Vec<String>,
     horizontal_lines: Vec<String>,
 }
@@ -8,12 +11,44 @@ enum Reflection {
     Horizontal(usize),
 }
 
-pub fn part1(input: &str) -> usize {
-    solve(&parse(input), 0)
-}
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input<'a> = Vec<Pattern>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .split("\n\n")
+            .map(|chunk| {
+                let horizontal_lines = chunk.lines().map(|line| line.to_string()).collect();
+
+                let width = chunk.lines().peekable().peek().unwrap().len();
+                let vertical_lines = (0..width)
+                    .map(|x| {
+                        chunk
+                            .lines()
+                            .flat_map(|line| line.chars())
+                            .skip(x)
+                            .step_by(width)
+                            .collect::<String>()
+                    })
+                    .collect();
+
+                Pattern {
+                    vertical_lines,
+                    horizontal_lines,
+                }
+            })
+            .collect())
+    }
+
+    fn part1(patterns: &Self::Input<'_>) -> Answer {
+        solve(patterns, 0).into()
+    }
 
-pub fn part2(input: &str) -> usize {
-    solve(&parse(input), 1)
+    fn part2(patterns: &Self::Input<'_>) -> Answer {
+        solve(patterns, 1).into()
+    }
 }
 
 fn solve(patterns: &[Pattern], tolerance: usize) -> usize {
@@ -65,32 +100,6 @@ fn is_symetrical(lines: &[String], mut left: usize, mut right: usize, tolerance:
     tolerance == difference_count
 }
 
-fn parse(input: &str) -> Vec<Pattern> {
-    input
-        .split("\n\n")
-        .map(|chunk| {
-            let horizontal_lines = chunk.lines().map(|line| line.to_string()).collect();
-
-            let width = chunk.lines().peekable().peek().unwrap().len();
-            let vertical_lines = (0..width)
-                .map(|x| {
-                    chunk
-                        .lines()
-                        .flat_map(|line| line.chars())
-                        .skip(x)
-                        .step_by(width)
-                        .collect::<String>()
-                })
-                .collect();
-
-            Pattern {
-                vertical_lines,
-                horizontal_lines,
-            }
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,11 +108,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(405, part1(EXAMPLE));
+        assert_eq!(405, Day13::part1(&Day13::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(400, part2(EXAMPLE));
+        assert_eq!(400, Day13::part2(&Day13::parse(EXAMPLE).unwrap()));
     }
 }
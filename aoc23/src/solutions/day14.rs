@@ -1,12 +1,15 @@
-use std::collections::{HashMap, HashSet};
+use std::collections::HashSet;
 
 use itertools::Itertools;
+use util::cycle;
 use util::grid::{Bounds, Coordinate, Direction};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 const LIMIT: usize = 1_000_000_000;
 
 #[derive(Clone)]
-struct Board {
+pub struct Board {
     bounds: Bounds,
     stones: HashSet<Coordinate>,
     walls: HashSet<Coordinate>,
@@ -85,64 +88,62 @@ impl Board {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let mut board = parse(input);
-    board.tilt(&Direction::Up);
-    board.calculate_load()
-}
-
-pub fn part2(input: &str) -> usize {
-    let mut board = parse(input);
+pub struct Day14;
 
-    let mut board_memory = HashMap::new();
-    let mut value_memory = Vec::new();
-    for i in 0..LIMIT {
-        let board_state = board.get_board_representation();
+impl Solution for Day14 {
+    type Input<'a> = Board;
 
-        value_memory.push(board.calculate_load());
-        if let Some(start) = board_memory.insert(board_state, i) {
-            let period = i - start;
-            let final_index = start + (LIMIT - start) % period;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let mut stones = HashSet::new();
+        let mut walls = HashSet::new();
 
-            return value_memory[final_index];
+        for (y, row) in input.lines().enumerate() {
+            for (x, ch) in row.chars().enumerate() {
+                if ch == 'O' {
+                    stones.insert(Coordinate::from(x as i32, y as i32));
+                } else if ch == '#' {
+                    walls.insert(Coordinate::from(x as i32, y as i32));
+                }
+            }
         }
 
-        [
-            Direction::Up,
-            Direction::Left,
-            Direction::Down,
-            Direction::Right,
-        ]
-        .iter()
-        .for_each(|direction| board.tilt(direction));
-    }
+        let bounds = Bounds::from(
+            0..input.lines().next().unwrap().len() as i32,
+            0..input.lines().count() as i32,
+        );
 
-    board.calculate_load()
-}
+        Ok(Board {
+            bounds,
+            stones,
+            walls,
+        })
+    }
 
-fn parse(input: &str) -> Board {
-    let mut stones = HashSet::new();
-    let mut walls = HashSet::new();
+    fn part1(board: &Self::Input<'_>) -> Answer {
+        let mut board = board.clone();
+        board.tilt(&Direction::Up);
 
-    for (y, row) in input.lines().enumerate() {
-        for (x, ch) in row.chars().enumerate() {
-            if ch == 'O' {
-                stones.insert(Coordinate::from(x as i32, y as i32));
-            } else if ch == '#' {
-                walls.insert(Coordinate::from(x as i32, y as i32));
-            }
-        }
+        board.calculate_load().into()
     }
 
-    let bounds = Bounds::from(
-        0..input.lines().next().unwrap().len() as i32,
-        0..input.lines().count() as i32,
-    );
-
-    Board {
-        bounds,
-        stones,
-        walls,
+    fn part2(board: &Self::Input<'_>) -> Answer {
+        let (cycle, loads) = cycle::find_cycle(
+            board.clone(),
+            |board| {
+                [
+                    Direction::Up,
+                    Direction::Left,
+                    Direction::Down,
+                    Direction::Right,
+                ]
+                .iter()
+                .for_each(|direction| board.tilt(direction))
+            },
+            Board::get_board_representation,
+            Board::calculate_load,
+        );
+
+        (*cycle.value_at(&loads, LIMIT)).into()
     }
 }
 
@@ -154,11 +155,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(136, part1(EXAMPLE));
+        assert_eq!(136, Day14::part1(&Day14::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(64, part2(EXAMPLE));
+        assert_eq!(64, Day14::part2(&Day14::parse(EXAMPLE).unwrap()));
     }
 }
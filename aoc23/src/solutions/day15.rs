@@ -1,6 +1,8 @@
 use lazy_static::lazy_static;
 use regex::Regex;
 use std::collections::VecDeque;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 lazy_static! {
     static ref STEP_REGEX: Regex = Regex::new(r"^(\w*)([-=])(\w?)$").unwrap();
@@ -14,34 +16,44 @@ enum Operation {
     Remove,
 }
 
-pub fn part1(input: &str) -> usize {
-    input.split(',').map(calculate_hash).sum()
-}
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input<'a> = &'a str;
 
-pub fn part2(input: &str) -> usize {
-    let mut lense_boxes: Vec<VecDeque<Lense>> = (0..LIMIT).map(|_| VecDeque::new()).collect();
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        input.split(',').map(calculate_hash).sum::<usize>().into()
+    }
 
-    for step in input.split(',') {
-        let (label, operation, focal_length) = parse_step(step);
-        let lense_box = &mut lense_boxes[calculate_hash(&label)];
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let mut lense_boxes: Vec<VecDeque<Lense>> = (0..LIMIT).map(|_| VecDeque::new()).collect();
 
-        match operation {
-            Operation::Write => {
-                if let Some(index) = lense_box.iter().position(|lense| lense.0 == label) {
-                    lense_box[index] = (label, focal_length);
-                } else {
-                    lense_box.push_back((label, focal_length));
+        for step in input.split(',') {
+            let (label, operation, focal_length) = parse_step(step);
+            let lense_box = &mut lense_boxes[calculate_hash(&label)];
+
+            match operation {
+                Operation::Write => {
+                    if let Some(index) = lense_box.iter().position(|lense| lense.0 == label) {
+                        lense_box[index] = (label, focal_length);
+                    } else {
+                        lense_box.push_back((label, focal_length));
+                    }
                 }
-            }
-            Operation::Remove => {
-                if let Some(index) = lense_box.iter().position(|lense| lense.0 == label) {
-                    lense_box.remove(index);
+                Operation::Remove => {
+                    if let Some(index) = lense_box.iter().position(|lense| lense.0 == label) {
+                        lense_box.remove(index);
+                    }
                 }
             }
         }
-    }
 
-    calculate_focusing_power(&lense_boxes)
+        calculate_focusing_power(&lense_boxes).into()
+    }
 }
 
 fn calculate_hash(to_hash: &str) -> usize {
@@ -110,11 +122,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(1320, part1(EXAMPLE));
+        assert_eq!(1320, Day15::part1(&Day15::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(145, part2(EXAMPLE));
+        assert_eq!(145, Day15::part2(&Day15::parse(EXAMPLE).unwrap()));
     }
 }
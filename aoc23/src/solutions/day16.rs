@@ -1,58 +1,82 @@
 use rayon::iter::{ParallelBridge, ParallelIterator};
-use std::collections::{HashMap, HashSet, VecDeque};
-use util::grid::{Coordinate, Direction};
+use std::collections::VecDeque;
+use util::grid::{Coordinate, Direction, Grid};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
-pub fn part1(input: &str) -> usize {
-    let mirrors = parse(input);
-    let energy_levels = simulate_light(&mirrors, Coordinate::from(0, 0), Direction::Right);
+pub struct Day16;
 
-    energy_levels.len()
-}
-
-pub fn part2(input: &str) -> usize {
-    let x_max = input.lines().next().unwrap().len();
-    let y_max = input.lines().count();
+impl Solution for Day16 {
+    type Input<'a> = Grid<char>;
 
-    let mirrors = parse(input);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse_chars(input)
+    }
 
-    let top_and_bottom = (0..x_max).flat_map(|x| [(x, 0), (x, y_max)]);
-    let left_and_right = (0..y_max).flat_map(|y| [(0, y), (x_max, y)]);
+    fn part1(mirrors: &Self::Input<'_>) -> Answer {
+        simulate_light(mirrors, Coordinate::from(0, 0), Direction::Right).into()
+    }
 
-    top_and_bottom
-        .chain(left_and_right)
-        .flat_map(|xy| Direction::iter().map(move |direction| (xy, direction)))
-        .map(|((x, y), direction)| (Coordinate::from(x as i32, y as i32), direction))
-        .par_bridge()
-        .map(|(coordinate, direction)| simulate_light(&mirrors, coordinate, direction))
-        .map(|energy_levels| energy_levels.len())
-        .max()
-        .unwrap()
+    fn part2(mirrors: &Self::Input<'_>) -> Answer {
+        let x_max = mirrors.width() as i32 - 1;
+        let y_max = mirrors.height() as i32 - 1;
+
+        let top_and_bottom = (0..=x_max).flat_map(|x| {
+            [
+                (Coordinate::from(x, 0), Direction::Down),
+                (Coordinate::from(x, y_max), Direction::Up),
+            ]
+        });
+        let left_and_right = (0..=y_max).flat_map(|y| {
+            [
+                (Coordinate::from(0, y), Direction::Right),
+                (Coordinate::from(x_max, y), Direction::Left),
+            ]
+        });
+
+        top_and_bottom
+            .chain(left_and_right)
+            .par_bridge()
+            .map(|(coordinate, direction)| simulate_light(mirrors, coordinate, direction))
+            .max()
+            .unwrap()
+            .into()
+    }
 }
 
+/// Follow the beam and count the energized tiles.
 fn simulate_light(
-    mirrors: &HashMap<Coordinate, char>,
+    mirrors: &Grid<char>,
     start_coodinate: Coordinate,
     start_direction: Direction,
-) -> HashSet<Coordinate> {
-    let mut visited = HashSet::new();
+) -> usize {
+    // one bit per direction a beam passed a tile in
+    let mut visited = Grid::new(mirrors.width(), mirrors.height(), 0_u8);
     let mut queue = VecDeque::from([(start_coodinate, start_direction)]);
+    let bounds = mirrors.bounds();
 
     while let Some((coodinate, direction)) = queue.pop_front() {
-        if !visited.insert((coodinate, direction)) {
+        let bit = 1 << direction as u8;
+        if visited[coodinate] & bit != 0 {
             continue;
         }
-
-        if let Some(tile) = mirrors.get(&coodinate) {
-            queue.extend(
-                change_direction(&direction, *tile)
-                    .iter()
-                    .map(|next_direction| (coodinate.step(next_direction, 1), *next_direction))
-                    .filter(|(next_coodinate, _)| mirrors.contains_key(next_coodinate)),
-            );
-        }
+        visited[coodinate] |= bit;
+
+        queue.extend(
+            change_direction(&direction, mirrors[coodinate])
+                .iter()
+                .filter_map(|next_direction| {
+                    coodinate
+                        .step_in_bounds(next_direction, 1, &bounds)
+                        .map(|next_coodinate| (next_coodinate, *next_direction))
+                }),
+        );
     }
 
-    visited.iter().map(|(coordinate, _)| *coordinate).collect()
+    visited
+        .values()
+        .filter(|&&directions| directions != 0)
+        .count()
 }
 
 fn change_direction(direction: &Direction, tile: char) -> Vec<Direction> {
@@ -67,17 +91,6 @@ fn change_direction(direction: &Direction, tile: char) -> Vec<Direction> {
     }
 }
 
-fn parse(input: &str) -> HashMap<Coordinate, char> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, line)| {
-            line.char_indices()
-                .map(move |(x, ch)| (Coordinate::from(x as i32, y as i32), ch))
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -86,11 +99,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(46, part1(EXAMPLE));
+        assert_eq!(46, Day16::part1(&Day16::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(51, part2(EXAMPLE));
+        assert_eq!(51, Day16::part2(&Day16::parse(EXAMPLE).unwrap()));
     }
 }
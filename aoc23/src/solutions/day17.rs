@@ -1,33 +1,7 @@
-use std::collections::{BinaryHeap, HashMap, HashSet};
-use util::grid::{Coordinate, Direction};
-
-#[derive(Eq, PartialEq)]
-struct CrucibleState {
-    heat_loss: u32,
-    crucible: Crucible,
-}
-
-impl CrucibleState {
-    fn from(heat_loss: u32, crucible: Crucible) -> Self {
-        Self {
-            heat_loss,
-            crucible,
-        }
-    }
-}
-
-// implement ordering, because binary heap is a max-heap by default
-impl Ord for CrucibleState {
-    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        other.heat_loss.cmp(&self.heat_loss)
-    }
-}
-
-impl PartialOrd for CrucibleState {
-    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(self.cmp(other))
-    }
-}
+use util::grid::{Coordinate, Direction, Grid};
+use util::parse::ParseError;
+use util::search;
+use util::solution::{Answer, Solution};
 
 #[derive(Eq, PartialEq, Clone, Hash)]
 struct Crucible {
@@ -51,13 +25,13 @@ impl Crucible {
 
     fn get_next_states(
         &self,
-        blocks: &HashMap<Coordinate, u32>,
+        blocks: &Grid<u32>,
         straight_min: u8,
         straight_max: u8,
     ) -> Vec<Crucible> {
         if self.straight_count < straight_min {
             return Some(self.position.step(&self.direction, 1))
-                .filter(|next_position| blocks.contains_key(next_position))
+                .filter(|next_position| blocks.contains(next_position))
                 .map(|next_position| {
                     Crucible::from(next_position, self.direction, self.straight_count + 1)
                 })
@@ -79,7 +53,7 @@ impl Crucible {
                 next_straight_count,
             )
         })
-        .filter(|(next_position, _, _)| blocks.contains_key(next_position))
+        .filter(|(next_position, _, _)| blocks.contains(next_position))
         .map(|(next_position, next_direction, next_straight_count)| {
             Crucible::from(next_position, *next_direction, *next_straight_count)
         })
@@ -91,77 +65,54 @@ impl Crucible {
     }
 }
 
-pub fn part1(input: &str) -> u32 {
-    let blocks = parse(input);
-    let goal = find_goal(&blocks);
-    lowest_heat_loss(&blocks, goal, 1, 3)
-}
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input<'a> = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Grid::parse_digits(input)
+    }
 
-pub fn part2(input: &str) -> u32 {
-    let blocks = parse(input);
-    let goal = find_goal(&blocks);
-    lowest_heat_loss(&blocks, goal, 4, 10)
+    fn part1(blocks: &Self::Input<'_>) -> Answer {
+        let goal = find_goal(blocks);
+        lowest_heat_loss(blocks, &goal, 1, 3).into()
+    }
+
+    fn part2(blocks: &Self::Input<'_>) -> Answer {
+        let goal = find_goal(blocks);
+        lowest_heat_loss(blocks, &goal, 4, 10).into()
+    }
 }
 
-fn find_goal(blocks: &HashMap<Coordinate, u32>) -> &Coordinate {
-    blocks
-        .keys()
-        .max_by(|c1, c2| (c1.x, c1.y).cmp(&(c2.x, c2.y)))
-        .unwrap()
+fn find_goal(blocks: &Grid<u32>) -> Coordinate {
+    Coordinate::from(blocks.width() as i32 - 1, blocks.height() as i32 - 1)
 }
 
 fn lowest_heat_loss(
-    blocks: &HashMap<Coordinate, u32>,
+    blocks: &Grid<u32>,
     goal: &Coordinate,
     straight_min: u8,
     straight_max: u8,
 ) -> u32 {
-    let mut heap = BinaryHeap::from([
-        CrucibleState::from(0, Crucible::new(Direction::Right)),
-        CrucibleState::from(0, Crucible::new(Direction::Down)),
-    ]);
-    let mut visited = HashSet::new();
-
-    while let Some(CrucibleState {
-        heat_loss,
-        crucible,
-    }) = heap.pop()
-    {
-        if crucible.has_reached_goal(goal, straight_min) {
-            return heat_loss;
-        }
-
-        if !visited.insert(crucible.clone()) {
-            continue;
-        }
-
-        crucible
-            .get_next_states(blocks, straight_min, straight_max)
-            .into_iter()
-            .filter(|next_crucible| blocks.contains_key(&next_crucible.position))
-            .map(|next_crucible| {
-                let next_heat_loss = heat_loss + blocks.get(&next_crucible.position).unwrap();
-                CrucibleState::from(next_heat_loss, next_crucible)
-            })
-            .for_each(|state| heap.push(state));
-    }
-
-    unreachable!()
-}
-
-fn parse(input: &str) -> HashMap<Coordinate, u32> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, row)| {
-            row.char_indices().map(move |(x, ch)| {
-                (
-                    Coordinate::from(x as i32, y as i32),
-                    ch.to_digit(10).unwrap(),
-                )
-            })
-        })
-        .collect()
+    search::dijkstra(
+        [
+            Crucible::new(Direction::Right),
+            Crucible::new(Direction::Down),
+        ],
+        |crucible| {
+            crucible
+                .get_next_states(blocks, straight_min, straight_max)
+                .into_iter()
+                .map(|next_crucible| {
+                    let heat_loss = blocks[next_crucible.position];
+                    (next_crucible, heat_loss)
+                })
+        },
+        |crucible| crucible.has_reached_goal(goal, straight_min),
+    )
+    .unwrap()
+    .cost
 }
 
 #[cfg(test)]
@@ -174,7 +125,7 @@ mod tests {
     #[test]
     fn get_next_state_should_return_straight_and_turning() {
         let crucible = Crucible::new(Direction::Right);
-        let blocks = HashMap::from([(Coordinate::from(1, 0), 3), (Coordinate::from(0, 1), 5)]);
+        let blocks = Grid::from_vec(2, vec![0, 3, 5, 0]);
 
         let next_crucibles = crucible.get_next_states(&blocks, 0, 3);
 
@@ -194,7 +145,7 @@ mod tests {
     #[test]
     fn get_next_state_should_only_return_straight_when_not_going_straight_far_enough() {
         let crucible = Crucible::new(Direction::Right);
-        let blocks = HashMap::from([(Coordinate::from(1, 0), 3)]);
+        let blocks = Grid::from_vec(2, vec![0, 3]);
 
         let next_crucibles = crucible.get_next_states(&blocks, 1, 3);
 
@@ -209,11 +160,7 @@ mod tests {
     #[test]
     fn get_next_state_should_only_return_turning_going_straight_too_far() {
         let crucible = Crucible::from(Coordinate::from(1, 1), Direction::Right, 3);
-        let blocks = HashMap::from([
-            (Coordinate::from(2, 1), 3),
-            (Coordinate::from(1, 0), 3),
-            (Coordinate::from(1, 2), 3),
-        ]);
+        let blocks = Grid::new(3, 3, 3);
         let next_crucibles = crucible.get_next_states(&blocks, 0, 3);
 
         assert_eq!(2, next_crucibles.len());
@@ -227,12 +174,12 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(102, part1(EXAMPLE_1));
+        assert_eq!(102, Day17::part1(&Day17::parse(EXAMPLE_1).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(94, part2(EXAMPLE_1));
-        assert_eq!(71, part2(EXAMPLE_2));
+        assert_eq!(94, Day17::part2(&Day17::parse(EXAMPLE_1).unwrap()));
+        assert_eq!(71, Day17::part2(&Day17::parse(EXAMPLE_2).unwrap()));
     }
 }
@@ -1,91 +1,53 @@
 use itertools::Itertools;
 use lazy_static::lazy_static;
 use regex::Regex;
-use util::grid::Direction;
+use util::geometry;
+use util::grid::{Coordinate, Direction};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 lazy_static! {
     static ref DIG_INSTRUCTION_REGEX: Regex =
         Regex::new(r"^([RULD]) (\d+) \(#([0-9a-f]{6})\)$").unwrap();
 }
 
-#[derive(Debug, Default, Clone, Copy)]
-struct Coordinate {
-    pub x: i64,
-    pub y: i64,
-}
-
-impl Coordinate {
-    pub fn from(x: i64, y: i64) -> Self {
-        Coordinate { x, y }
-    }
-
-    fn step(&self, direction: &Direction, steps: u32) -> Self {
-        let steps = steps as i64;
-        match direction {
-            Direction::Right => Coordinate::from(self.x + steps, self.y),
-            Direction::Left => Coordinate::from(self.x - steps, self.y),
-            Direction::Up => Coordinate::from(self.x, self.y - steps),
-            Direction::Down => Coordinate::from(self.x, self.y + steps),
-        }
-    }
-}
-
-struct Instruction(Direction, u32);
-
-pub fn part1(input: &str) -> usize {
-    let instructions = parse(input, parse_instruction);
-    let (edges, boundary_count) = dig_pit(&instructions);
-    total_area(&edges, boundary_count)
-}
-
-pub fn part2(input: &str) -> usize {
-    let instructions = parse(input, parse_extracted_instruction);
-    let (edges, boundary_count) = dig_pit(&instructions);
-    total_area(&edges, boundary_count)
-}
+#[derive(Clone, Copy)]
+pub struct Instruction(Direction, u32);
 
-fn dig_pit(instructions: &[Instruction]) -> (Vec<Coordinate>, usize) {
-    let mut edges = Vec::with_capacity(instructions.len());
-    let mut boundary_count = 0;
-    let mut current = Coordinate::default();
+pub struct Day18;
 
-    for Instruction(direction, steps) in instructions {
-        current = current.step(direction, *steps);
-        edges.push(current);
+impl Solution for Day18 {
+    /// The instruction as written and the one extracted from its hex code.
+    type Input<'a> = Vec<(Instruction, Instruction)>;
 
-        boundary_count += steps;
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .map(|line| (parse_instruction(line), parse_extracted_instruction(line)))
+            .collect())
     }
 
-    (edges, boundary_count as usize)
-}
-
-fn total_area(pit: &[Coordinate], boundary_count: usize) -> usize {
-    let area = calculate_area(pit);
-    boundary_count + interior_count(area, boundary_count)
-}
-
-fn calculate_area(polygon: &[Coordinate]) -> usize {
-    // Calculate the area of the polygon with the Shoelace formula (https://en.wikipedia.org/wiki/Shoelace_formula).
-
-    let first_edge = [polygon[0]];
-    let polygon = polygon.iter().chain(first_edge.iter()).collect_vec();
-
-    let area: i64 = polygon
-        .iter()
-        .zip(polygon.iter().skip(1))
-        .map(|(p1, p2)| (p1.x * p2.y) - (p1.y * p2.x))
-        .sum();
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let instructions = input.iter().map(|&(instruction, _)| instruction).collect_vec();
+        dug_out_volume(&instructions).into()
+    }
 
-    area.unsigned_abs() as usize / 2
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let instructions = input.iter().map(|&(_, instruction)| instruction).collect_vec();
+        dug_out_volume(&instructions).into()
+    }
 }
 
-fn interior_count(area: usize, boundary_count: usize) -> usize {
-    // Calculate the number of points inside the polygon with the Pick's Theorem (https://en.wikipedia.org/wiki/Pick%27s_theorem)
-    area - boundary_count / 2 + 1
-}
+fn dug_out_volume(instructions: &[Instruction]) -> i64 {
+    // the trench is dug around the cubes it passes, so they count as well
+    let pit = geometry::trace_polygon(
+        Coordinate::<i64>::default(),
+        instructions
+            .iter()
+            .map(|&Instruction(direction, steps)| (direction, steps)),
+    );
 
-fn parse(input: &str, parse_instruction_function: fn(&str) -> Instruction) -> Vec<Instruction> {
-    input.lines().map(parse_instruction_function).collect()
+    geometry::enclosed_points(&pit)
 }
 
 fn parse_instruction(line: &str) -> Instruction {
@@ -122,11 +84,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(62, part1(EXAMPLE));
+        assert_eq!(62, Day18::part1(&Day18::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(952408144115, part2(EXAMPLE));
+        assert_eq!(952408144115, Day18::part2(&Day18::parse(EXAMPLE).unwrap()));
     }
 }
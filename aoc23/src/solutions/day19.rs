@@ -1,12 +1,14 @@
 use lazy_static::lazy_static;
 use regex::{Match, Regex};
 use std::{collections::HashMap, ops::Range};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 lazy_static! {
     static ref OPERATION_REGEX: Regex = Regex::new(r"^([xmas])[<>](\d+):(.*)$").unwrap();
 }
 
-enum Rule {
+pub enum Rule {
     GT(char, usize, Operation),
     LT(char, usize, Operation),
     Unconditional(Operation),
@@ -35,13 +37,13 @@ impl Rule {
 }
 
 #[derive(Clone)]
-enum Operation {
+pub enum Operation {
     Accept,
     Reject,
     Call(String),
 }
 
-struct Part {
+pub struct Part {
     x: usize,
     m: usize,
     a: usize,
@@ -116,23 +118,39 @@ impl PartRanges {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let (workflows, parts) = parse(input);
+pub struct Day19;
 
-    parts
-        .iter()
-        .filter(|part| is_part_accepted(part, &workflows, "in"))
-        .map(|part| part.sum_ratings())
-        .sum()
-}
+impl Solution for Day19 {
+    type Input<'a> = (HashMap<String, Vec<Rule>>, Vec<Part>);
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        let (workflow_part, rating_part) = input.split_once("\n\n").unwrap();
+
+        let workflows = parse_workflows(workflow_part);
+        let ratings = parse_parts(rating_part);
+
+        Ok((workflows, ratings))
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let (workflows, parts) = input;
 
-pub fn part2(input: &str) -> usize {
-    let (workflows, _) = parse(input);
+        parts
+            .iter()
+            .filter(|part| is_part_accepted(part, workflows, "in"))
+            .map(|part| part.sum_ratings())
+            .sum::<usize>()
+            .into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        let (workflows, _) = input;
 
-    let mut accepted = Vec::new();
-    get_valid_ranges(PartRanges::default(), &workflows, "in", &mut accepted);
+        let mut accepted = Vec::new();
+        get_valid_ranges(PartRanges::default(), workflows, "in", &mut accepted);
 
-    accepted.iter().map(PartRanges::score).sum()
+        accepted.iter().map(PartRanges::score).sum::<usize>().into()
+    }
 }
 
 fn get_valid_ranges(
@@ -191,15 +209,6 @@ fn is_part_accepted(part: &Part, workflows: &HashMap<String, Vec<Rule>>, workflo
     }
 }
 
-fn parse(input: &str) -> (HashMap<String, Vec<Rule>>, Vec<Part>) {
-    let (workflow_part, rating_part) = input.split_once("\n\n").unwrap();
-
-    let workflows = parse_workflows(workflow_part);
-    let ratings = parse_parts(rating_part);
-
-    (workflows, ratings)
-}
-
 fn parse_workflows(workflow_part: &str) -> HashMap<String, Vec<Rule>> {
     let workflow_regex = Regex::new(r"(.+)\{(.*)\}").unwrap();
     workflow_part
@@ -281,11 +290,14 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(19114, part1(EXAMPLE));
+        assert_eq!(19114, Day19::part1(&Day19::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(167_409_079_868_000, part2(EXAMPLE));
+        assert_eq!(
+            167_409_079_868_000,
+            Day19::part2(&Day19::parse(EXAMPLE).unwrap())
+        );
     }
 }
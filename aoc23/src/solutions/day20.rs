@@ -1,6 +1,8 @@
 use std::collections::{HashMap, VecDeque};
 
 use num::Integer;
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(PartialEq, Eq, Clone, Copy)]
 enum Signal {
@@ -61,28 +63,84 @@ impl ReceiveSignal for Conjunction {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let mut modules = parse(input);
+pub struct Day20;
 
-    let (high_count, low_count) = (0..1_000)
-        .map(|_| simulate(&mut modules))
-        .fold((0, 0), |acc, x| (acc.0 + x.0, acc.1 + x.1));
+impl Solution for Day20 {
+    // Modules keep their state in trait objects, so every simulation builds them anew.
+    type Input<'a> = &'a str;
 
-    high_count * low_count
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input<'_>) -> Answer {
+        let mut modules = build_modules(input);
+
+        let (high_count, low_count) = (0..1_000)
+            .map(|_| simulate(&mut modules))
+            .fold((0, 0), |acc, x| (acc.0 + x.0, acc.1 + x.1));
+        (high_count * low_count).into()
+    }
+
+    fn part2(input: &Self::Input<'_>) -> Answer {
+        // Solution specifically tailored for my input.
+        // Identified the "parents" of the rx node and find how many iterations are needed until they are all High
+        // Calculate LCM for the resulting values to find the first time when all of them are true
+        ["xm", "dr", "nh", "tr"]
+            .iter()
+            .map(|target| {
+                let mut modules = build_modules(input);
+
+                simulate_until_high(&mut modules, target)
+            })
+            .fold(1, |x, y| x.lcm(&y))
+            .into()
+    }
 }
 
-pub fn part2(input: &str) -> usize {
-    // Solution specifically tailored for my input. 
-    // Identified the "parents" of the rx node and find how many iterations are needed until they are all High
-    // Calculate LCM for the resulting values to find the first time when all of them are true
-    ["xm", "dr", "nh", "tr"]
-        .iter()
-        .map(|target| {
-            let mut modules = parse(input);
-
-            simulate_until_high(&mut modules, target)
-        })
-        .fold(1, |x, y| x.lcm(&y))
+fn build_modules(input: &str) -> HashMap<String, Module> {
+    let mut module_map: HashMap<String, Module> = HashMap::new();
+
+    let mut outputs_of: HashMap<String, Vec<String>> = HashMap::new();
+
+    // build map which module is output of which modules. This is important for Conjunction modules
+    for line in input.lines() {
+        let (name, outputs) = line.split_once(" -> ").unwrap();
+
+        for output in outputs.split(", ") {
+            outputs_of
+                .entry(output.to_string())
+                .or_default()
+                .push(process_name(name));
+        }
+    }
+
+    // build map of modules
+    for line in input.lines() {
+        let (name, outputs) = line.split_once(" -> ").unwrap();
+        let outputs: Vec<String> = outputs.split(", ").map(|out| out.to_string()).collect();
+
+        let (name, behavior): (String, Box<dyn ReceiveSignal>) = if name.starts_with('%') {
+            (process_name(name), Box::<FlipFlop>::default())
+        } else if name.starts_with('&') {
+            let name = process_name(name);
+
+            let memory = outputs_of
+                .get(&name)
+                .unwrap()
+                .iter()
+                .map(|output| (output.to_string(), Signal::Low))
+                .collect();
+
+            (name, Box::new(Conjunction { memory }))
+        } else {
+            (name.to_string(), Box::new(Broadcast))
+        };
+
+        module_map.insert(name, Module { outputs, behavior });
+    }
+
+    module_map
 }
 
 fn simulate(modules: &mut HashMap<String, Module>) -> (usize, usize) {
@@ -102,7 +160,7 @@ fn simulate(modules: &mut HashMap<String, Module>) -> (usize, usize) {
         }
 
         if let Some(values) = evaluate_signal(modules, source, destination, signal) {
-            sent_signals.extend(values.into_iter());
+            sent_signals.extend(values);
         }
     }
 
@@ -142,57 +200,12 @@ fn simulate_until_high(modules: &mut HashMap<String, Module>, target: &str) -> u
                 return presses;
             }
             if let Some(values) = evaluate_signal(modules, source, destination, signal) {
-                sent_signals.extend(values.into_iter());
+                sent_signals.extend(values);
             }
         }
     }
 }
 
-fn parse(input: &str) -> HashMap<String, Module> {
-    let mut module_map: HashMap<String, Module> = HashMap::new();
-
-    let mut outputs_of: HashMap<String, Vec<String>> = HashMap::new();
-
-    // build map which module is output of which modules. This is important for Conjunction modules
-    for line in input.lines() {
-        let (name, outputs) = line.split_once(" -> ").unwrap();
-
-        for output in outputs.split(", ") {
-            outputs_of
-                .entry(output.to_string())
-                .or_default()
-                .push(process_name(name));
-        }
-    }
-
-    // build map of modules
-    for line in input.lines() {
-        let (name, outputs) = line.split_once(" -> ").unwrap();
-        let outputs: Vec<String> = outputs.split(", ").map(|out| out.to_string()).collect();
-
-        let (name, behavior): (String, Box<dyn ReceiveSignal>) = if name.starts_with('%') {
-            (process_name(name), Box::<FlipFlop>::default())
-        } else if name.starts_with('&') {
-            let name = process_name(name);
-
-            let memory = outputs_of
-                .get(&name)
-                .unwrap()
-                .iter()
-                .map(|output| (output.to_string(), Signal::Low))
-                .collect();
-
-            (name, Box::new(Conjunction { memory }))
-        } else {
-            (name.to_string(), Box::new(Broadcast))
-        };
-
-        module_map.insert(name, Module { outputs, behavior });
-    }
-
-    module_map
-}
-
 fn process_name(raw_name: &str) -> String {
     if raw_name.starts_with('%') || raw_name.starts_with('&') {
         raw_name.chars().skip(1).collect()
@@ -210,7 +223,7 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(32_000_000, part1(EXAMPLE_1));
-        assert_eq!(11_687_500, part1(EXAMPLE_2));
+        assert_eq!(32_000_000, Day20::part1(&Day20::parse(EXAMPLE_1).unwrap()));
+        assert_eq!(11_687_500, Day20::part1(&Day20::parse(EXAMPLE_2).unwrap()));
     }
 }
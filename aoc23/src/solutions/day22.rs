@@ -1,12 +1,14 @@
-use regex::Regex;
 use std::collections::{HashMap, HashSet};
 
-use util::grid::Coordinate;
+use util::grid::{Coordinate, Coordinate3};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 type HeightMap = HashMap<Coordinate, (usize, Option<usize>)>;
 type BrickMap = HashMap<usize, HashSet<usize>>;
 
-struct Brick {
+#[derive(Clone)]
+pub struct Brick {
     index: usize,
     start: Coordinate,
     end: Coordinate,
@@ -73,28 +75,62 @@ impl Brick {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let mut bricks = parse(input);
-    let (bricks_on_top, brick_supported_by) = perform_initial_fall(&mut bricks);
+pub struct Day22;
 
-    bricks
-        .iter()
-        .filter(|brick| {
-            can_brick_safely_be_disintegrated(brick, &bricks_on_top, &brick_supported_by)
-        })
-        .count()
-}
+impl Solution for Day22 {
+    type Input<'a> = Vec<Brick>;
 
-pub fn part2(input: &str) -> usize {
-    let mut bricks = parse(input);
-    let (bricks_on_top, brick_supported_by) = perform_initial_fall(&mut bricks);
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| parse_brick(1 + index, line).map_err(|e| e.at_line(1 + index)))
+            .collect()
+    }
 
-    bricks
-        .iter()
-        .map(|brick| {
-            count_potential_falling_bricks(brick.index, &bricks_on_top, &brick_supported_by)
-        })
-        .sum()
+    fn part1(bricks: &Self::Input<'_>) -> Answer {
+        let mut bricks = bricks.clone();
+        let (bricks_on_top, brick_supported_by) = perform_initial_fall(&mut bricks);
+
+        bricks
+            .iter()
+            .filter(|brick| {
+                can_brick_safely_be_disintegrated(brick, &bricks_on_top, &brick_supported_by)
+            })
+            .count()
+            .into()
+    }
+
+    fn part2(bricks: &Self::Input<'_>) -> Answer {
+        let mut bricks = bricks.clone();
+        let (bricks_on_top, brick_supported_by) = perform_initial_fall(&mut bricks);
+
+        bricks
+            .iter()
+            .map(|brick| {
+                count_potential_falling_bricks(brick.index, &bricks_on_top, &brick_supported_by)
+            })
+            .sum::<usize>()
+            .into()
+    }
+}
+
+/// The brick with the number `index`, written as its two opposite corners like `1,0,1~1,2,1`.
+fn parse_brick(index: usize, line: &str) -> Result<Brick, ParseError> {
+    let (start, end) = line
+        .split_once('~')
+        .ok_or_else(|| ParseError::new(line, "expected two corners separated by '~'"))?;
+    let start = Coordinate3::<i32>::parse(start)?;
+    let end = Coordinate3::<i32>::parse(end)?;
+
+    // bricks are projected onto the ground, only their lower end is kept
+    Ok(Brick {
+        index,
+        start: start.xy(),
+        end: end.xy(),
+        z: start.z as usize,
+        height: (end.z - start.z) as usize,
+    })
 }
 
 fn perform_initial_fall(bricks: &mut [Brick]) -> (BrickMap, BrickMap) {
@@ -112,7 +148,7 @@ fn perform_initial_fall(bricks: &mut [Brick]) -> (BrickMap, BrickMap) {
 }
 
 fn sort_by_height(bricks: &mut [Brick]) {
-    bricks.sort_by(|a, b| (a.z, a.height).cmp(&(b.z, b.height)))
+    bricks.sort_by_key(|a| (a.z, a.height))
 }
 
 fn can_brick_safely_be_disintegrated(
@@ -173,35 +209,6 @@ fn perform_recursive_falling(
         });
 }
 
-fn parse(input: &str) -> Vec<Brick> {
-    let brick_regex = Regex::new(r"(\d+),(\d+),(\d+)~(\d+),(\d+),(\d+)").unwrap();
-    input
-        .lines()
-        .enumerate()
-        .map(|(index, line)| {
-            let numbers: Vec<usize> = brick_regex
-                .captures(line)
-                .unwrap()
-                .iter()
-                .skip(1)
-                .map(Option::unwrap)
-                .map(|group| group.as_str().parse::<usize>().unwrap())
-                .collect();
-
-            let start = Coordinate::from(numbers[0] as i32, numbers[1] as i32);
-            let end = Coordinate::from(numbers[3] as i32, numbers[4] as i32);
-
-            Brick {
-                index: 1 + index,
-                start,
-                end,
-                z: numbers[2],
-                height: numbers[5] - numbers[2],
-            }
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -280,11 +287,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(5, part1(EXAMPLE));
+        assert_eq!(5, Day22::part1(&Day22::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(7, part2(EXAMPLE));
+        assert_eq!(7, Day22::part2(&Day22::parse(EXAMPLE).unwrap()));
     }
 }
@@ -1,9 +1,11 @@
 use std::collections::{HashMap, HashSet, VecDeque};
 
 use util::grid::{Coordinate, Direction};
+use util::parse::ParseError;
+use util::solution::{Answer, Solution};
 
 #[derive(PartialEq, Debug, Clone)]
-enum Tile {
+pub enum Tile {
     Path,
     Slope(Direction),
 }
@@ -70,27 +72,43 @@ impl Adjacent for Part2Adjacent {
     }
 }
 
-pub fn part1(input: &str) -> usize {
-    let tiles = parse(input);
-    let adjacent_strategy = Part1Adjacent {
-        tiles: tiles.clone(),
-    };
+pub struct Day23;
 
-    part(tiles, &adjacent_strategy)
-}
+impl Solution for Day23 {
+    type Input<'a> = HashMap<Coordinate, Tile>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+        Ok(input
+            .lines()
+            .enumerate()
+            .flat_map(|(y, row)| {
+                row.char_indices().filter_map(move |(x, ch)| {
+                    Tile::from(ch).map(|tile| (Coordinate::from(x as i32, y as i32), tile))
+                })
+            })
+            .collect())
+    }
+
+    fn part1(tiles: &Self::Input<'_>) -> Answer {
+        let adjacent_strategy = Part1Adjacent {
+            tiles: tiles.clone(),
+        };
 
-pub fn part2(input: &str) -> usize {
-    let tiles = parse(input);
-    let adjacent_strategy = Part2Adjacent {
-        tiles: tiles.clone(),
-    };
+        part(tiles, &adjacent_strategy).into()
+    }
 
-    part(tiles, &adjacent_strategy)
+    fn part2(tiles: &Self::Input<'_>) -> Answer {
+        let adjacent_strategy = Part2Adjacent {
+            tiles: tiles.clone(),
+        };
+
+        part(tiles, &adjacent_strategy).into()
+    }
 }
 
-fn part(tiles: HashMap<Coordinate, Tile>, adjacent_strategy: &dyn Adjacent) -> usize {
-    let start = find_start(&tiles);
-    let goal = find_goal(&tiles);
+fn part(tiles: &HashMap<Coordinate, Tile>, adjacent_strategy: &dyn Adjacent) -> usize {
+    let start = find_start(tiles);
+    let goal = find_goal(tiles);
 
     let condensed_graph = condense_graph(start, goal, adjacent_strategy);
     find_longest_path(&condensed_graph, start, goal)
@@ -201,18 +219,6 @@ fn walk_paths(current: &Coordinate, adjacent_strategy: &dyn Adjacent) -> Vec<(Co
     results
 }
 
-fn parse(input: &str) -> HashMap<Coordinate, Tile> {
-    input
-        .lines()
-        .enumerate()
-        .flat_map(|(y, row)| {
-            row.char_indices().filter_map(move |(x, ch)| {
-                Tile::from(ch).map(|tile| (Coordinate::from(x as i32, y as i32), tile))
-            })
-        })
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -221,11 +227,11 @@ mod tests {
 
     #[test]
     fn part1_ex() {
-        assert_eq!(94, part1(EXAMPLE));
+        assert_eq!(94, Day23::part1(&Day23::parse(EXAMPLE).unwrap()));
     }
 
     #[test]
     fn part2_ex() {
-        assert_eq!(154, part2(EXAMPLE));
+        assert_eq!(154, Day23::part2(&Day23::parse(EXAMPLE).unwrap()));
     }
 }
@@ -0,0 +1,271 @@
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+use std::{env, error, fs};
+
+use toml::{Table, Value};
+
+use crate::solution::Answer;
+
+const ANSWERS_FILE: &str = "answers.toml";
+
+/// Answers which were accepted on the website, stored in the `answers.toml` of a year's crate.
+/// Rejected answers are kept as well, so they are never submitted twice.
+///
+/// ```toml
+/// [day01]
+/// part1 = 24000
+/// part1_wrong = [23000, 25000]
+/// part2 = 45000
+/// ```
+#[derive(Debug)]
+pub struct Answers {
+    path: PathBuf,
+    days: BTreeMap<u8, [Part; 2]>,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Part {
+    accepted: Option<Answer>,
+    wrong: Vec<Answer>,
+}
+
+impl Answers {
+    /// Load the answers of a year. A year without an answers file has no answers yet.
+    pub fn load(aoc_directory: &str) -> Result<Answers, Box<dyn error::Error>> {
+        let path = env::current_dir()?.join(aoc_directory).join(ANSWERS_FILE);
+
+        let text = match path.try_exists()? {
+            true => fs::read_to_string(&path)?,
+            false => String::new(),
+        };
+
+        Answers::from_toml(path, &text)
+    }
+
+    pub fn save(&self) -> Result<(), Box<dyn error::Error>> {
+        fs::write(&self.path, self.to_toml())?;
+
+        Ok(())
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.days.get(&day)?[part_index(part)].accepted.as_ref()
+    }
+
+    /// Record the answer of a part after it has been accepted, unless a different answer is
+    /// recorded already. That answer is returned as the error then and stays recorded.
+    pub fn confirm(&mut self, day: u8, part: u8, answer: Answer) -> Result<(), Answer> {
+        match self.get(day, part) {
+            Some(recorded) if *recorded != answer => Err(recorded.clone()),
+            _ => {
+                self.overwrite(day, part, answer);
+                Ok(())
+            }
+        }
+    }
+
+    /// Record the answer of a part, replacing the one recorded before.
+    pub fn overwrite(&mut self, day: u8, part: u8, answer: Answer) {
+        self.part_mut(day, part).accepted = Some(answer);
+    }
+
+    /// Record an answer which was rejected.
+    pub fn reject(&mut self, day: u8, part: u8, answer: Answer) {
+        if !self.is_rejected(day, part, &answer) {
+            self.part_mut(day, part).wrong.push(answer);
+        }
+    }
+
+    pub fn is_rejected(&self, day: u8, part: u8, answer: &Answer) -> bool {
+        self.days
+            .get(&day)
+            .is_some_and(|parts| parts[part_index(part)].wrong.contains(answer))
+    }
+
+    fn part_mut(&mut self, day: u8, part: u8) -> &mut Part {
+        &mut self.days.entry(day).or_default()[part_index(part)]
+    }
+
+    fn from_toml(path: PathBuf, text: &str) -> Result<Answers, Box<dyn error::Error>> {
+        let mut answers = Answers {
+            path,
+            days: BTreeMap::new(),
+        };
+
+        for (day_key, value) in text.parse::<Table>()? {
+            let day = day_key
+                .strip_prefix("day")
+                .and_then(|day| day.parse().ok())
+                .ok_or(format!("'{}' is not a day like 'day07'", day_key))?;
+            let parts = value
+                .as_table()
+                .ok_or(format!("'{}' has to be a table of parts", day_key))?;
+
+            for (part, part_key) in [(1, "part1"), (2, "part2")] {
+                let wrong_key = format!("{}_wrong", part_key);
+                let invalid = || format!("{}.{} is no number or text", day_key, part_key);
+
+                if let Some(value) = parts.get(part_key) {
+                    let answer = to_answer(value).ok_or_else(invalid)?;
+                    answers.overwrite(day, part, answer);
+                }
+
+                let wrong = match parts.get(&wrong_key) {
+                    Some(Value::Array(values)) => values.as_slice(),
+                    Some(_) => {
+                        return Err(format!("{}.{} has to be a list", day_key, wrong_key).into())
+                    }
+                    None => &[],
+                };
+                for value in wrong {
+                    let answer = to_answer(value).ok_or_else(invalid)?;
+                    answers.reject(day, part, answer);
+                }
+            }
+        }
+
+        Ok(answers)
+    }
+
+    fn to_toml(&self) -> String {
+        let mut table = Table::new();
+
+        for (day, parts) in self.days.iter() {
+            let mut day_table = Table::new();
+
+            for (number, part) in (1..).zip(parts) {
+                if let Some(answer) = part.accepted.as_ref() {
+                    day_table.insert(format!("part{}", number), to_value(answer));
+                }
+                if !part.wrong.is_empty() {
+                    let wrong = part.wrong.iter().map(to_value).collect();
+                    day_table.insert(format!("part{}_wrong", number), Value::Array(wrong));
+                }
+            }
+
+            table.insert(format!("day{:0>2}", day), Value::Table(day_table));
+        }
+
+        table.to_string()
+    }
+}
+
+// TOML integers are `i64`, so larger numbers are written as text and recognized when reading.
+fn to_answer(value: &Value) -> Option<Answer> {
+    match value {
+        Value::Integer(number) => Some(Answer::Number(*number)),
+        Value::String(text) => match text.parse::<u64>() {
+            Ok(number) if number > i64::MAX as u64 => Some(Answer::Unsigned(number)),
+            _ => Some(Answer::Text(text.clone())),
+        },
+        _ => None,
+    }
+}
+
+fn to_value(answer: &Answer) -> Value {
+    match answer {
+        Answer::Number(number) => Value::Integer(*number),
+        Answer::Unsigned(number) => Value::String(number.to_string()),
+        Answer::Text(text) => Value::String(text.clone()),
+    }
+}
+
+fn part_index(part: u8) -> usize {
+    match part {
+        1 | 2 => part as usize - 1,
+        _ => panic!("there is no part {} of a day", part),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ANSWERS: &str = "\
+[day01]
+part1 = 24000
+part2 = 45000
+
+[day10]
+part1 = 13140
+part1_wrong = [13000, \"13140\"]
+part2 = \"\"\"
+##..##
+#.#.#.\"\"\"
+";
+
+    fn example() -> Answers {
+        Answers::from_toml(PathBuf::from(ANSWERS_FILE), ANSWERS).unwrap()
+    }
+
+    #[test]
+    fn answers_are_read_per_day_and_part() {
+        let answers = example();
+
+        assert_eq!(Some(&Answer::Number(45000)), answers.get(1, 2));
+        assert_eq!(Some(&Answer::from("##..##\n#.#.#.")), answers.get(10, 2));
+        assert_eq!(None, answers.get(2, 1));
+    }
+
+    #[test]
+    fn answers_are_written_back_unchanged() {
+        let answers = example();
+        let written = Answers::from_toml(answers.path.clone(), &answers.to_toml()).unwrap();
+
+        assert_eq!(answers.days, written.days);
+    }
+
+    #[test]
+    fn confirmed_answers_fill_in_missing_ones() {
+        let mut answers = example();
+
+        assert_eq!(Ok(()), answers.confirm(3, 2, Answer::from("abc")));
+        assert_eq!(Ok(()), answers.confirm(1, 1, Answer::from(24000)));
+        assert_eq!(None, answers.get(3, 1));
+        assert_eq!(Some(&Answer::from("abc")), answers.get(3, 2));
+    }
+
+    #[test]
+    fn differing_answers_are_only_replaced_when_overwriting() {
+        let mut answers = example();
+
+        assert_eq!(
+            Err(Answer::from(24000)),
+            answers.confirm(1, 1, Answer::from(1))
+        );
+        assert_eq!(Some(&Answer::Number(24000)), answers.get(1, 1));
+
+        answers.overwrite(1, 1, Answer::from(1));
+        assert_eq!(Some(&Answer::Number(1)), answers.get(1, 1));
+    }
+
+    #[test]
+    fn large_unsigned_answers_are_written_as_text() {
+        let mut answers = example();
+        answers.reject(2, 1, Answer::from(u64::MAX));
+        let written = Answers::from_toml(answers.path.clone(), &answers.to_toml()).unwrap();
+
+        assert!(written.is_rejected(2, 1, &Answer::from(u64::MAX)));
+        assert_eq!(answers.days, written.days);
+    }
+
+    #[test]
+    fn rejected_answers_are_remembered_once() {
+        let mut answers = example();
+        answers.reject(10, 1, Answer::from(13000));
+        answers.reject(2, 2, Answer::from(7));
+
+        assert!(answers.is_rejected(10, 1, &Answer::from(13000)));
+        assert!(answers.is_rejected(10, 1, &Answer::from("13140")));
+        assert!(!answers.is_rejected(10, 1, &Answer::from(13140)));
+        assert!(answers.is_rejected(2, 2, &Answer::from(7)));
+        assert_eq!(2, answers.days[&10][0].wrong.len());
+    }
+
+    #[test]
+    fn keys_which_are_no_days_are_rejected() {
+        assert!(Answers::from_toml(PathBuf::new(), "[first]\npart1 = 1").is_err());
+        assert!(Answers::from_toml(PathBuf::new(), "[day01]\npart1 = 1.5").is_err());
+        assert!(Answers::from_toml(PathBuf::new(), "[day01]\npart1_wrong = 1").is_err());
+    }
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use num_traits::PrimInt;
+
+/// States which repeat after a number of steps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cycle {
+    /// Number of steps before the first state which repeats.
+    pub start: usize,
+    /// Number of steps until that state repeats.
+    pub period: usize,
+}
+
+impl Cycle {
+    /// The earliest step which leads to the same state as `n` steps.
+    pub fn equivalent_step(&self, n: usize) -> usize {
+        match n < self.start {
+            true => n,
+            false => self.start + (n - self.start) % self.period,
+        }
+    }
+
+    /// The value after `n` steps for values which only depend on the state.
+    /// `values` are the values of the states before the cycle and of the first lap.
+    pub fn value_at<'a, T>(&self, values: &'a [T], n: usize) -> &'a T {
+        &values[self.equivalent_step(n)]
+    }
+
+    /// The value after `n` steps for values which grow by the same amount every lap,
+    /// e.g. the height of a tower. `values` have to contain the value of the repeated state.
+    pub fn extrapolate<T: PrimInt>(&self, values: &[T], n: usize) -> T {
+        if n < values.len() {
+            return values[n];
+        }
+
+        let laps = (n - self.start) / self.period;
+        let growth = values[self.start + self.period] - values[self.start];
+
+        values[self.equivalent_step(n)] + growth * T::from(laps).unwrap()
+    }
+
+    /// The state after `n` steps, applying only the steps up to the equivalent one.
+    pub fn state_at<S>(&self, mut state: S, mut step: impl FnMut(&mut S), n: usize) -> S {
+        for _ in 0..self.equivalent_step(n) {
+            step(&mut state);
+        }
+
+        state
+    }
+}
+
+/// Apply `step` until the key of a state was seen before. Every key is remembered.
+/// Returns the cycle and the metric of every state up to and including the repeated one.
+pub fn find_cycle<S, K, M>(
+    mut state: S,
+    mut step: impl FnMut(&mut S),
+    mut key: impl FnMut(&S) -> K,
+    mut metric: impl FnMut(&S) -> M,
+) -> (Cycle, Vec<M>)
+where
+    K: Eq + Hash,
+{
+    let mut seen = HashMap::new();
+    let mut metrics = Vec::new();
+
+    for i in 0.. {
+        metrics.push(metric(&state));
+        if let Some(start) = seen.insert(key(&state), i) {
+            let period = i - start;
+            return (Cycle { start, period }, metrics);
+        }
+
+        step(&mut state);
+    }
+
+    unreachable!()
+}
+
+/// Brent's cycle detection, which only keeps two states at a time.
+/// The keys of two states have to be equal exactly if the states are.
+pub fn brent<S, K>(initial: &S, mut step: impl FnMut(&mut S), mut key: impl FnMut(&S) -> K) -> Cycle
+where
+    S: Clone,
+    K: PartialEq,
+{
+    // find the period by moving the tortoise to the hare at every power of two
+    let mut power = 1;
+    let mut period = 1;
+    let mut tortoise = key(initial);
+    let mut hare = initial.clone();
+    step(&mut hare);
+    let mut hare_key = key(&hare);
+
+    while tortoise != hare_key {
+        if power == period {
+            tortoise = hare_key;
+            power *= 2;
+            period = 0;
+        }
+        step(&mut hare);
+        hare_key = key(&hare);
+        period += 1;
+    }
+
+    // the first repetition is found with the hare one period ahead
+    let mut tortoise = initial.clone();
+    let mut hare = initial.clone();
+    for _ in 0..period {
+        step(&mut hare);
+    }
+
+    let mut start = 0;
+    while key(&tortoise) != key(&hare) {
+        step(&mut tortoise);
+        step(&mut hare);
+        start += 1;
+    }
+
+    Cycle { start, period }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0, 1, 2, 3, 4, 5, 3, 4, 5, ...
+    fn step(n: &mut u32) {
+        *n = if *n == 5 { 3 } else { *n + 1 };
+    }
+
+    #[test]
+    fn cycle_is_found_by_remembering_states() {
+        let (cycle, values) = find_cycle(0, step, |&n| n, |&n| n);
+
+        assert_eq!(
+            Cycle {
+                start: 3,
+                period: 3
+            },
+            cycle
+        );
+        assert_eq!(vec![0, 1, 2, 3, 4, 5, 3], values);
+    }
+
+    #[test]
+    fn cycle_is_found_with_brent() {
+        assert_eq!(
+            Cycle {
+                start: 3,
+                period: 3
+            },
+            brent(&0, step, |&n| n)
+        );
+        assert_eq!(
+            Cycle {
+                start: 0,
+                period: 1
+            },
+            brent(&7, |_| {}, |&n: &u32| n)
+        );
+    }
+
+    #[test]
+    fn values_are_taken_from_the_first_lap() {
+        let (cycle, values) = find_cycle(0, step, |&n| n, |&n| n);
+
+        assert_eq!(&2, cycle.value_at(&values, 2));
+        assert_eq!(&4, cycle.value_at(&values, 1_000_000));
+        assert_eq!(4, cycle.state_at(0, step, 1_000_000));
+    }
+
+    #[test]
+    fn growing_values_are_extrapolated() {
+        // the value grows by 10 every lap
+        let values = [0, 1, 2, 10, 12, 15, 20];
+        let cycle = Cycle {
+            start: 3,
+            period: 3,
+        };
+
+        assert_eq!(15, cycle.extrapolate(&values, 5));
+        assert_eq!(30, cycle.extrapolate(&values, 9));
+        assert_eq!(32, cycle.extrapolate(&values, 10));
+    }
+}
@@ -1,51 +1,243 @@
 use dotenv::dotenv;
-use reqwest::header::{COOKIE, USER_AGENT};
-use std::{env, error, io, fs};
 use std::path::PathBuf;
+use std::time::Duration;
+use std::{env, fs, io};
+
+use crate::solution::Answer;
+use client::client;
+pub use error::DlinError;
+
+mod client;
+mod error;
 
 const INPUT_FOLDER: &str = "inputs";
-const USER_AGENT_HEADER_CONTENT: &str = "https://github.com/H-Rusch/AdventOfCode-Rust contact @ https://github.com/H-Rusch/AdventOfCode-Rust/issues/new";
+const EXAMPLE_FOLDER: &str = "examples";
+const OFFLINE_VARIABLE: &str = "AOC_OFFLINE";
+const BASE_URL_VARIABLE: &str = "AOC_BASE_URL";
+const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
 
 struct Config {
     pub year: String,
     pub day: String,
     pub session_cookie: String,
+    pub base_url: String,
 }
 
 impl Config {
-    pub fn build(year: &str, day: &str) -> Result<Config, &'static str> {
+    pub fn build(year: &str, day: &str) -> Result<Config, DlinError> {
         let year = year.to_string();
         let day = day.to_string();
 
         dotenv().ok();
         let session_cookie = match std::env::var("session") {
             Ok(val) => val,
-            Err(_) => {
-                return Err("Error reading session cookie from .env. Entry 'session' not found.")
-            }
+            Err(_) => return Err(DlinError::MissingSession),
         };
 
+        // the website can be replaced by a local stand-in, e.g. for testing
+        let base_url = env::var(BASE_URL_VARIABLE).unwrap_or(DEFAULT_BASE_URL.to_string());
+
         Ok(Config {
             year,
             day,
             session_cookie,
+            base_url,
         })
     }
+
+    /// The url of a page of the day. The empty page is the puzzle description.
+    fn url(&self, page: &str) -> String {
+        [
+            self.base_url.trim_end_matches('/'),
+            &self.year,
+            "day",
+            &self.day,
+            page,
+        ]
+        .join("/")
+        .trim_end_matches('/')
+        .to_string()
+    }
 }
 
-pub fn read_input(
-    aoc_directory: &str,
-    year: &str,
-    day: u8,
-) -> Result<String, Box<dyn error::Error>> {
-    let day = &day.to_string();
-    let config = Config::build(year, day)?;
+/// The website's response to a submitted answer.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Another answer was submitted too recently. Contains the time left to wait.
+    RateLimited(Duration),
+    /// The part was solved already or is not unlocked yet.
+    WrongLevel,
+}
 
+pub fn read_input(aoc_directory: &str, year: &str, day: u8) -> Result<String, DlinError> {
     let path = build_inputs_path(aoc_directory);
-    create_inputs_dir(&path)?;
-    let path = build_day_input_path(path, day);
-    download_if_needed(&path, &config)?;
-    read_cached_file(&path)
+    let day_path = build_day_input_path(path.clone(), &day.to_string());
+
+    // the session is only needed if the input has to be downloaded
+    if !day_path.try_exists()? {
+        if is_offline() {
+            return Err(DlinError::NotCached(day));
+        }
+
+        let config = Config::build(year, &day.to_string())?;
+        create_dir_if_missing(&path)?;
+        download_if_needed(&day_path, &config)?;
+    }
+
+    read_cached_file(&day_path)
+}
+
+/// Download every input of a year which is not cached yet, one request at a time.
+/// Stops at the first puzzle which is not unlocked yet and returns the downloaded days.
+pub fn prefetch_inputs(aoc_directory: &str, year: &str) -> Result<Vec<u8>, DlinError> {
+    let mut downloaded = Vec::new();
+
+    for day in 1..=25 {
+        if read_cached_input(aoc_directory, day)?.is_some() {
+            continue;
+        }
+
+        match read_input(aoc_directory, year, day) {
+            Ok(_) => downloaded.push(day),
+            Err(DlinError::NotUnlocked) => break,
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(downloaded)
+}
+
+/// Submit the answer of a part and return how the website judged it.
+pub fn submit_answer(year: &str, day: u8, part: u8, answer: &Answer) -> Result<Verdict, DlinError> {
+    if is_offline() {
+        return Err(DlinError::Offline);
+    }
+
+    let config = Config::build(year, &day.to_string())?;
+    let body = post_answer(&config, part, answer)?;
+
+    parse_verdict(&body)
+}
+
+/// Examples taken from the puzzle description.
+#[derive(Debug)]
+pub struct Examples {
+    /// The files the example blocks were written to. Existing files are never overwritten.
+    pub files: Vec<PathBuf>,
+    /// The highlighted values of the description. They contain the expected example answers.
+    pub answers: Vec<String>,
+}
+
+/// Download the puzzle description and save its example blocks, the first one as
+/// `examples/dayNN.txt` like the days created by `new-day` expect and the following ones as
+/// `examples/dayNN_k.txt`. Existing examples are only replaced if they are empty.
+/// The description of part 2 is only included once part 1 is solved.
+pub fn fetch_examples(aoc_directory: &str, year: &str, day: u8) -> Result<Examples, DlinError> {
+    if is_offline() {
+        return Err(DlinError::Offline);
+    }
+
+    let config = Config::build(year, &day.to_string())?;
+    let page = make_request(&config, "")?;
+
+    let path = env::current_dir()?.join(aoc_directory).join(EXAMPLE_FOLDER);
+    create_dir_if_missing(&path)?;
+
+    let mut files = Vec::new();
+    for (k, block) in (1..).zip(extract_between(&page, "<pre><code>", "</code></pre>")) {
+        let file = path.join(example_file_name(day, k));
+        let is_empty = match file.try_exists()? {
+            true => fs::metadata(&file)?.len() == 0,
+            false => true,
+        };
+        if is_empty {
+            fs::write(&file, block.trim_end())?;
+        }
+        files.push(file);
+    }
+
+    let answers = extract_between(&page, "<code><em>", "</em></code>");
+
+    Ok(Examples { files, answers })
+}
+
+fn example_file_name(day: u8, k: usize) -> String {
+    match k {
+        1 => format!("day{:0>2}.txt", day),
+        _ => format!("day{:0>2}_{}.txt", day, k),
+    }
+}
+
+/// All texts between `start` and `end` without any inner tags.
+fn extract_between(html: &str, start: &str, end: &str) -> Vec<String> {
+    html.split(start)
+        .skip(1)
+        .filter_map(|rest| rest.split_once(end))
+        .map(|(inner, _)| unescape_html(&strip_tags(inner)))
+        .collect()
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+
+    for ch in html.chars() {
+        match ch {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            ch if !in_tag => text.push(ch),
+            _ => {}
+        }
+    }
+
+    text
+}
+
+fn unescape_html(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+/// Read an input from a file instead of the cache. The path `-` reads from stdin.
+pub fn read_input_file(path: &str) -> Result<String, DlinError> {
+    let text = match path {
+        "-" => io::read_to_string(io::stdin())?,
+        path => fs::read_to_string(path)?,
+    };
+
+    Ok(text.trim_end().to_string())
+}
+
+/// Offline mode never contacts the website.
+/// It is enabled by setting `AOC_OFFLINE` to anything but `0` or `false`.
+pub fn is_offline() -> bool {
+    dotenv().ok();
+
+    env::var(OFFLINE_VARIABLE).is_ok_and(|value| enables_offline_mode(&value))
+}
+
+fn enables_offline_mode(value: &str) -> bool {
+    !matches!(
+        value.trim().to_ascii_lowercase().as_str(),
+        "" | "0" | "false"
+    )
+}
+
+/// Read an input which was downloaded before, without contacting the website.
+pub fn read_cached_input(aoc_directory: &str, day: u8) -> Result<Option<String>, io::Error> {
+    let path = build_day_input_path(build_inputs_path(aoc_directory), &day.to_string());
+
+    match path.try_exists()? {
+        true => fs::read_to_string(path).map(Some),
+        false => Ok(None),
+    }
 }
 
 fn build_inputs_path(aoc_directory: &str) -> PathBuf {
@@ -55,7 +247,7 @@ fn build_inputs_path(aoc_directory: &str) -> PathBuf {
         .join(INPUT_FOLDER)
 }
 
-fn create_inputs_dir(path: &PathBuf) -> Result<(), io::Error> {
+fn create_dir_if_missing(path: &PathBuf) -> Result<(), io::Error> {
     if !path.try_exists()? {
         fs::create_dir(path)?;
     }
@@ -67,12 +259,12 @@ fn build_day_input_path(path: PathBuf, day: &str) -> PathBuf {
     path.join(format!("day{:0>2}.txt", day))
 }
 
-fn download_if_needed(path: &PathBuf, config: &Config) -> Result<(), Box<dyn error::Error>> {
+fn download_if_needed(path: &PathBuf, config: &Config) -> Result<(), DlinError> {
     if !path.try_exists()? {
-        let text = make_request(config)?.trim_end().to_string();
+        let text = make_request(config, "input")?.trim_end().to_string();
 
         if text.starts_with("Puzzle inputs differ by user.") {
-            return Err("User is currenty not logged in.".into());
+            return Err(DlinError::SessionExpired);
         }
         fs::write(path, &text)?;
     }
@@ -80,33 +272,189 @@ fn download_if_needed(path: &PathBuf, config: &Config) -> Result<(), Box<dyn err
     Ok(())
 }
 
-fn read_cached_file(path: &PathBuf) -> Result<String, Box<dyn error::Error>> {
-    match fs::read_to_string(path) {
-        Ok(text) => Ok(text),
-        Err(e) => Err(e.into()),
+fn read_cached_file(path: &PathBuf) -> Result<String, DlinError> {
+    Ok(fs::read_to_string(path)?)
+}
+
+fn parse_verdict(body: &str) -> Result<Verdict, DlinError> {
+    // the message is the only article of the page
+    let message = body
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(body, |(article, _)| article);
+
+    if message.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if message.contains("That's not the right answer") {
+        if message.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if message.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else {
+            Ok(Verdict::Incorrect)
+        }
+    } else if message.contains("You gave an answer too recently") {
+        Ok(Verdict::RateLimited(parse_wait_time(message)))
+    } else if message.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::WrongLevel)
+    } else {
+        Err(DlinError::UnexpectedResponse(message.trim().to_string()))
     }
 }
 
-#[tokio::main]
-async fn make_request(config: &Config) -> Result<String, Box<dyn error::Error>> {
-    let url = [
-        "https://adventofcode.com",
-        &config.year,
-        "day",
-        &config.day,
-        "input",
-    ]
-    .join("/");
+/// Parse the time left from a message like "You have 1m 5s left to wait."
+fn parse_wait_time(message: &str) -> Duration {
+    let wait = message
+        .split_once("You have ")
+        .and_then(|(_, rest)| rest.split_once(" left to wait"))
+        .map(|(wait, _)| wait);
+
+    let seconds = wait.and_then(|wait| {
+        wait.split_whitespace()
+            .map(|amount| {
+                let (number, unit) = amount.split_at(amount.len() - 1);
+                let factor = match unit {
+                    "h" => 3600,
+                    "m" => 60,
+                    "s" => 1,
+                    _ => return None,
+                };
+                number.parse::<u64>().ok().map(|number| number * factor)
+            })
+            .sum::<Option<u64>>()
+    });
+
+    // be careful if the message changed and wait a whole minute
+    Duration::from_secs(seconds.unwrap_or(60))
+}
+
+fn post_answer(config: &Config, part: u8, answer: &Answer) -> Result<String, DlinError> {
+    let form = [("level", part.to_string()), ("answer", answer.to_string())];
+
+    client().post_form(&config.url("answer"), &config.session_cookie, &form)
+}
+
+fn make_request(config: &Config, page: &str) -> Result<String, DlinError> {
+    client().get(&config.url(page), &config.session_cookie)
+}
 
-    let client = reqwest::Client::new();
-    let body = client
-        .get(url)
-        .header(COOKIE, format!("session={}", &config.session_cookie))
-        .header(USER_AGENT, USER_AGENT_HEADER_CONTENT)
-        .send()
-        .await?
-        .text()
-        .await?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use client::tests::serve;
 
-    Ok(body)
+    fn article(message: &str) -> String {
+        format!(
+            "<html><main><article><p>{}</p></article></main></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn answers_are_posted_to_the_answer_page() {
+        let (base_url, server) = serve(vec![(
+            "200 OK",
+            article("That's the right answer! You are one gold star closer."),
+        )]);
+        let config = Config {
+            year: "2022".to_string(),
+            day: "1".to_string(),
+            session_cookie: "cookie".to_string(),
+            base_url,
+        };
+
+        let body = post_answer(&config, 2, &Answer::from(45000)).unwrap();
+        let request = &server.join().unwrap()[0];
+
+        assert_eq!(Verdict::Correct, parse_verdict(&body).unwrap());
+        assert!(request.starts_with("POST /2022/day/1/answer HTTP/1.1"));
+        assert!(request.contains("cookie: session=cookie"));
+        assert!(request.ends_with("level=2&answer=45000"));
+    }
+
+    #[test]
+    fn wrong_answers_are_recognized() {
+        let wrong = "That's not the right answer. If you're stuck, make sure you're using the full input data.";
+        let too_high = "That's not the right answer; your answer is too high.";
+        let too_low = "That's not the right answer; your answer is too low.";
+
+        assert_eq!(Verdict::Incorrect, parse_verdict(&article(wrong)).unwrap());
+        assert_eq!(Verdict::TooHigh, parse_verdict(&article(too_high)).unwrap());
+        assert_eq!(Verdict::TooLow, parse_verdict(&article(too_low)).unwrap());
+    }
+
+    #[test]
+    fn rate_limits_contain_the_time_left() {
+        let minutes = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 5s left to wait.";
+        let seconds = "You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 32s left to wait.";
+
+        assert_eq!(
+            Verdict::RateLimited(Duration::from_secs(65)),
+            parse_verdict(&article(minutes)).unwrap()
+        );
+        assert_eq!(
+            Verdict::RateLimited(Duration::from_secs(32)),
+            parse_verdict(&article(seconds)).unwrap()
+        );
+    }
+
+    #[test]
+    fn example_blocks_and_highlighted_answers_are_extracted() {
+        let page =
+            "<article><p>For example:</p>\n<pre><code>1000\n2000\n\n&lt;3000&gt;\n</code></pre>\n\
+            <p>This is <code><em>24000</em></code> calories, not <em>three</em>.</p>\n\
+            <pre><code>A &amp; <em>B</em>\n</code></pre></article>";
+
+        assert_eq!(
+            vec!["1000\n2000\n\n<3000>\n", "A & B\n"],
+            extract_between(page, "<pre><code>", "</code></pre>")
+        );
+        assert_eq!(
+            vec!["24000"],
+            extract_between(page, "<code><em>", "</em></code>")
+        );
+    }
+
+    #[test]
+    fn the_first_example_is_the_one_new_days_include() {
+        assert_eq!("day07.txt", example_file_name(7, 1));
+        assert_eq!("day07_2.txt", example_file_name(7, 2));
+    }
+
+    #[test]
+    fn the_description_is_the_page_of_the_day() {
+        let config = Config {
+            year: "2023".to_string(),
+            day: "5".to_string(),
+            session_cookie: String::new(),
+            base_url: "http://localhost:8080/".to_string(),
+        };
+
+        assert_eq!("http://localhost:8080/2023/day/5", config.url(""));
+        assert_eq!(
+            "http://localhost:8080/2023/day/5/input",
+            config.url("input")
+        );
+    }
+
+    #[test]
+    fn unexpected_responses_are_errors() {
+        assert_eq!(
+            Verdict::WrongLevel,
+            parse_verdict(&article(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            ))
+            .unwrap()
+        );
+        assert!(parse_verdict("<html>Something else</html>").is_err());
+    }
+
+    #[test]
+    fn offline_mode_is_enabled_by_any_value_but_false() {
+        assert!(enables_offline_mode("1"));
+        assert!(enables_offline_mode("true"));
+        assert!(!enables_offline_mode("0"));
+        assert!(!enables_offline_mode("False"));
+        assert!(!enables_offline_mode(""));
+    }
 }
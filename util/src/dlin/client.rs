@@ -0,0 +1,289 @@
+use std::sync::{Mutex, OnceLock};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use reqwest::blocking::{self, RequestBuilder};
+use reqwest::header::{COOKIE, USER_AGENT};
+use reqwest::StatusCode;
+
+use super::DlinError;
+
+const USER_AGENT_HEADER_CONTENT: &str = "https://github.com/H-Rusch/AdventOfCode-Rust contact @ https://github.com/H-Rusch/AdventOfCode-Rust/issues/new";
+/// Be nice to the website, even when downloading every input of a year.
+const MIN_INTERVAL: Duration = Duration::from_secs(1);
+const TIMEOUT: Duration = Duration::from_secs(30);
+const MAX_ATTEMPTS: u32 = 3;
+/// Wait before the first retry, doubled for every further one, so an outage is not made worse.
+const BACKOFF: Duration = Duration::from_secs(2);
+
+static CLIENT: OnceLock<Client> = OnceLock::new();
+
+/// The client shared by every request to the website.
+pub fn client() -> &'static Client {
+    CLIENT.get_or_init(|| Client::new(MIN_INTERVAL, BACKOFF))
+}
+
+/// Sends requests with a session cookie. Consecutive requests are at least `min_interval` apart.
+/// Failed downloads are retried after `backoff`, which doubles with every attempt.
+pub struct Client {
+    http: blocking::Client,
+    min_interval: Duration,
+    backoff: Duration,
+    last_request: Mutex<Option<Instant>>,
+}
+
+impl Client {
+    fn new(min_interval: Duration, backoff: Duration) -> Self {
+        let http = blocking::Client::builder()
+            .user_agent(USER_AGENT_HEADER_CONTENT)
+            .timeout(TIMEOUT)
+            .build()
+            .expect("the HTTP client could not be built");
+
+        Client {
+            http,
+            min_interval,
+            backoff,
+            last_request: Mutex::new(None),
+        }
+    }
+
+    pub fn get(&self, url: &str, session_cookie: &str) -> Result<String, DlinError> {
+        self.send(self.http.get(url), session_cookie, MAX_ATTEMPTS)
+    }
+
+    /// Sent only once, a form which timed out may have been received and acted on already.
+    pub fn post_form(
+        &self,
+        url: &str,
+        session_cookie: &str,
+        form: &[(&str, String)],
+    ) -> Result<String, DlinError> {
+        self.send(self.http.post(url).form(form), session_cookie, 1)
+    }
+
+    /// Send a request, retrying it up to `max_attempts` in total if the website could not be
+    /// reached or was unavailable.
+    fn send(
+        &self,
+        request: RequestBuilder,
+        session_cookie: &str,
+        max_attempts: u32,
+    ) -> Result<String, DlinError> {
+        let request = request
+            .header(COOKIE, format!("session={}", session_cookie))
+            .header(USER_AGENT, USER_AGENT_HEADER_CONTENT);
+
+        let mut attempt = 1;
+        loop {
+            if attempt > 1 {
+                thread::sleep(self.backoff * 2_u32.pow(attempt - 2));
+            }
+            self.throttle();
+            let retry = request
+                .try_clone()
+                .expect("requests have no streamed bodies");
+
+            let response = match retry.send() {
+                Err(e) if (e.is_connect() || e.is_timeout()) && attempt < max_attempts => {
+                    attempt += 1;
+                    continue;
+                }
+                result => result?,
+            };
+
+            match response.status() {
+                status if status.is_success() => return Ok(response.text()?),
+                StatusCode::NOT_FOUND => return Err(DlinError::NotUnlocked),
+                StatusCode::BAD_REQUEST | StatusCode::INTERNAL_SERVER_ERROR => {
+                    return Err(DlinError::SessionExpired)
+                }
+                StatusCode::BAD_GATEWAY
+                | StatusCode::SERVICE_UNAVAILABLE
+                | StatusCode::GATEWAY_TIMEOUT
+                    if attempt < max_attempts =>
+                {
+                    attempt += 1;
+                }
+                status => return Err(DlinError::Status(status)),
+            }
+        }
+    }
+
+    /// Wait until `min_interval` has passed since the previous request.
+    fn throttle(&self) {
+        let mut last_request = self.last_request.lock().unwrap();
+
+        if let Some(last) = *last_request {
+            let elapsed = last.elapsed();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        *last_request = Some(Instant::now());
+    }
+}
+
+#[cfg(test)]
+pub mod tests {
+    use super::*;
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    /// Answer one request per response with the given status line and body.
+    /// Returns the url of the server and the raw requests it received.
+    pub fn serve(
+        responses: Vec<(&'static str, String)>,
+    ) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            responses
+                .into_iter()
+                .map(|(status, body)| {
+                    let (mut stream, _) = listener.accept().unwrap();
+                    let request = read_request(&mut stream);
+                    write!(
+                        stream,
+                        "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+
+                    request
+                })
+                .collect()
+        });
+
+        (url, handle)
+    }
+
+    fn read_request(stream: &mut impl Read) -> String {
+        let mut request = Vec::new();
+        let mut buffer = [0; 1024];
+
+        // the request is complete once the body announced in the headers arrived
+        loop {
+            let read = stream.read(&mut buffer).unwrap();
+            request.extend_from_slice(&buffer[..read]);
+
+            let text = String::from_utf8_lossy(&request);
+            if let Some((headers, body)) = text.split_once("\r\n\r\n") {
+                let length = headers
+                    .lines()
+                    .find_map(|line| {
+                        line.to_lowercase()
+                            .strip_prefix("content-length: ")?
+                            .parse()
+                            .ok()
+                    })
+                    .unwrap_or(0);
+                if body.len() >= length {
+                    return text.to_string();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn requests_contain_the_session_cookie() {
+        let (url, server) = serve(vec![("200 OK", "1\n2\n".to_string())]);
+
+        let body = Client::new(Duration::ZERO, Duration::ZERO)
+            .get(&url, "cookie")
+            .unwrap();
+        let requests = server.join().unwrap();
+
+        assert_eq!("1\n2\n", body);
+        assert!(requests[0].contains("cookie: session=cookie"));
+        assert!(requests[0].contains("user-agent: https://github.com/H-Rusch"));
+    }
+
+    #[test]
+    fn status_codes_are_turned_into_errors() {
+        let (url, server) = serve(vec![
+            ("404 Not Found", String::new()),
+            ("400 Bad Request", String::new()),
+            ("500 Internal Server Error", String::new()),
+            ("403 Forbidden", String::new()),
+        ]);
+        let client = Client::new(Duration::ZERO, Duration::ZERO);
+
+        assert!(matches!(client.get(&url, ""), Err(DlinError::NotUnlocked)));
+        assert!(matches!(
+            client.get(&url, ""),
+            Err(DlinError::SessionExpired)
+        ));
+        assert!(matches!(
+            client.get(&url, ""),
+            Err(DlinError::SessionExpired)
+        ));
+        assert!(matches!(
+            client.get(&url, ""),
+            Err(DlinError::Status(StatusCode::FORBIDDEN))
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn unavailable_website_is_retried() {
+        let (url, server) = serve(vec![
+            ("503 Service Unavailable", String::new()),
+            ("200 OK", "input".to_string()),
+        ]);
+
+        let body = Client::new(Duration::ZERO, Duration::ZERO)
+            .get(&url, "")
+            .unwrap();
+
+        assert_eq!("input", body);
+        assert_eq!(2, server.join().unwrap().len());
+    }
+
+    #[test]
+    fn forms_are_not_resent() {
+        let (url, server) = serve(vec![("503 Service Unavailable", String::new())]);
+
+        let result = Client::new(Duration::ZERO, Duration::ZERO).post_form(&url, "", &[]);
+
+        assert!(matches!(
+            result,
+            Err(DlinError::Status(StatusCode::SERVICE_UNAVAILABLE))
+        ));
+        assert_eq!(1, server.join().unwrap().len());
+    }
+
+    #[test]
+    fn retries_back_off_exponentially() {
+        let (url, server) = serve(vec![
+            ("502 Bad Gateway", String::new()),
+            ("504 Gateway Timeout", String::new()),
+            ("200 OK", "input".to_string()),
+        ]);
+        let client = Client::new(Duration::ZERO, Duration::from_millis(100));
+
+        let start = Instant::now();
+        let body = client.get(&url, "").unwrap();
+
+        assert_eq!("input", body);
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn requests_are_throttled() {
+        let (url, server) = serve(vec![("200 OK", String::new()), ("200 OK", String::new())]);
+        let client = Client::new(Duration::from_millis(200), Duration::ZERO);
+
+        let start = Instant::now();
+        client.get(&url, "").unwrap();
+        client.get(&url, "").unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(200));
+        server.join().unwrap();
+    }
+}
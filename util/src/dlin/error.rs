@@ -0,0 +1,77 @@
+use std::{error, fmt, io};
+
+use reqwest::StatusCode;
+
+use super::OFFLINE_VARIABLE;
+
+/// Everything that can go wrong while downloading from or submitting to the website.
+#[derive(Debug)]
+pub enum DlinError {
+    /// Neither the environment nor the `.env`-file contain a `session` entry.
+    MissingSession,
+    /// The website answered with 400 or 500, or asked to log in, which it does for an invalid
+    /// session cookie.
+    SessionExpired,
+    /// The website answered with 404, the puzzle is not unlocked yet.
+    NotUnlocked,
+    /// The input of the day is not cached and offline mode is enabled.
+    NotCached(u8),
+    /// Offline mode is enabled, but the website has to be contacted.
+    Offline,
+    /// The website answered with an unexpected status code.
+    Status(StatusCode),
+    /// The website answered with a page that could not be understood.
+    UnexpectedResponse(String),
+    Http(reqwest::Error),
+    Io(io::Error),
+}
+
+impl fmt::Display for DlinError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DlinError::MissingSession => {
+                write!(f, "Entry 'session' not found in the environment or .env.")
+            }
+            DlinError::SessionExpired => write!(f, "The session cookie is invalid or expired."),
+            DlinError::NotUnlocked => write!(f, "The puzzle is not unlocked yet."),
+            DlinError::NotCached(day) => write!(
+                f,
+                "The input of day {} is not cached and {} is set.",
+                day, OFFLINE_VARIABLE
+            ),
+            DlinError::Offline => write!(
+                f,
+                "The website is not contacted while {} is set.",
+                OFFLINE_VARIABLE
+            ),
+            DlinError::Status(status) => write!(f, "The website answered with {}.", status),
+            DlinError::UnexpectedResponse(message) => {
+                write!(f, "Unexpected response of the website: {}", message)
+            }
+            DlinError::Http(e) => write!(f, "The request failed: {}", e),
+            DlinError::Io(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl error::Error for DlinError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            DlinError::Http(e) => Some(e),
+            DlinError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for DlinError {
+    fn from(e: reqwest::Error) -> Self {
+        DlinError::Http(e)
+    }
+}
+
+impl From<io::Error> for DlinError {
+    fn from(e: io::Error) -> Self {
+        DlinError::Io(e)
+    }
+}
@@ -0,0 +1,177 @@
+//! Polygons on the integer lattice, given by their corners in order.
+//! The last corner is connected back to the first one.
+
+use num_traits::{PrimInt, Signed};
+
+use crate::grid::{Coordinate, Offset};
+
+/// The corners reached by following the instructions from `start`.
+/// A path which returns to `start` ends with it as the last corner.
+pub fn trace_polygon<T, D>(
+    start: Coordinate<T>,
+    instructions: impl IntoIterator<Item = (D, u32)>,
+) -> Vec<Coordinate<T>>
+where
+    T: PrimInt + Signed,
+    D: Offset,
+{
+    instructions
+        .into_iter()
+        .scan(start, |current, (direction, steps)| {
+            *current = current.step(&direction, steps);
+            Some(*current)
+        })
+        .collect()
+}
+
+/// Twice the enclosed area, which is always an integer.
+/// Calculated with the shoelace formula (https://en.wikipedia.org/wiki/Shoelace_formula).
+pub fn double_area<T: PrimInt + Signed>(polygon: &[Coordinate<T>]) -> T {
+    edges(polygon)
+        .fold(T::zero(), |area, (p1, p2)| {
+            area + (p1.x * p2.y - p1.y * p2.x)
+        })
+        .abs()
+}
+
+/// The enclosed area, rounded down if it is not an integer.
+pub fn area<T: PrimInt + Signed>(polygon: &[Coordinate<T>]) -> T {
+    double_area(polygon) / two()
+}
+
+/// The number of lattice points on the edges of the polygon.
+pub fn boundary_points<T: PrimInt + Signed>(polygon: &[Coordinate<T>]) -> T {
+    edges(polygon).fold(T::zero(), |count, (p1, p2)| {
+        let difference = *p2 - *p1;
+        count + gcd(difference.x.abs(), difference.y.abs())
+    })
+}
+
+/// The number of lattice points strictly inside the polygon.
+/// Calculated with Pick's theorem (https://en.wikipedia.org/wiki/Pick%27s_theorem).
+pub fn interior_points<T: PrimInt + Signed>(polygon: &[Coordinate<T>]) -> T {
+    (double_area(polygon) - boundary_points(polygon) + two()) / two()
+}
+
+/// The number of lattice points inside the polygon or on its edges.
+pub fn enclosed_points<T: PrimInt + Signed>(polygon: &[Coordinate<T>]) -> T {
+    interior_points(polygon) + boundary_points(polygon)
+}
+
+pub fn is_on_boundary<T: PrimInt + Signed>(
+    polygon: &[Coordinate<T>],
+    point: &Coordinate<T>,
+) -> bool {
+    edges(polygon).any(|(p1, p2)| {
+        let (edge, to_point) = (*p2 - *p1, *point - *p1);
+        let is_collinear = edge.x * to_point.y == edge.y * to_point.x;
+
+        is_collinear
+            && point.x >= p1.x.min(p2.x)
+            && point.x <= p1.x.max(p2.x)
+            && point.y >= p1.y.min(p2.y)
+            && point.y <= p1.y.max(p2.y)
+    })
+}
+
+/// Whether the point is strictly inside the polygon, points on an edge are not.
+pub fn is_inside<T: PrimInt + Signed>(polygon: &[Coordinate<T>], point: &Coordinate<T>) -> bool {
+    if is_on_boundary(polygon, point) {
+        return false;
+    }
+
+    // count the edges crossed by a ray from the point in the direction of growing x
+    edges(polygon)
+        .filter(|(p1, p2)| (p1.y > point.y) != (p2.y > point.y))
+        .filter(|(p1, p2)| {
+            let (edge, to_point) = (**p2 - **p1, *point - **p1);
+            let cross = edge.x * to_point.y - to_point.x * edge.y;
+
+            match edge.y > T::zero() {
+                true => cross > T::zero(),
+                false => cross < T::zero(),
+            }
+        })
+        .count()
+        % 2
+        == 1
+}
+
+fn edges<T>(polygon: &[Coordinate<T>]) -> impl Iterator<Item = (&Coordinate<T>, &Coordinate<T>)> {
+    polygon.iter().zip(polygon.iter().cycle().skip(1))
+}
+
+fn two<T: PrimInt>() -> T {
+    T::one() + T::one()
+}
+
+fn gcd<T: PrimInt>(a: T, b: T) -> T {
+    match b.is_zero() {
+        true => a,
+        false => gcd(b, a % b),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Direction;
+
+    fn square(size: i32) -> Vec<Coordinate> {
+        [(0, 0), (size, 0), (size, size), (0, size)]
+            .into_iter()
+            .map(|(x, y)| Coordinate::from(x, y))
+            .collect()
+    }
+
+    #[test]
+    fn points_of_a_square_are_counted() {
+        let square = square(4);
+
+        assert_eq!(16, area(&square));
+        assert_eq!(16, boundary_points(&square));
+        assert_eq!(9, interior_points(&square));
+        assert_eq!(25, enclosed_points(&square));
+    }
+
+    #[test]
+    fn diagonal_edges_are_counted() {
+        let triangle: Vec<Coordinate> = [(0, 0), (4, 0), (0, 3)]
+            .into_iter()
+            .map(|(x, y)| Coordinate::from(x, y))
+            .collect();
+
+        assert_eq!(12, double_area(&triangle));
+        assert_eq!(8, boundary_points(&triangle));
+        assert_eq!(3, interior_points(&triangle));
+    }
+
+    #[test]
+    fn points_are_located() {
+        let square = square(4);
+
+        assert!(is_inside(&square, &Coordinate::from(2, 3)));
+        assert!(!is_inside(&square, &Coordinate::from(4, 2)));
+        assert!(is_on_boundary(&square, &Coordinate::from(4, 2)));
+        assert!(!is_inside(&square, &Coordinate::from(5, 2)));
+        assert!(!is_on_boundary(&square, &Coordinate::from(5, 2)));
+    }
+
+    #[test]
+    fn huge_polygons_do_not_overflow() {
+        let steps = 1 << 20;
+        let polygon: Vec<Coordinate<i64>> = trace_polygon(
+            Coordinate::default(),
+            [
+                (Direction::Right, steps),
+                (Direction::Down, steps),
+                (Direction::Left, steps),
+                (Direction::Up, steps),
+            ],
+        );
+
+        assert_eq!(Coordinate::default(), polygon[3]);
+        assert_eq!(1 << 40, area(&polygon));
+        assert_eq!((1 << 40) + (1 << 21) + 1, enclosed_points(&polygon));
+    }
+}
@@ -1,4 +1,22 @@
-use std::ops::Range;
+use std::ops::{Add, AddAssign, Mul, Neg, Range, Sub, SubAssign};
+
+use num_traits::{PrimInt, Signed};
+
+pub use compass::Direction8;
+pub use dense::Grid;
+pub use hex::{FlatHexDirection, PointyHexDirection};
+pub use space::{Bounds3, Coordinate3, Direction3, Offset3};
+
+mod compass;
+mod dense;
+mod hex;
+mod space;
+
+/// Anything a `Coordinate` can step into.
+/// The offset is the change of `x` and `y` for a single step.
+pub trait Offset {
+    fn offset(&self) -> (i32, i32);
+}
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum Direction {
@@ -69,44 +87,62 @@ impl Direction {
     }
 }
 
-/// Two dimensional coordinate
+impl Offset for Direction {
+    fn offset(&self) -> (i32, i32) {
+        match self {
+            Direction::Right => (1, 0),
+            Direction::Up => (0, -1),
+            Direction::Left => (-1, 0),
+            Direction::Down => (0, 1),
+        }
+    }
+}
+
+/// Two dimensional coordinate, which is also used as a vector between coordinates.
+/// Grids use `i32`, wider integers help with large areas.
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
-pub struct Coordinate {
-    pub x: i32,
-    pub y: i32,
+pub struct Coordinate<T = i32> {
+    pub x: T,
+    pub y: T,
 }
 
-impl Coordinate {
-    pub fn from(x: i32, y: i32) -> Self {
+impl<T: PrimInt + Signed> Coordinate<T> {
+    pub fn from(x: T, y: T) -> Self {
         Coordinate { x, y }
     }
 
-    pub fn manhatten_distance(&self, other: &Coordinate) -> u32 {
-        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    pub fn manhatten_distance(&self, other: &Coordinate<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
     }
 
-    pub fn step(&self, direction: &Direction, steps: u32) -> Self {
-        let steps = steps as i32;
-        match direction {
-            Direction::Right => Coordinate::from(self.x + steps, self.y),
-            Direction::Left => Coordinate::from(self.x - steps, self.y),
-            Direction::Up => Coordinate::from(self.x, self.y - steps),
-            Direction::Down => Coordinate::from(self.x, self.y + steps),
-        }
+    /// The number of steps between two coordinates if diagonal steps are allowed.
+    pub fn chebyshev_distance(&self, other: &Coordinate<T>) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs())
     }
 
-    pub fn step_in_bounds(
-        &self,
-        direction: &Direction,
-        steps: u32,
-        bounds: &Bounds,
-    ) -> Option<Self> {
-        let coordinate = self.step(direction, steps);
-        (bounds.x_values.contains(&coordinate.x) && bounds.y_values.contains(&coordinate.y))
-            .then_some(coordinate)
+    /// The vector with the sign of each value, e.g. the direction of a straight line.
+    pub fn signum(&self) -> Self {
+        Coordinate::from(self.x.signum(), self.y.signum())
+    }
+
+    /// Rotate clockwise by 90 degrees around the origin, with `y` growing downwards.
+    pub fn rotate_right(&self) -> Self {
+        Coordinate::from(-self.y, self.x)
+    }
+
+    /// Rotate counterclockwise by 90 degrees around the origin, with `y` growing downwards.
+    pub fn rotate_left(&self) -> Self {
+        Coordinate::from(self.y, -self.x)
+    }
+
+    pub fn step(&self, direction: &impl Offset, steps: u32) -> Self {
+        let (dx, dy) = direction.offset();
+        let offset = Coordinate::from(T::from(dx).unwrap(), T::from(dy).unwrap());
+
+        *self + offset * T::from(steps).unwrap()
     }
 
-    pub fn get_adjacent(&self) -> Vec<Coordinate> {
+    pub fn get_adjacent(&self) -> Vec<Self> {
         [
             Direction::Right,
             Direction::Down,
@@ -117,6 +153,82 @@ impl Coordinate {
         .map(|direction| self.step(direction, 1))
         .collect()
     }
+
+    /// The orthogonally and diagonally adjacent coordinates.
+    pub fn get_adjacent_with_diag(&self) -> Vec<Self> {
+        Direction8::iter()
+            .map(|direction| self.step(&direction, 1))
+            .collect()
+    }
+}
+
+impl Coordinate {
+    pub fn step_in_bounds(
+        &self,
+        direction: &impl Offset,
+        steps: u32,
+        bounds: &Bounds,
+    ) -> Option<Self> {
+        let coordinate = self.step(direction, steps);
+        (bounds.x_values.contains(&coordinate.x) && bounds.y_values.contains(&coordinate.y))
+            .then_some(coordinate)
+    }
+}
+
+impl<T: PrimInt + Signed> Add for Coordinate<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Coordinate::from(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: PrimInt + Signed> AddAssign for Coordinate<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: PrimInt + Signed> Sub for Coordinate<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Coordinate::from(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: PrimInt + Signed> SubAssign for Coordinate<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: PrimInt + Signed> Mul<T> for Coordinate<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self::Output {
+        Coordinate::from(self.x * factor, self.y * factor)
+    }
+}
+
+impl<T: PrimInt + Signed> Neg for Coordinate<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Coordinate::from(-self.x, -self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Coordinate<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Coordinate { x, y }
+    }
+}
+
+impl<T> From<Coordinate<T>> for (T, T) {
+    fn from(coordinate: Coordinate<T>) -> Self {
+        (coordinate.x, coordinate.y)
+    }
 }
 
 #[derive(Clone)]
@@ -323,4 +435,38 @@ mod tests {
             bounds.coordinates().collect::<Vec<_>>()
         );
     }
+
+    #[test]
+    fn coordinates_are_added_like_vectors() {
+        let a = Coordinate::from(3, -2);
+        let b = Coordinate::from(-1, 4);
+
+        assert_eq!(Coordinate::from(2, 2), a + b);
+        assert_eq!(Coordinate::from(4, -6), a - b);
+        assert_eq!(Coordinate::from(9, -6), a * 3);
+        assert_eq!(Coordinate::from(-3, 2), -a);
+        assert_eq!(Coordinate::from(1, -1), a.signum());
+    }
+
+    #[test]
+    fn coordinates_rotate_like_directions() {
+        let origin: Coordinate = Coordinate::default();
+
+        for direction in Direction::iter() {
+            let vector = origin.step(&direction, 1);
+
+            assert_eq!(origin.step(&direction.right(), 1), vector.rotate_right());
+            assert_eq!(origin.step(&direction.left(), 1), vector.rotate_left());
+        }
+    }
+
+    #[test]
+    fn distances_work_for_wide_integers() {
+        let a: Coordinate<i64> = (3_000_000_000, 1).into();
+        let b = Coordinate::from(-1_000_000_000, 5);
+
+        assert_eq!(4_000_000_004, a.manhatten_distance(&b));
+        assert_eq!(4_000_000_000, a.chebyshev_distance(&b));
+        assert_eq!((3_000_000_010, 1), a.step(&Direction::Right, 10).into());
+    }
 }
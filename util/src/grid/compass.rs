@@ -0,0 +1,148 @@
+use super::{Direction, Offset};
+
+/// The eight directions of a compass, clockwise from north.
+/// North points upwards, so it decreases `y` like `Direction::Up`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+const CLOCKWISE: [Direction8; 8] = [
+    Direction8::North,
+    Direction8::NorthEast,
+    Direction8::East,
+    Direction8::SouthEast,
+    Direction8::South,
+    Direction8::SouthWest,
+    Direction8::West,
+    Direction8::NorthWest,
+];
+
+impl Direction8 {
+    /// Rotate clockwise by 45 degrees.
+    pub fn turn_right(&mut self) {
+        *self = self.right();
+    }
+
+    /// The direction rotated clockwise by 45 degrees.
+    pub fn right(&self) -> Self {
+        self.rotate(1)
+    }
+
+    /// Rotate counterclockwise by 45 degrees.
+    pub fn turn_left(&mut self) {
+        *self = self.left();
+    }
+
+    /// The direction rotated counterclockwise by 45 degrees.
+    pub fn left(&self) -> Self {
+        self.rotate(7)
+    }
+
+    pub fn turn_around(&mut self) {
+        *self = self.back();
+    }
+
+    pub fn back(&self) -> Self {
+        self.rotate(4)
+    }
+
+    pub fn is_diagonal(&self) -> bool {
+        (*self as u8) % 2 == 1
+    }
+
+    /// All directions clockwise from north.
+    pub fn iter() -> impl Iterator<Item = Direction8> {
+        CLOCKWISE.into_iter()
+    }
+
+    fn rotate(&self, eighths: u8) -> Self {
+        CLOCKWISE[((*self as u8 + eighths) % 8) as usize]
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::Right => Direction8::East,
+            Direction::Up => Direction8::North,
+            Direction::Left => Direction8::West,
+            Direction::Down => Direction8::South,
+        }
+    }
+}
+
+impl Offset for Direction8 {
+    fn offset(&self) -> (i32, i32) {
+        match self {
+            Direction8::North => (0, -1),
+            Direction8::NorthEast => (1, -1),
+            Direction8::East => (1, 0),
+            Direction8::SouthEast => (1, 1),
+            Direction8::South => (0, 1),
+            Direction8::SouthWest => (-1, 1),
+            Direction8::West => (-1, 0),
+            Direction8::NorthWest => (-1, -1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Bounds, Coordinate};
+
+    #[test]
+    fn directions_rotate_by_45_degrees() {
+        assert_eq!(Direction8::NorthEast, Direction8::North.right());
+        assert_eq!(Direction8::NorthWest, Direction8::North.left());
+        assert_eq!(Direction8::SouthWest, Direction8::NorthEast.back());
+
+        let mut direction = Direction8::West;
+        for _ in 0..8 {
+            direction.turn_right();
+        }
+        assert_eq!(Direction8::West, direction);
+    }
+
+    #[test]
+    fn orthogonal_directions_match_direction() {
+        for direction in Direction::iter() {
+            let direction8 = Direction8::from(direction);
+
+            assert!(!direction8.is_diagonal());
+            assert_eq!(direction.offset(), direction8.offset());
+            assert_eq!(
+                Direction8::from(direction.right()),
+                direction8.right().right()
+            );
+        }
+    }
+
+    #[test]
+    fn coordinates_step_diagonally() {
+        let coordinate = Coordinate::from(1, 1);
+        let bounds = Bounds::from(0..3, 0..3);
+
+        assert_eq!(
+            Coordinate::from(3, -1),
+            coordinate.step(&Direction8::NorthEast, 2)
+        );
+        assert_eq!(
+            Some(Coordinate::from(0, 2)),
+            coordinate.step_in_bounds(&Direction8::SouthWest, 1, &bounds)
+        );
+        assert_eq!(
+            None,
+            coordinate.step_in_bounds(&Direction8::SouthWest, 2, &bounds)
+        );
+        assert_eq!(8, coordinate.get_adjacent_with_diag().len());
+    }
+}
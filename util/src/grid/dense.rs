@@ -0,0 +1,371 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use super::{Bounds, Coordinate, Direction};
+use crate::parse::ParseError;
+
+/// Rectangular grid stored row by row in a single `Vec`.
+/// The top left cell is at `(0, 0)`, `x` grows to the right and `y` downwards.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid in which every cell has the same value.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid in which every cell is computed from its coordinate.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Coordinate) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Coordinate::from(x as i32, y as i32)))
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Grid of rows with `width` cells each.
+    ///
+    /// Panics if the number of cells is not a multiple of `width`.
+    pub fn from_vec(width: usize, cells: Vec<T>) -> Self {
+        assert!(
+            width > 0 && cells.len().is_multiple_of(width),
+            "{} cells do not fill rows of width {}",
+            cells.len(),
+            width
+        );
+
+        Grid {
+            width,
+            height: cells.len() / width,
+            cells,
+        }
+    }
+
+    /// Parse every line as a row with one cell per character.
+    /// Fails if `f` rejects a character or the lines differ in length.
+    pub fn parse(
+        input: &str,
+        mut f: impl FnMut(char) -> Result<T, ParseError>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.lines() {
+            height += 1;
+            let before = cells.len();
+            for ch in line.chars() {
+                cells.push(f(ch).map_err(|e| e.at_line(height))?);
+            }
+
+            let row_width = cells.len() - before;
+            let width = *width.get_or_insert(row_width);
+            if row_width != width {
+                let reason = format!("expected {} characters like the first line", width);
+                return Err(ParseError::new(line, reason).at_line(height));
+            }
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn bounds(&self) -> Bounds {
+        Bounds::from(0..self.width as i32, 0..self.height as i32)
+    }
+
+    pub fn contains(&self, coordinate: &Coordinate) -> bool {
+        self.index_of(coordinate).is_some()
+    }
+
+    pub fn get(&self, coordinate: &Coordinate) -> Option<&T> {
+        self.index_of(coordinate).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, coordinate: &Coordinate) -> Option<&mut T> {
+        self.index_of(coordinate)
+            .map(|index| &mut self.cells[index])
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is outside of the grid", x);
+
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// All coordinates row by row.
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate> {
+        let width = self.width;
+
+        (0..self.cells.len()).map(move |index| coordinate_of(index, width))
+    }
+
+    /// All cells together with their coordinate row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Coordinate, &T)> {
+        self.coordinates().zip(self.cells.iter())
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// The coordinate of the first cell row by row which matches the predicate.
+    pub fn find(&self, predicate: impl FnMut(&T) -> bool) -> Option<Coordinate> {
+        self.cells
+            .iter()
+            .position(predicate)
+            .map(|index| coordinate_of(index, self.width))
+    }
+
+    /// The orthogonally adjacent cells which are inside the grid.
+    pub fn neighbours(&self, coordinate: &Coordinate) -> impl Iterator<Item = (Coordinate, &T)> {
+        let bounds = self.bounds();
+        let coordinate = *coordinate;
+
+        Direction::iter()
+            .filter_map(move |direction| coordinate.step_in_bounds(&direction, 1, &bounds))
+            .map(|neighbour| (neighbour, &self[neighbour]))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    fn index_of(&self, coordinate: &Coordinate) -> Option<usize> {
+        let x = usize::try_from(coordinate.x).ok()?;
+        let y = usize::try_from(coordinate.y).ok()?;
+
+        (x < self.width && y < self.height).then_some(y * self.width + x)
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Mirror the grid along its diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self {
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coordinate::from(c.y, c.x)].clone()
+        })
+    }
+
+    /// Rotate the grid clockwise by 90 degrees.
+    pub fn rotate_right(&self) -> Self {
+        let height = self.height as i32;
+
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coordinate::from(c.y, height - 1 - c.x)].clone()
+        })
+    }
+
+    /// Rotate the grid counterclockwise by 90 degrees.
+    pub fn rotate_left(&self) -> Self {
+        let width = self.width as i32;
+
+        Grid::from_fn(self.height, self.width, |c| {
+            self[Coordinate::from(width - 1 - c.y, c.x)].clone()
+        })
+    }
+}
+
+impl Grid<char> {
+    pub fn parse_chars(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, Ok)
+    }
+}
+
+impl Grid<u32> {
+    pub fn parse_digits(input: &str) -> Result<Self, ParseError> {
+        Grid::parse(input, |ch| {
+            ch.to_digit(10)
+                .ok_or_else(|| ParseError::new(&ch.to_string(), "not a digit"))
+        })
+    }
+}
+
+impl<T> Index<Coordinate> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, coordinate: Coordinate) -> &Self::Output {
+        match self.index_of(&coordinate) {
+            Some(index) => &self.cells[index],
+            None => panic!("{:?} is outside of the grid", coordinate),
+        }
+    }
+}
+
+impl<T> IndexMut<Coordinate> for Grid<T> {
+    fn index_mut(&mut self, coordinate: Coordinate) -> &mut Self::Output {
+        match self.index_of(&coordinate) {
+            Some(index) => &mut self.cells[index],
+            None => panic!("{:?} is outside of the grid", coordinate),
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn coordinate_of(index: usize, width: usize) -> Coordinate {
+    Coordinate::from((index % width) as i32, (index / width) as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const GRID: &str = "\
+abc
+def";
+
+    #[test]
+    fn cells_are_indexed_by_coordinate() {
+        let grid = Grid::parse_chars(GRID).unwrap();
+
+        assert_eq!(3, grid.width());
+        assert_eq!(2, grid.height());
+        assert_eq!('a', grid[Coordinate::from(0, 0)]);
+        assert_eq!('f', grid[Coordinate::from(2, 1)]);
+        assert_eq!(Some(&'d'), grid.get(&Coordinate::from(0, 1)));
+    }
+
+    #[test]
+    fn coordinates_outside_of_the_grid_are_none() {
+        let grid = Grid::parse_chars(GRID).unwrap();
+
+        for (x, y) in [(-1, 0), (0, -1), (3, 0), (0, 2)] {
+            assert_eq!(None, grid.get(&Coordinate::from(x, y)));
+        }
+    }
+
+    #[test]
+    fn digits_are_parsed() {
+        let grid = Grid::parse_digits("12\n34").unwrap();
+
+        assert_eq!(Grid::from_vec(2, vec![1, 2, 3, 4]), grid);
+    }
+
+    #[test]
+    fn lines_of_different_length_are_rejected() {
+        let error = Grid::parse_chars("abc\nde").unwrap_err();
+
+        assert_eq!(Some(2), error.line());
+        assert_eq!("de", error.text());
+    }
+
+    #[test]
+    fn cells_which_are_no_digits_are_rejected() {
+        let error = Grid::parse_digits("12\n3x").unwrap_err();
+
+        assert_eq!(Some(2), error.line());
+        assert_eq!("x", error.text());
+    }
+
+    #[test]
+    fn rows_and_columns_are_iterated() {
+        let grid = Grid::parse_chars(GRID).unwrap();
+
+        assert_eq!(
+            vec![&['a', 'b', 'c'][..], &['d', 'e', 'f']],
+            grid.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec!["ad", "be", "cf"],
+            grid.columns()
+                .map(|column| column.collect::<String>())
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn grid_is_transposed() {
+        let grid = Grid::parse_chars(GRID).unwrap();
+
+        assert_eq!("ad\nbe\ncf", grid.transpose().to_string());
+    }
+
+    #[test]
+    fn grid_is_rotated() {
+        let grid = Grid::parse_chars(GRID).unwrap();
+
+        assert_eq!("da\neb\nfc", grid.rotate_right().to_string());
+        assert_eq!("cf\nbe\nad", grid.rotate_left().to_string());
+        assert_eq!(grid, grid.rotate_right().rotate_left());
+    }
+
+    #[test]
+    fn cells_are_found() {
+        let grid = Grid::parse_chars(GRID).unwrap();
+
+        assert_eq!(Some(Coordinate::from(1, 1)), grid.find(|&ch| ch == 'e'));
+        assert_eq!(None, grid.find(|&ch| ch == 'z'));
+    }
+
+    #[test]
+    fn neighbours_stay_inside_the_grid() {
+        let grid = Grid::parse_chars(GRID).unwrap();
+
+        let mut neighbours: Vec<char> = grid
+            .neighbours(&Coordinate::from(0, 0))
+            .map(|(_, &ch)| ch)
+            .collect();
+        neighbours.sort();
+
+        assert_eq!(vec!['b', 'd'], neighbours);
+        assert_eq!(3, grid.neighbours(&Coordinate::from(1, 1)).count());
+    }
+}
@@ -0,0 +1,254 @@
+//! Hexagonal grids in axial coordinates, stored in a `Coordinate` with `q` as `x` and `r` as `y`.
+//! The third cube coordinate `s` follows from `q + r + s = 0`.
+//! Pointy-top and flat-top hexagons have the same six neighbours, only their names differ.
+
+use super::{Coordinate, Offset};
+
+/// Directions between hexagons with a pointy top, clockwise from east.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum PointyHexDirection {
+    East,
+    SouthEast,
+    SouthWest,
+    West,
+    NorthWest,
+    NorthEast,
+}
+
+/// Directions between hexagons with a flat top, clockwise from north.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum FlatHexDirection {
+    North,
+    NorthEast,
+    SouthEast,
+    South,
+    SouthWest,
+    NorthWest,
+}
+
+const POINTY_CLOCKWISE: [PointyHexDirection; 6] = [
+    PointyHexDirection::East,
+    PointyHexDirection::SouthEast,
+    PointyHexDirection::SouthWest,
+    PointyHexDirection::West,
+    PointyHexDirection::NorthWest,
+    PointyHexDirection::NorthEast,
+];
+
+const FLAT_CLOCKWISE: [FlatHexDirection; 6] = [
+    FlatHexDirection::North,
+    FlatHexDirection::NorthEast,
+    FlatHexDirection::SouthEast,
+    FlatHexDirection::South,
+    FlatHexDirection::SouthWest,
+    FlatHexDirection::NorthWest,
+];
+
+impl PointyHexDirection {
+    /// Parse the abbreviation of a direction like `e` or `nw`.
+    pub fn from(text: &str) -> Self {
+        match text.to_ascii_lowercase().as_str() {
+            "e" => PointyHexDirection::East,
+            "se" => PointyHexDirection::SouthEast,
+            "sw" => PointyHexDirection::SouthWest,
+            "w" => PointyHexDirection::West,
+            "nw" => PointyHexDirection::NorthWest,
+            "ne" => PointyHexDirection::NorthEast,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The direction rotated clockwise by 60 degrees.
+    pub fn right(&self) -> Self {
+        POINTY_CLOCKWISE[(*self as usize + 1) % 6]
+    }
+
+    /// The direction rotated counterclockwise by 60 degrees.
+    pub fn left(&self) -> Self {
+        POINTY_CLOCKWISE[(*self as usize + 5) % 6]
+    }
+
+    pub fn back(&self) -> Self {
+        POINTY_CLOCKWISE[(*self as usize + 3) % 6]
+    }
+
+    pub fn iter() -> impl Iterator<Item = PointyHexDirection> {
+        POINTY_CLOCKWISE.into_iter()
+    }
+}
+
+impl Offset for PointyHexDirection {
+    fn offset(&self) -> (i32, i32) {
+        match self {
+            PointyHexDirection::East => (1, 0),
+            PointyHexDirection::SouthEast => (0, 1),
+            PointyHexDirection::SouthWest => (-1, 1),
+            PointyHexDirection::West => (-1, 0),
+            PointyHexDirection::NorthWest => (0, -1),
+            PointyHexDirection::NorthEast => (1, -1),
+        }
+    }
+}
+
+impl FlatHexDirection {
+    /// Parse the abbreviation of a direction like `n` or `se`.
+    pub fn from(text: &str) -> Self {
+        match text.to_ascii_lowercase().as_str() {
+            "n" => FlatHexDirection::North,
+            "ne" => FlatHexDirection::NorthEast,
+            "se" => FlatHexDirection::SouthEast,
+            "s" => FlatHexDirection::South,
+            "sw" => FlatHexDirection::SouthWest,
+            "nw" => FlatHexDirection::NorthWest,
+            _ => unreachable!(),
+        }
+    }
+
+    /// The direction rotated clockwise by 60 degrees.
+    pub fn right(&self) -> Self {
+        FLAT_CLOCKWISE[(*self as usize + 1) % 6]
+    }
+
+    /// The direction rotated counterclockwise by 60 degrees.
+    pub fn left(&self) -> Self {
+        FLAT_CLOCKWISE[(*self as usize + 5) % 6]
+    }
+
+    pub fn back(&self) -> Self {
+        FLAT_CLOCKWISE[(*self as usize + 3) % 6]
+    }
+
+    pub fn iter() -> impl Iterator<Item = FlatHexDirection> {
+        FLAT_CLOCKWISE.into_iter()
+    }
+}
+
+impl Offset for FlatHexDirection {
+    fn offset(&self) -> (i32, i32) {
+        match self {
+            FlatHexDirection::North => (0, -1),
+            FlatHexDirection::NorthEast => (1, -1),
+            FlatHexDirection::SouthEast => (1, 0),
+            FlatHexDirection::South => (0, 1),
+            FlatHexDirection::SouthWest => (-1, 1),
+            FlatHexDirection::NorthWest => (-1, 0),
+        }
+    }
+}
+
+impl Coordinate {
+    /// Axial coordinate from cube coordinates.
+    ///
+    /// Panics if `q + r + s` is not zero.
+    pub fn from_cube(q: i32, r: i32, s: i32) -> Self {
+        assert_eq!(
+            0,
+            q + r + s,
+            "({}, {}, {}) is not a cube coordinate",
+            q,
+            r,
+            s
+        );
+
+        Coordinate::from(q, r)
+    }
+
+    /// The cube coordinates `(q, r, s)` of an axial coordinate.
+    pub fn cube(&self) -> (i32, i32, i32) {
+        (self.x, self.y, -self.x - self.y)
+    }
+
+    /// The number of steps between two hexagons.
+    pub fn hex_distance(&self, other: &Coordinate) -> u32 {
+        let (q, r, s) = self.cube();
+        let (other_q, other_r, other_s) = other.cube();
+
+        q.abs_diff(other_q)
+            .max(r.abs_diff(other_r))
+            .max(s.abs_diff(other_s))
+    }
+
+    /// The six hexagons around this one.
+    pub fn get_adjacent_hex(&self) -> Vec<Coordinate> {
+        PointyHexDirection::iter()
+            .map(|direction| self.step(&direction, 1))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::Bounds;
+
+    fn walk(path: &str) -> Coordinate {
+        path.split(',')
+            .map(FlatHexDirection::from)
+            .fold(Coordinate::default(), |coordinate, direction| {
+                coordinate.step(&direction, 1)
+            })
+    }
+
+    #[test]
+    fn hex_distances_are_counted_in_steps() {
+        for (expected, path) in [
+            (3, "ne,ne,ne"),
+            (0, "ne,ne,sw,sw"),
+            (2, "ne,ne,s,s"),
+            (3, "se,sw,se,sw,sw"),
+        ] {
+            assert_eq!(expected, walk(path).hex_distance(&Coordinate::default()));
+        }
+    }
+
+    #[test]
+    fn hex_directions_rotate_by_60_degrees() {
+        assert_eq!(
+            PointyHexDirection::SouthEast,
+            PointyHexDirection::East.right()
+        );
+        assert_eq!(
+            PointyHexDirection::NorthEast,
+            PointyHexDirection::East.left()
+        );
+        assert_eq!(FlatHexDirection::South, FlatHexDirection::North.back());
+
+        // walking a hexagon ends where it started
+        let mut direction = FlatHexDirection::NorthEast;
+        let mut coordinate = Coordinate::from(4, 2);
+        for _ in 0..6 {
+            coordinate = coordinate.step(&direction, 3);
+            direction = direction.right();
+        }
+        assert_eq!(Coordinate::from(4, 2), coordinate);
+    }
+
+    #[test]
+    fn neighbours_are_the_same_for_both_orientations() {
+        let center = Coordinate::from_cube(1, -2, 1);
+        let mut flat: Vec<Coordinate> = FlatHexDirection::iter()
+            .map(|direction| center.step(&direction, 1))
+            .collect();
+        let mut pointy = center.get_adjacent_hex();
+        flat.sort_by_key(|c| (c.x, c.y));
+        pointy.sort_by_key(|c| (c.x, c.y));
+
+        assert_eq!(flat, pointy);
+        assert!(pointy.iter().all(|c| c.hex_distance(&center) == 1));
+    }
+
+    #[test]
+    fn hex_steps_respect_bounds() {
+        let bounds = Bounds::from(0..2, 0..2);
+        let coordinate = Coordinate::from(0, 1);
+
+        assert_eq!(
+            Some(Coordinate::from(1, 0)),
+            coordinate.step_in_bounds(&PointyHexDirection::NorthEast, 1, &bounds)
+        );
+        assert_eq!(
+            None,
+            coordinate.step_in_bounds(&PointyHexDirection::SouthWest, 1, &bounds)
+        );
+    }
+}
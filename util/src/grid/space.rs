@@ -0,0 +1,387 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Range, Sub, SubAssign};
+use std::str::FromStr;
+
+use num_traits::{PrimInt, Signed};
+
+use super::Coordinate;
+use crate::parse::{self, ParseError};
+use crate::search;
+
+/// Something which points from a coordinate in space to another one.
+pub trait Offset3 {
+    fn offset(&self) -> (i32, i32, i32);
+}
+
+/// The six directions along the axes.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Direction3 {
+    PositiveX,
+    NegativeX,
+    PositiveY,
+    NegativeY,
+    PositiveZ,
+    NegativeZ,
+}
+
+impl Direction3 {
+    pub fn back(&self) -> Self {
+        match self {
+            Direction3::PositiveX => Direction3::NegativeX,
+            Direction3::NegativeX => Direction3::PositiveX,
+            Direction3::PositiveY => Direction3::NegativeY,
+            Direction3::NegativeY => Direction3::PositiveY,
+            Direction3::PositiveZ => Direction3::NegativeZ,
+            Direction3::NegativeZ => Direction3::PositiveZ,
+        }
+    }
+
+    pub fn iter() -> impl Iterator<Item = Direction3> {
+        [
+            Direction3::PositiveX,
+            Direction3::NegativeX,
+            Direction3::PositiveY,
+            Direction3::NegativeY,
+            Direction3::PositiveZ,
+            Direction3::NegativeZ,
+        ]
+        .into_iter()
+    }
+}
+
+impl Offset3 for Direction3 {
+    fn offset(&self) -> (i32, i32, i32) {
+        match self {
+            Direction3::PositiveX => (1, 0, 0),
+            Direction3::NegativeX => (-1, 0, 0),
+            Direction3::PositiveY => (0, 1, 0),
+            Direction3::NegativeY => (0, -1, 0),
+            Direction3::PositiveZ => (0, 0, 1),
+            Direction3::NegativeZ => (0, 0, -1),
+        }
+    }
+}
+
+/// Three dimensional coordinate, which is also used as a vector between coordinates.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Coordinate3<T = i32> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: PrimInt + Signed> Coordinate3<T> {
+    pub fn from(x: T, y: T, z: T) -> Self {
+        Coordinate3 { x, y, z }
+    }
+
+    /// Parse a coordinate written as `x,y,z`.
+    pub fn parse(text: &str) -> Result<Self, ParseError>
+    where
+        T: FromStr,
+        T::Err: fmt::Display,
+    {
+        let values: Vec<T> = text
+            .split(',')
+            .map(|value| parse::field(value.trim()))
+            .collect::<Result<_, _>>()?;
+
+        match values[..] {
+            [x, y, z] => Ok(Coordinate3::from(x, y, z)),
+            _ => Err(ParseError::new(text, "expected 3 numbers")),
+        }
+    }
+
+    pub fn manhatten_distance(&self, other: &Coordinate3<T>) -> T {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    /// The coordinate projected onto the `x`-`y` plane.
+    pub fn xy(&self) -> Coordinate<T> {
+        Coordinate::from(self.x, self.y)
+    }
+
+    pub fn step(&self, direction: &impl Offset3, steps: u32) -> Self {
+        let (dx, dy, dz) = direction.offset();
+        let offset = Coordinate3::from(
+            T::from(dx).unwrap(),
+            T::from(dy).unwrap(),
+            T::from(dz).unwrap(),
+        );
+
+        *self + offset * T::from(steps).unwrap()
+    }
+
+    /// The 6 coordinates which share a face with this one.
+    pub fn get_adjacent(&self) -> Vec<Self> {
+        Direction3::iter()
+            .map(|direction| self.step(&direction, 1))
+            .collect()
+    }
+
+    /// The 26 coordinates which share a face, an edge or a corner with this one.
+    pub fn get_adjacent_with_diag(&self) -> Vec<Self> {
+        let units = [-T::one(), T::zero(), T::one()];
+
+        units
+            .into_iter()
+            .flat_map(|dx| {
+                units
+                    .into_iter()
+                    .flat_map(move |dy| units.map(|dz| (dx, dy, dz)))
+            })
+            .filter(|&(dx, dy, dz)| !(dx.is_zero() && dy.is_zero() && dz.is_zero()))
+            .map(|(dx, dy, dz)| *self + Coordinate3::from(dx, dy, dz))
+            .collect()
+    }
+}
+
+impl<T: PrimInt + Signed> Add for Coordinate3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Coordinate3::from(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: PrimInt + Signed> AddAssign for Coordinate3<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<T: PrimInt + Signed> Sub for Coordinate3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Coordinate3::from(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: PrimInt + Signed> SubAssign for Coordinate3<T> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<T: PrimInt + Signed> Mul<T> for Coordinate3<T> {
+    type Output = Self;
+
+    fn mul(self, factor: T) -> Self::Output {
+        Coordinate3::from(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl<T: PrimInt + Signed> Neg for Coordinate3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Coordinate3::from(-self.x, -self.y, -self.z)
+    }
+}
+
+impl<T> From<(T, T, T)> for Coordinate3<T> {
+    fn from((x, y, z): (T, T, T)) -> Self {
+        Coordinate3 { x, y, z }
+    }
+}
+
+impl<T> From<Coordinate3<T>> for (T, T, T) {
+    fn from(coordinate: Coordinate3<T>) -> Self {
+        (coordinate.x, coordinate.y, coordinate.z)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bounds3 {
+    x_values: Range<i32>,
+    y_values: Range<i32>,
+    z_values: Range<i32>,
+}
+
+impl Bounds3 {
+    pub fn from(x_values: Range<i32>, y_values: Range<i32>, z_values: Range<i32>) -> Self {
+        Bounds3 {
+            x_values,
+            y_values,
+            z_values,
+        }
+    }
+
+    /// The smallest bounds which contain all coordinates.
+    /// Without any coordinates the bounds are empty.
+    pub fn enclosing<'a>(coordinates: impl IntoIterator<Item = &'a Coordinate3>) -> Self {
+        let mut coordinates = coordinates.into_iter();
+        let Some(first) = coordinates.next() else {
+            return Bounds3::from(0..0, 0..0, 0..0);
+        };
+
+        coordinates.fold(
+            Bounds3::from(
+                first.x..first.x + 1,
+                first.y..first.y + 1,
+                first.z..first.z + 1,
+            ),
+            |bounds, c| {
+                Bounds3::from(
+                    bounds.x_values.start.min(c.x)..bounds.x_values.end.max(c.x + 1),
+                    bounds.y_values.start.min(c.y)..bounds.y_values.end.max(c.y + 1),
+                    bounds.z_values.start.min(c.z)..bounds.z_values.end.max(c.z + 1),
+                )
+            },
+        )
+    }
+
+    /// The bounds extended by `margin` on every side.
+    pub fn grow(&self, margin: i32) -> Self {
+        Bounds3::from(
+            self.x_values.start - margin..self.x_values.end + margin,
+            self.y_values.start - margin..self.y_values.end + margin,
+            self.z_values.start - margin..self.z_values.end + margin,
+        )
+    }
+
+    pub fn contains(&self, coordinate: &Coordinate3) -> bool {
+        self.x_values.contains(&coordinate.x)
+            && self.y_values.contains(&coordinate.y)
+            && self.z_values.contains(&coordinate.z)
+    }
+
+    /// The corner with the smallest values.
+    pub fn min(&self) -> Coordinate3 {
+        Coordinate3::from(
+            self.x_values.start,
+            self.y_values.start,
+            self.z_values.start,
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.x_values.start.abs_diff(self.x_values.end) as usize
+    }
+
+    pub fn height(&self) -> usize {
+        self.y_values.start.abs_diff(self.y_values.end) as usize
+    }
+
+    pub fn depth(&self) -> usize {
+        self.z_values.start.abs_diff(self.z_values.end) as usize
+    }
+
+    pub fn coordinates(&self) -> impl Iterator<Item = Coordinate3> + '_ {
+        self.z_values.clone().flat_map(move |z| {
+            self.y_values.clone().flat_map(move |y| {
+                self.x_values
+                    .clone()
+                    .map(move |x| Coordinate3::from(x, y, z))
+            })
+        })
+    }
+
+    /// All coordinates inside the bounds which can be reached from `start` by moving to
+    /// adjacent coordinates which are open.
+    pub fn flood_fill(
+        &self,
+        start: Coordinate3,
+        mut is_open: impl FnMut(&Coordinate3) -> bool,
+    ) -> HashSet<Coordinate3> {
+        if !self.contains(&start) || !is_open(&start) {
+            return HashSet::new();
+        }
+
+        search::distances([start], |coordinate| {
+            coordinate
+                .get_adjacent()
+                .into_iter()
+                .filter(|next| self.contains(next) && is_open(next))
+                .collect::<Vec<_>>()
+        })
+        .into_keys()
+        .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn coordinates_have_6_and_26_neighbours() {
+        let center = Coordinate3::from(1, 2, 3);
+
+        assert_eq!(6, center.get_adjacent().len());
+        assert!(center
+            .get_adjacent()
+            .iter()
+            .all(|neighbour| center.manhatten_distance(neighbour) == 1));
+
+        let all: HashSet<Coordinate3> = center.get_adjacent_with_diag().into_iter().collect();
+        assert_eq!(26, all.len());
+        assert!(!all.contains(&center));
+        assert!(all.contains(&Coordinate3::from(0, 1, 2)));
+    }
+
+    #[test]
+    fn coordinates_are_added_like_vectors() {
+        let mut coordinate = Coordinate3::from(1, 2, 3);
+        coordinate += Coordinate3::from(1, -1, 0) * 2;
+
+        assert_eq!(Coordinate3::from(3, 0, 3), coordinate);
+        assert_eq!(
+            Coordinate3::from(-2, -2, -3),
+            -coordinate + Coordinate3::from(1, -2, 0)
+        );
+        assert_eq!(
+            Coordinate3::from(3, 5, 3),
+            coordinate.step(&Direction3::PositiveY, 5)
+        );
+        assert_eq!((3, 0, 3), coordinate.into());
+    }
+
+    #[test]
+    fn distances_work_for_wide_integers() {
+        let far: Coordinate3<i64> = Coordinate3::from(1 << 40, 0, -(1 << 40));
+
+        assert_eq!(1 << 41, far.manhatten_distance(&Coordinate3::default()));
+        assert_eq!(26, far.get_adjacent_with_diag().len());
+    }
+
+    #[test]
+    fn coordinates_are_parsed() {
+        assert_eq!(
+            Ok(Coordinate3::from(2, -1, 5)),
+            Coordinate3::parse("2,-1,5")
+        );
+        assert!(Coordinate3::<i32>::parse("2,-1").is_err());
+        assert!(Coordinate3::<i32>::parse("2,x,5").is_err());
+        assert_eq!(
+            6,
+            Coordinate3::from(1, 1, 1).manhatten_distance(&Coordinate3::from(-1, 2, 4))
+        );
+    }
+
+    #[test]
+    fn bounds_enclose_coordinates() {
+        let coordinates = [Coordinate3::from(1, 5, -2), Coordinate3::from(3, 2, 0)];
+        let bounds = Bounds3::enclosing(&coordinates);
+
+        assert_eq!(Bounds3::from(1..4, 2..6, -2..1), bounds);
+        assert_eq!((3, 4, 3), (bounds.width(), bounds.height(), bounds.depth()));
+        assert_eq!(36, bounds.coordinates().count());
+        assert!(bounds.grow(1).contains(&Coordinate3::from(0, 1, 1)));
+    }
+
+    #[test]
+    fn flood_fill_stays_inside_bounds_and_walls() {
+        // a wall at x = 1 splits the bounds in two halves
+        let bounds = Bounds3::from(0..3, 0..2, 0..2);
+        let filled = bounds.flood_fill(Coordinate3::from(0, 0, 0), |c| c.x != 1);
+
+        assert_eq!(4, filled.len());
+        assert!(filled.iter().all(|c| c.x == 0));
+        assert!(bounds
+            .flood_fill(Coordinate3::from(1, 0, 0), |c| c.x != 1)
+            .is_empty());
+    }
+}
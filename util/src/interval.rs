@@ -0,0 +1,263 @@
+use std::ops::Range;
+
+use num_traits::PrimInt;
+
+/// Set of integers stored as sorted, disjoint half-open ranges.
+/// Ranges which overlap or touch are merged.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct IntervalSet<T> {
+    ranges: Vec<Range<T>>,
+}
+
+impl<T: PrimInt> IntervalSet<T> {
+    pub fn new() -> Self {
+        IntervalSet { ranges: Vec::new() }
+    }
+
+    pub fn ranges(&self) -> impl Iterator<Item = &Range<T>> {
+        self.ranges.iter()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn min(&self) -> Option<T> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<T> {
+        self.ranges.last().map(|range| range.end - T::one())
+    }
+
+    /// The number of values in the set.
+    pub fn total_length(&self) -> T {
+        self.ranges.iter().fold(T::zero(), |length, range| {
+            length + (range.end - range.start)
+        })
+    }
+
+    pub fn contains(&self, value: T) -> bool {
+        let index = self.ranges.partition_point(|range| range.end <= value);
+
+        self.ranges
+            .get(index)
+            .is_some_and(|range| range.start <= value)
+    }
+
+    pub fn insert(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // every range which overlaps or touches the new one is merged into it
+        let first = self.ranges.partition_point(|other| other.end < range.start);
+        let last = self
+            .ranges
+            .partition_point(|other| other.start <= range.end);
+
+        let mut merged = range;
+        if first < last {
+            merged.start = merged.start.min(self.ranges[first].start);
+            merged.end = merged.end.max(self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, [merged]);
+    }
+
+    pub fn remove(&mut self, range: Range<T>) {
+        if range.is_empty() {
+            return;
+        }
+
+        let first = self
+            .ranges
+            .partition_point(|other| other.end <= range.start);
+        let last = self.ranges.partition_point(|other| other.start < range.end);
+        if first == last {
+            return;
+        }
+
+        // the overlapping ranges may stick out on both sides
+        let mut rest = Vec::new();
+        if self.ranges[first].start < range.start {
+            rest.push(self.ranges[first].start..range.start);
+        }
+        if self.ranges[last - 1].end > range.end {
+            rest.push(range.end..self.ranges[last - 1].end);
+        }
+        self.ranges.splice(first..last, rest);
+    }
+
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        for range in other.ranges() {
+            union.insert(range.clone());
+        }
+
+        union
+    }
+
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.ranges.len() && j < other.ranges.len() {
+            let (a, b) = (&self.ranges[i], &other.ranges[j]);
+            let start = a.start.max(b.start);
+            let end = a.end.min(b.end);
+            if start < end {
+                intersection.push(start..end);
+            }
+
+            // the range ending first can not overlap with any later one
+            match a.end < b.end {
+                true => i += 1,
+                false => j += 1,
+            }
+        }
+
+        IntervalSet {
+            ranges: intersection,
+        }
+    }
+
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.ranges() {
+            difference.remove(range.clone());
+        }
+
+        difference
+    }
+
+    /// The values below `at` and the ones from `at` on.
+    pub fn split_at(&self, at: T) -> (Self, Self) {
+        let mut below = Vec::new();
+        let mut above = Vec::new();
+
+        for range in self.ranges() {
+            if range.end <= at {
+                below.push(range.clone());
+            } else if range.start >= at {
+                above.push(range.clone());
+            } else {
+                below.push(range.start..at);
+                above.push(at..range.end);
+            }
+        }
+
+        (IntervalSet { ranges: below }, IntervalSet { ranges: above })
+    }
+
+    /// Move every value by the distance from `from` to `to`.
+    /// Unlike adding an offset this also moves unsigned values downwards.
+    pub fn shift(&self, from: T, to: T) -> Self {
+        let shift = |value: T| match to >= from {
+            true => value + (to - from),
+            false => value - (from - to),
+        };
+
+        IntervalSet {
+            ranges: self
+                .ranges()
+                .map(|range| shift(range.start)..shift(range.end))
+                .collect(),
+        }
+    }
+
+    /// The smallest value from `from` on which is not in the set.
+    pub fn first_gap(&self, from: T) -> T {
+        let index = self.ranges.partition_point(|range| range.end <= from);
+
+        match self.ranges.get(index) {
+            Some(range) if range.start <= from => range.end,
+            _ => from,
+        }
+    }
+}
+
+impl<T: PrimInt> From<Range<T>> for IntervalSet<T> {
+    fn from(range: Range<T>) -> Self {
+        let mut set = IntervalSet::new();
+        set.insert(range);
+
+        set
+    }
+}
+
+impl<T: PrimInt> FromIterator<Range<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Range<T>>>(iter: I) -> Self {
+        let mut set = IntervalSet::new();
+        for range in iter {
+            set.insert(range);
+        }
+
+        set
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<Range<i32>> {
+        set.ranges().cloned().collect()
+    }
+
+    #[test]
+    fn overlapping_and_touching_ranges_are_merged() {
+        let set: IntervalSet<i32> = [5..8, 0..2, 1..3, 3..4, 10..10].into_iter().collect();
+
+        assert_eq!(vec![0..4, 5..8], ranges(&set));
+        assert_eq!(7, set.total_length());
+    }
+
+    #[test]
+    fn removing_splits_ranges() {
+        let mut set = IntervalSet::from(0..10);
+        set.remove(3..5);
+        set.remove(8..20);
+
+        assert_eq!(vec![0..3, 5..8], ranges(&set));
+        assert!(set.contains(2));
+        assert!(!set.contains(3));
+        assert!(set.contains(5));
+        assert!(!set.contains(8));
+    }
+
+    #[test]
+    fn sets_are_combined() {
+        let a: IntervalSet<i32> = [0..5, 10..15].into_iter().collect();
+        let b = IntervalSet::from(3..12);
+
+        assert_eq!(vec![0..15], ranges(&a.union(&b)));
+        assert_eq!(vec![3..5, 10..12], ranges(&a.intersection(&b)));
+        assert_eq!(vec![0..3, 12..15], ranges(&a.difference(&b)));
+    }
+
+    #[test]
+    fn sets_are_split() {
+        let set: IntervalSet<i32> = [0..5, 10..15].into_iter().collect();
+        let (below, above) = set.split_at(12);
+
+        assert_eq!(vec![0..5, 10..12], ranges(&below));
+        assert_eq!(vec![12..15], ranges(&above));
+    }
+
+    #[test]
+    fn unsigned_sets_are_shifted_in_both_directions() {
+        let set = IntervalSet::from(10_u32..15);
+
+        assert_eq!(Some(2), set.shift(10, 2).min());
+        assert_eq!(Some(22), set.shift(2, 10).max());
+    }
+
+    #[test]
+    fn first_gap_is_found() {
+        let set: IntervalSet<i32> = [0..3, 4..6].into_iter().collect();
+
+        assert_eq!(3, set.first_gap(0));
+        assert_eq!(6, set.first_gap(4));
+        assert_eq!(10, set.first_gap(10));
+    }
+}
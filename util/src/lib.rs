@@ -1,5 +1,14 @@
+pub mod answers;
+pub mod cycle;
 pub mod dlin;
+pub mod geometry;
 pub mod grid;
+pub mod interval;
+pub mod ocr;
+pub mod parse;
+pub mod search;
+pub mod solution;
+pub mod year;
 
 use num_traits::PrimInt;
 
@@ -18,7 +27,7 @@ pub fn median(arr: &[i32]) -> f64 {
 
     let mid = sorted.len() / 2;
 
-    if sorted.len() % 2 == 0 {
+    if sorted.len().is_multiple_of(2) {
         mean(&sorted[(mid - 1)..(mid + 1)])
     } else {
         sorted[mid] as f64
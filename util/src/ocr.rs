@@ -0,0 +1,257 @@
+//! Reading the letters some puzzles draw with lit pixels.
+//! The letters are spaced evenly, but they don't have to start at `(0, 0)`.
+
+use std::collections::HashSet;
+
+use crate::grid::{Coordinate, Grid};
+
+struct Font {
+    height: i32,
+    /// Distance between the left edges of two letters, a glyph may be as wide as this.
+    pitch: i32,
+    glyphs: &'static [(char, &'static str)],
+}
+
+/// Letters 4 pixels wide and 6 high, as used by most puzzles. `Y` is 5 pixels wide.
+const SMALL: Font = Font {
+    height: 6,
+    pitch: 5,
+    glyphs: &[
+        ('A', ".##.\n#..#\n#..#\n####\n#..#\n#..#"),
+        ('B', "###.\n#..#\n###.\n#..#\n#..#\n###."),
+        ('C', ".##.\n#..#\n#...\n#...\n#..#\n.##."),
+        ('E', "####\n#...\n###.\n#...\n#...\n####"),
+        ('F', "####\n#...\n###.\n#...\n#...\n#..."),
+        ('G', ".##.\n#..#\n#...\n#.##\n#..#\n.###"),
+        ('H', "#..#\n#..#\n####\n#..#\n#..#\n#..#"),
+        ('I', ".###\n..#.\n..#.\n..#.\n..#.\n.###"),
+        ('J', "..##\n...#\n...#\n...#\n#..#\n.##."),
+        ('K', "#..#\n#.#.\n##..\n#.#.\n#.#.\n#..#"),
+        ('L', "#...\n#...\n#...\n#...\n#...\n####"),
+        ('O', ".##.\n#..#\n#..#\n#..#\n#..#\n.##."),
+        ('P', "###.\n#..#\n#..#\n###.\n#...\n#..."),
+        ('R', "###.\n#..#\n#..#\n###.\n#.#.\n#..#"),
+        ('S', ".###\n#...\n#...\n.##.\n...#\n###."),
+        ('U', "#..#\n#..#\n#..#\n#..#\n#..#\n.##."),
+        ('Y', "#...#\n#...#\n.#.#.\n..#..\n..#..\n..#.."),
+        ('Z', "####\n...#\n..#.\n.#..\n#...\n####"),
+    ],
+};
+
+/// Letters 6 pixels wide and 10 high.
+const LARGE: Font = Font {
+    height: 10,
+    pitch: 8,
+    glyphs: &[
+        (
+            'A',
+            "..##..\n.#..#.\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#",
+        ),
+        (
+            'B',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#....#\n#....#\n#....#\n#....#\n#####.",
+        ),
+        (
+            'C',
+            ".####.\n#....#\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#....#\n.####.",
+        ),
+        (
+            'E',
+            "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n######",
+        ),
+        (
+            'F',
+            "######\n#.....\n#.....\n#.....\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+        ),
+        (
+            'G',
+            ".####.\n#....#\n#.....\n#.....\n#.....\n#..###\n#....#\n#....#\n#...##\n.###.#",
+        ),
+        (
+            'H',
+            "#....#\n#....#\n#....#\n#....#\n######\n#....#\n#....#\n#....#\n#....#\n#....#",
+        ),
+        (
+            'J',
+            "...###\n....#.\n....#.\n....#.\n....#.\n....#.\n....#.\n#...#.\n#...#.\n.###..",
+        ),
+        (
+            'K',
+            "#....#\n#...#.\n#..#..\n#.#...\n##....\n##....\n#.#...\n#..#..\n#...#.\n#....#",
+        ),
+        (
+            'L',
+            "#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n#.....\n######",
+        ),
+        (
+            'N',
+            "#....#\n##...#\n##...#\n#.#..#\n#.#..#\n#..#.#\n#..#.#\n#...##\n#...##\n#....#",
+        ),
+        (
+            'P',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#.....\n#.....\n#.....\n#.....\n#.....",
+        ),
+        (
+            'R',
+            "#####.\n#....#\n#....#\n#....#\n#####.\n#..#..\n#...#.\n#...#.\n#....#\n#....#",
+        ),
+        (
+            'X',
+            "#....#\n#....#\n.#..#.\n.#..#.\n..##..\n..##..\n.#..#.\n.#..#.\n#....#\n#....#",
+        ),
+        (
+            'Z',
+            "######\n.....#\n.....#\n....#.\n...#..\n..#...\n.#....\n#.....\n#.....\n######",
+        ),
+    ],
+};
+
+impl Font {
+    /// The letter whose top left corner is at `corner`, if its glyph is known.
+    /// The columns between the glyph and the next letter have to be dark.
+    fn letter(&self, lit: &HashSet<Coordinate>, corner: Coordinate) -> Option<char> {
+        let pattern = (0..self.height)
+            .map(|y| {
+                (0..self.pitch)
+                    .map(|x| match lit.contains(&(corner + Coordinate::from(x, y))) {
+                        true => '#',
+                        false => '.',
+                    })
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>();
+
+        self.glyphs
+            .iter()
+            .find(|(_, glyph)| {
+                glyph.lines().zip(&pattern).all(|(row, pixels)| {
+                    let (inside, gap) = pixels.split_at(row.len());
+                    row == inside && !gap.contains('#')
+                })
+            })
+            .map(|&(letter, _)| letter)
+    }
+
+    /// The letters whose left edges are `pitch` apart, starting with `corner`.
+    fn text(&self, lit: &HashSet<Coordinate>, corner: Coordinate, right: i32) -> Option<String> {
+        (corner.x..=right)
+            .step_by(self.pitch as usize)
+            .map(|left| self.letter(lit, Coordinate::from(left, corner.y)))
+            .collect()
+    }
+}
+
+/// The letters drawn by the lit cells of the grid.
+/// `None` if the letters are in neither font or contain an unknown glyph.
+pub fn recognize(grid: &Grid<bool>) -> Option<String> {
+    recognize_points(grid.iter().filter(|(_, &lit)| lit).map(|(c, _)| c))
+}
+
+/// The letters drawn by the lit points.
+/// `None` if the letters are in neither font or contain an unknown glyph.
+pub fn recognize_points(points: impl IntoIterator<Item = Coordinate>) -> Option<String> {
+    let lit: HashSet<Coordinate> = points.into_iter().collect();
+    let left = lit.iter().map(|c| c.x).min()?;
+    let right = lit.iter().map(|c| c.x).max()?;
+    let top = lit.iter().map(|c| c.y).min()?;
+    let bottom = lit.iter().map(|c| c.y).max()?;
+
+    let font = [SMALL, LARGE]
+        .into_iter()
+        .find(|font| bottom - top < font.height)?;
+
+    // every glyph has lit pixels in its top row, but some start with dark columns
+    (0..font.pitch).find_map(|margin| font.text(&lit, Coordinate::from(left - margin, top), right))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Draw the letters the way puzzles do.
+    fn render(font: &Font, text: &str) -> Vec<Coordinate> {
+        text.chars()
+            .enumerate()
+            .flat_map(|(i, letter)| {
+                let (_, glyph) = font.glyphs.iter().find(|(l, _)| *l == letter).unwrap();
+                let left = i as i32 * font.pitch;
+
+                glyph.lines().enumerate().flat_map(move |(y, row)| {
+                    row.char_indices()
+                        .filter(|&(_, pixel)| pixel == '#')
+                        .map(move |(x, _)| Coordinate::from(left + x as i32, y as i32))
+                })
+            })
+            .collect()
+    }
+
+    #[test]
+    fn glyphs_have_the_size_of_their_font() {
+        for font in [SMALL, LARGE] {
+            for (letter, glyph) in font.glyphs {
+                let rows: Vec<&str> = glyph.lines().collect();
+
+                assert_eq!(font.height as usize, rows.len(), "{}", letter);
+                assert!(rows.iter().all(|row| row.len() == rows[0].len()));
+                assert!(rows[0].len() <= font.pitch as usize, "{}", letter);
+            }
+        }
+    }
+
+    #[test]
+    fn small_letters_are_recognized() {
+        let text = "ABCEFGHIJKLOPRSUYZ";
+
+        assert_eq!(
+            Some(text.to_string()),
+            recognize_points(render(&SMALL, text))
+        );
+    }
+
+    #[test]
+    fn large_letters_are_recognized() {
+        let text = "ABCEFGHJKLNPRXZ";
+
+        assert_eq!(
+            Some(text.to_string()),
+            recognize_points(render(&LARGE, text))
+        );
+    }
+
+    #[test]
+    fn wide_letters_fill_the_gap() {
+        assert_eq!(
+            Some("YAY".to_string()),
+            recognize_points(render(&SMALL, "YAY"))
+        );
+    }
+
+    #[test]
+    fn letters_away_from_the_origin_are_recognized() {
+        for (offset, text) in [((3, 2), "HEY"), ((-7, 4), "JIL"), ((10, -5), "IS")] {
+            let offset = Coordinate::from(offset.0, offset.1);
+            let points = render(&SMALL, text).into_iter().map(|c| c + offset);
+
+            assert_eq!(Some(text.to_string()), recognize_points(points));
+        }
+        let points = render(&LARGE, "JAN")
+            .into_iter()
+            .map(|c| c + Coordinate::from(4, 9));
+        assert_eq!(Some("JAN".to_string()), recognize_points(points));
+    }
+
+    #[test]
+    fn letters_are_read_from_a_grid() {
+        let lit: HashSet<Coordinate> = render(&SMALL, "HI").into_iter().collect();
+        let grid = Grid::from_fn(12, 6, |c| lit.contains(&c));
+
+        assert_eq!(Some("HI".to_string()), recognize(&grid));
+    }
+
+    #[test]
+    fn unknown_pictures_are_none() {
+        assert_eq!(None, recognize(&Grid::new(10, 6, false)));
+        assert_eq!(None, recognize_points([Coordinate::from(1, 1)]));
+        assert_eq!(None, recognize_points([Coordinate::from(0, 11)]));
+    }
+}
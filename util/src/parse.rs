@@ -0,0 +1,279 @@
+//! Small helpers for reading puzzle inputs without chains of `unwrap()`.
+//! Failures are returned as a `ParseError`, which names the line and the text that could not
+//! be parsed.
+
+use std::{error, fmt, str::FromStr};
+
+/// Text which could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Number of the line starting at 1, if the text was part of a line by line input.
+    line: Option<usize>,
+    text: String,
+    reason: String,
+}
+
+impl ParseError {
+    pub fn new(text: &str, reason: impl fmt::Display) -> Self {
+        ParseError {
+            line: None,
+            text: text.to_string(),
+            reason: reason.to_string(),
+        }
+    }
+
+    /// The error with the number of the line the text is in, unless it already has one.
+    pub fn at_line(self, line: usize) -> Self {
+        ParseError {
+            line: self.line.or(Some(line)),
+            ..self
+        }
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(line) = self.line {
+            write!(f, "line {}: ", line)?;
+        }
+
+        write!(f, "'{}': {}", self.text, self.reason)
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parse the text as a value of type `T`.
+pub fn field<T>(text: &str) -> Result<T, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    text.parse().map_err(|e| ParseError::new(text, e))
+}
+
+/// Parse every line as a value of type `T`.
+pub fn parse_lines<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    map_lines(input, field)
+}
+
+/// Convert every line with `f`. Errors get the number of the line they occurred in.
+pub fn map_lines<T>(
+    input: &str,
+    mut f: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, line)| f(line).map_err(|e| e.at_line(index + 1)))
+        .collect()
+}
+
+/// All integers in the text, ignoring everything else.
+/// A `-` is a sign only if it does not follow a letter or digit, so `2-4` are two positive
+/// numbers while `x=-3` is negative.
+pub fn integers<T>(text: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: fmt::Display,
+{
+    let bytes = text.as_bytes();
+    let mut integers = Vec::new();
+    let mut index = 0;
+
+    while index < bytes.len() {
+        let is_sign = bytes[index] == b'-'
+            && bytes.get(index + 1).is_some_and(u8::is_ascii_digit)
+            && (index == 0 || !bytes[index - 1].is_ascii_alphanumeric());
+        if !is_sign && !bytes[index].is_ascii_digit() {
+            index += 1;
+            continue;
+        }
+
+        let start = index;
+        index += 1;
+        while index < bytes.len() && bytes[index].is_ascii_digit() {
+            index += 1;
+        }
+        integers.push(field(&text[start..index])?);
+    }
+
+    Ok(integers)
+}
+
+/// The parts of the input which are separated by blank lines.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut start = None;
+    let mut end = 0;
+    let mut offset = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = start.take() {
+                sections.push(&input[start..end]);
+            }
+        } else {
+            start.get_or_insert(offset);
+            end = offset + line.trim_end_matches(['\r', '\n']).len();
+        }
+        offset += line.len();
+    }
+    if let Some(start) = start {
+        sections.push(&input[start..end]);
+    }
+
+    sections
+}
+
+/// Split the text into the parts matching the `{}` placeholders of the pattern.
+/// All other parts of the pattern have to appear literally. A placeholder ends at the first
+/// appearance of the literal following it. Used by `scan!`.
+pub fn scan_fields<'a>(text: &'a str, pattern: &str) -> Result<Vec<&'a str>, ParseError> {
+    let mismatch = || ParseError::new(text, format!("does not match \"{}\"", pattern));
+    let mut literals = pattern.split("{}");
+    let prefix = literals.next().unwrap_or_default();
+
+    let mut rest = text.strip_prefix(prefix).ok_or_else(mismatch)?;
+    let mut fields = Vec::new();
+    for literal in literals {
+        let end = match literal.is_empty() {
+            true => rest.len(),
+            false => rest.find(literal).ok_or_else(mismatch)?,
+        };
+        fields.push(&rest[..end]);
+        rest = &rest[end + literal.len()..];
+    }
+
+    match rest.is_empty() {
+        true => Ok(fields),
+        false => Err(mismatch()),
+    }
+}
+
+/// Parse the text by a pattern with `{}` placeholders into a tuple with one value per type,
+/// e.g. `scan!("move 3 from 1 to 2", "move {} from {} to {}", u32, usize, usize)`.
+/// Returns a `Result` with a `ParseError` if the text does not match, a value is invalid or
+/// the number of placeholders differs from the number of types.
+#[macro_export]
+macro_rules! scan {
+    ($text:expr, $pattern:expr, $($t:ty),+ $(,)?) => {
+        $crate::parse::scan_counted($text, $pattern, [$(stringify!($t)),+].len()).and_then(
+            |fields| {
+                let mut fields = fields.into_iter();
+
+                Ok(($($crate::parse::field::<$t>(fields.next().unwrap())?,)+))
+            },
+        )
+    };
+}
+
+/// `scan_fields` for a pattern which has to have `count` placeholders. Used by `scan!`.
+pub fn scan_counted<'a>(
+    text: &'a str,
+    pattern: &str,
+    count: usize,
+) -> Result<Vec<&'a str>, ParseError> {
+    let placeholders = pattern.matches("{}").count();
+    if placeholders != count {
+        let reason = format!("has {} placeholders for {} types", placeholders, count);
+        return Err(ParseError::new(pattern, reason));
+    }
+
+    scan_fields(text, pattern)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signed_integers_are_extracted() {
+        assert_eq!(
+            Ok(vec![2, -10, 15, 3]),
+            integers::<i32>("Sensor at x=2, y=-10: beacon at x=15, y=3")
+        );
+        assert_eq!(Ok(vec![2, 4, 6, 8]), integers::<u8>("2-4,6-8"));
+        assert_eq!(Ok(vec![]), integers::<i64>("no numbers - here"));
+    }
+
+    #[test]
+    fn too_large_integers_are_errors() {
+        let error = integers::<u8>("1 300").unwrap_err();
+
+        assert_eq!("300", error.text());
+    }
+
+    #[test]
+    fn sections_are_split_at_blank_lines() {
+        assert_eq!(vec!["a\nb", "c", "d"], sections("a\nb\n\nc\n\n\nd\n"));
+        assert_eq!(vec!["a\r\nb", "c"], sections("a\r\nb\r\n\r\nc"));
+        assert!(sections("").is_empty());
+    }
+
+    #[test]
+    fn lines_are_parsed() {
+        assert_eq!(Ok(vec![1, -2, 3]), parse_lines::<i32>("1\n-2\n3"));
+
+        let error = parse_lines::<i32>("1\n2\nthree").unwrap_err();
+        assert_eq!(Some(3), error.line());
+        assert_eq!("three", error.text());
+        assert!(error.to_string().starts_with("line 3: 'three': "));
+    }
+
+    #[test]
+    fn patterns_are_scanned() {
+        assert_eq!(
+            Ok((3, 1, 2)),
+            scan!(
+                "move 3 from 1 to 2",
+                "move {} from {} to {}",
+                u32,
+                usize,
+                usize
+            )
+        );
+        assert_eq!(
+            Ok(("aaaaa-bbb-z-123".to_string(), "abxyz".to_string())),
+            scan!("aaaaa-bbb-z-123[abxyz]", "{}[{}]", String, String)
+        );
+    }
+
+    #[test]
+    fn mismatching_patterns_are_errors() {
+        assert!(scan!("move 3 from 1", "move {} from {} to {}", u32, u32, u32).is_err());
+        assert!(scan!(
+            "move 3 from 1 to 2!",
+            "move {} from {} to {}",
+            u32,
+            u32,
+            u32
+        )
+        .is_err());
+        assert!(scan!("move x from 1 to 2", "move {} from {} to {}", u32, u32, u32).is_err());
+
+        let error = scan!("1 -> 2", "{} -> {}", u32, u32, u32).unwrap_err();
+        assert_eq!(
+            "'{} -> {}': has 2 placeholders for 3 types",
+            error.to_string()
+        );
+        let error = scan!("1 -> 2", "{} -> {}", u32).unwrap_err();
+        assert_eq!("{} -> {}", error.text());
+
+        let error =
+            map_lines("1 -> 2\n3 => 4", |line| scan!(line, "{} -> {}", u32, u32)).unwrap_err();
+        assert_eq!(Some(2), error.line());
+    }
+}
@@ -0,0 +1,318 @@
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+use num_traits::Zero;
+
+/// The path to a goal found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    /// Cost of the whole path. For `bfs` this is the number of steps.
+    pub cost: C,
+    /// Nodes from the start to the goal, both included.
+    pub nodes: Vec<N>,
+}
+
+/// Breadth-first search from all starts to the closest node which is a goal.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut visited = Visited::new();
+    let mut queue: VecDeque<usize> = starts
+        .into_iter()
+        .filter_map(|start| visited.relax(start, None, 0))
+        .collect();
+
+    while let Some(index) = queue.pop_front() {
+        let (node, steps) = visited.node(index);
+        if is_goal(&node) {
+            return Some(visited.path(index, steps));
+        }
+
+        for next in successors(&node) {
+            if !visited.contains(&next) {
+                queue.extend(visited.relax(next, Some(index), steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+/// The number of steps from the closest start to every reachable node.
+pub fn distances<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    reachable_within(starts, successors, usize::MAX)
+}
+
+/// The number of nodes which can be reached from the starts in at most `steps` steps,
+/// including the starts.
+pub fn count_reachable_within<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    steps: usize,
+) -> usize
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    reachable_within(starts, successors, steps).len()
+}
+
+fn reachable_within<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    limit: usize,
+) -> HashMap<N, usize>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if distances.insert(start.clone(), 0).is_none() {
+            queue.push_back((start, 0));
+        }
+    }
+
+    while let Some((node, steps)) = queue.pop_front() {
+        if steps == limit {
+            continue;
+        }
+
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(steps + 1);
+                queue.push_back((next, steps + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// Dijkstra's algorithm from all starts to the cheapest node which is a goal.
+/// The successors are the next nodes together with the cost of the step to them.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    successors: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, successors, |_| C::zero(), is_goal)
+}
+
+/// A* search from all starts to the cheapest node which is a goal.
+/// The heuristic must never overestimate the remaining cost to a goal.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Eq + Hash + Clone,
+    C: Zero + Ord + Copy,
+    I: IntoIterator<Item = (N, C)>,
+{
+    let mut visited = Visited::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        let estimate = heuristic(&start);
+        if let Some(index) = visited.relax(start, None, C::zero()) {
+            heap.push(Reverse((estimate, C::zero(), index)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let (node, best) = visited.node(index);
+        // a cheaper way to the node was found after this one was queued
+        if cost > best {
+            continue;
+        }
+        if is_goal(&node) {
+            return Some(visited.path(index, cost));
+        }
+
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            let estimate = next_cost + heuristic(&next);
+            if let Some(next_index) = visited.relax(next, Some(index), next_cost) {
+                heap.push(Reverse((estimate, next_cost, next_index)));
+            }
+        }
+    }
+
+    None
+}
+
+/// Every node seen by a search with the cheapest known cost and the node it was reached from.
+struct Visited<N, C> {
+    indices: HashMap<N, usize>,
+    nodes: Vec<(N, Option<usize>, C)>,
+}
+
+impl<N: Eq + Hash + Clone, C: Ord + Copy> Visited<N, C> {
+    fn new() -> Self {
+        Visited {
+            indices: HashMap::new(),
+            nodes: Vec::new(),
+        }
+    }
+
+    fn contains(&self, node: &N) -> bool {
+        self.indices.contains_key(node)
+    }
+
+    fn node(&self, index: usize) -> (N, C) {
+        let (node, _, cost) = &self.nodes[index];
+
+        (node.clone(), *cost)
+    }
+
+    /// Record the node if it is new or cheaper than before. Returns its index in that case.
+    fn relax(&mut self, node: N, parent: Option<usize>, cost: C) -> Option<usize> {
+        match self.indices.entry(node) {
+            Entry::Vacant(entry) => {
+                let index = self.nodes.len();
+                self.nodes.push((entry.key().clone(), parent, cost));
+                entry.insert(index);
+
+                Some(index)
+            }
+            Entry::Occupied(entry) => {
+                let index = *entry.get();
+                let known = &mut self.nodes[index];
+                if cost >= known.2 {
+                    return None;
+                }
+                known.1 = parent;
+                known.2 = cost;
+
+                Some(index)
+            }
+        }
+    }
+
+    fn path(&self, goal: usize, cost: C) -> Path<N, C> {
+        let mut nodes = Vec::new();
+        let mut current = Some(goal);
+        while let Some(index) = current {
+            let (node, parent, _) = &self.nodes[index];
+            nodes.push(node.clone());
+            current = *parent;
+        }
+        nodes.reverse();
+
+        Path { cost, nodes }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 0 -> 1 -> 3 costs 1 + 10, 0 -> 2 -> 3 costs 5 + 2
+    fn weighted(node: &u32) -> Vec<(u32, u32)> {
+        match node {
+            0 => vec![(1, 1), (2, 5)],
+            1 => vec![(3, 10)],
+            2 => vec![(3, 2)],
+            _ => vec![],
+        }
+    }
+
+    fn line(node: &i32) -> [i32; 2] {
+        [node - 1, node + 1]
+    }
+
+    #[test]
+    fn bfs_finds_the_path_with_the_fewest_steps() {
+        let path = bfs(
+            [0],
+            |node| weighted(node).into_iter().map(|(next, _)| next),
+            |&node| node == 3,
+        );
+
+        assert_eq!(
+            Some(Path {
+                cost: 2,
+                nodes: vec![0, 1, 3]
+            }),
+            path
+        );
+    }
+
+    #[test]
+    fn bfs_starts_at_the_closest_start() {
+        let path = bfs([-10, 3], line, |&node| node == 5).unwrap();
+
+        assert_eq!(vec![3, 4, 5], path.nodes);
+    }
+
+    #[test]
+    fn unreachable_goals_are_none() {
+        assert_eq!(None, bfs([0], |_| Vec::new(), |&node: &i32| node == 1));
+        assert_eq!(None, dijkstra([0], weighted, |&node| node == 4));
+    }
+
+    #[test]
+    fn dijkstra_finds_the_cheapest_path() {
+        let path = dijkstra([0], weighted, |&node| node == 3);
+
+        assert_eq!(
+            Some(Path {
+                cost: 7,
+                nodes: vec![0, 2, 3]
+            }),
+            path
+        );
+    }
+
+    #[test]
+    fn astar_finds_the_cheapest_path() {
+        let path = astar([0], weighted, |&node| 3 - node.min(3), |&node| node == 3);
+
+        assert_eq!(
+            Some(Path {
+                cost: 7,
+                nodes: vec![0, 2, 3]
+            }),
+            path
+        );
+    }
+
+    #[test]
+    fn reachable_nodes_are_counted() {
+        assert_eq!(7, count_reachable_within([0], line, 3));
+        assert_eq!(9, count_reachable_within([0, 2], line, 3));
+        assert_eq!(1, count_reachable_within([0], line, 0));
+    }
+
+    #[test]
+    fn distances_contain_every_reachable_node() {
+        let distances = distances([0], |node: &u32| {
+            weighted(node).into_iter().map(|(next, _)| next)
+        });
+
+        assert_eq!(HashMap::from([(0, 0), (1, 1), (2, 1), (3, 2)]), distances);
+    }
+}
@@ -0,0 +1,231 @@
+use std::fmt;
+use std::time::{Duration, Instant};
+
+use crate::parse::ParseError;
+
+/// Common result type of both parts of every puzzle.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Number(i64),
+    /// Only numbers larger than `i64::MAX`, so every number has a single representation.
+    Unsigned(u64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Unsigned(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+macro_rules! impl_from_signed {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Self {
+                    Answer::Number(number as i64)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_from_unsigned {
+    ($($number:ty),*) => {
+        $(
+            impl From<$number> for Answer {
+                fn from(number: $number) -> Self {
+                    match i64::try_from(number) {
+                        Ok(number) => Answer::Number(number),
+                        Err(_) => Answer::Unsigned(number as u64),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_signed!(i8, i16, i32, i64, isize);
+impl_from_unsigned!(u8, u16, u32, u64, usize);
+
+// Only compared against `i64`, so integer literals in tests are inferred without annotations.
+impl PartialEq<Answer> for i64 {
+    fn eq(&self, answer: &Answer) -> bool {
+        matches!(answer, Answer::Number(number) if number == self)
+    }
+}
+
+impl PartialEq<i64> for Answer {
+    fn eq(&self, number: &i64) -> bool {
+        number == self
+    }
+}
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+impl PartialEq<Answer> for &str {
+    fn eq(&self, answer: &Answer) -> bool {
+        matches!(answer, Answer::Text(text) if text == self)
+    }
+}
+
+impl PartialEq<Answer> for String {
+    fn eq(&self, answer: &Answer) -> bool {
+        matches!(answer, Answer::Text(text) if text == self)
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, text: &&str) -> bool {
+        text == self
+    }
+}
+
+impl PartialEq<String> for Answer {
+    fn eq(&self, text: &String) -> bool {
+        text == self
+    }
+}
+
+/// A puzzle whose input is parsed once and then shared between both parts.
+/// The parsed input may borrow from the puzzle input. Invalid input is reported as a
+/// `ParseError`, preferably with the number of the offending line.
+pub trait Solution {
+    type Input<'a>;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, ParseError>;
+
+    fn part1(input: &Self::Input<'_>) -> Answer;
+
+    fn part2(input: &Self::Input<'_>) -> Answer;
+}
+
+/// The answers of both parts and the time each step took.
+#[derive(Debug)]
+pub struct Solved {
+    pub parse_time: Duration,
+    pub part1: Answer,
+    pub part1_time: Duration,
+    pub part2: Answer,
+    pub part2_time: Duration,
+}
+
+/// Parse the input and solve both parts, timing each step separately.
+/// Fails without solving anything if the input can't be parsed.
+pub fn solve<S: Solution>(input: &str) -> Result<Solved, ParseError> {
+    let (parsed, parse_time) = timed(|| S::parse(input));
+    let parsed = parsed?;
+    let (part1, part1_time) = timed(|| S::part1(&parsed));
+    let (part2, part2_time) = timed(|| S::part2(&parsed));
+
+    Ok(Solved {
+        parse_time,
+        part1,
+        part1_time,
+        part2,
+        part2_time,
+    })
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+
+    (result, start.elapsed())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        type Input<'a> = Vec<usize>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            Ok(input.split_whitespace().map(str::len).collect())
+        }
+
+        fn part1(lengths: &Self::Input<'_>) -> Answer {
+            lengths.iter().sum::<usize>().into()
+        }
+
+        fn part2(lengths: &Self::Input<'_>) -> Answer {
+            lengths.iter().map(|length| length.to_string()).collect::<String>().into()
+        }
+    }
+
+    #[test]
+    fn answers_compare_to_numbers_and_text() {
+        assert_eq!(5, Answer::from(5_u64));
+        assert_eq!(-3, Answer::from(-3_i64));
+        assert_eq!("abc", Answer::from("abc".to_string()));
+        assert_ne!(5, Answer::from("5"));
+        assert_eq!(Answer::from(7_u8), 7);
+        assert_eq!(Answer::from("abc"), "abc");
+    }
+
+    #[test]
+    fn large_unsigned_answers_are_kept() {
+        assert_eq!(Answer::Number(i64::MAX), Answer::from(i64::MAX as u64));
+        assert_eq!(Answer::Unsigned(u64::MAX), Answer::from(u64::MAX));
+        assert_eq!("18446744073709551615", Answer::from(u64::MAX).to_string());
+        assert_ne!(-1, Answer::from(u64::MAX));
+    }
+
+    #[test]
+    fn answers_are_displayed_without_variant() {
+        assert_eq!("42", Answer::Number(42).to_string());
+        assert_eq!("ABC", Answer::Text("ABC".to_string()).to_string());
+    }
+
+    #[test]
+    fn solve_shares_parsed_input_between_parts() {
+        let solved = solve::<Lengths>("ab cde f").unwrap();
+
+        assert_eq!(6, solved.part1);
+        assert_eq!("231", solved.part2);
+    }
+
+    struct Numbers;
+
+    impl Solution for Numbers {
+        type Input<'a> = Vec<i64>;
+
+        fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+            crate::parse::parse_lines(input)
+        }
+
+        fn part1(numbers: &Self::Input<'_>) -> Answer {
+            numbers.iter().sum::<i64>().into()
+        }
+
+        fn part2(numbers: &Self::Input<'_>) -> Answer {
+            numbers.iter().product::<i64>().into()
+        }
+    }
+
+    #[test]
+    fn invalid_lines_are_reported_instead_of_solved() {
+        assert_eq!(24, solve::<Numbers>("2\n3\n4").unwrap().part2);
+
+        let error = solve::<Numbers>("2\n3\nfour\n5").unwrap_err();
+        assert_eq!(Some(3), error.line());
+        assert_eq!("four", error.text());
+    }
+}
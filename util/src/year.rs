@@ -0,0 +1,83 @@
+use crate::parse::ParseError;
+use crate::solution::Solved;
+
+/// A solved puzzle together with the function parsing its input and solving both parts.
+pub struct Day {
+    pub day: u8,
+    pub solve: fn(&str) -> Result<Solved, ParseError>,
+}
+
+/// All solved puzzles of one event.
+/// `directory` is the crate directory the inputs get cached in.
+pub struct Year {
+    pub year: u16,
+    pub directory: &'static str,
+    pub days: &'static [Day],
+}
+
+impl Year {
+    pub fn get(&self, day: u8) -> Option<&Day> {
+        self.days.iter().find(|solved| solved.day == day)
+    }
+}
+
+/// Register the solved days of a year crate as `day => Solution` pairs.
+/// Generates the constant `YEAR` the `aoc` runner dispatches to.
+#[macro_export]
+macro_rules! year {
+    ($year:literal, $directory:literal, { $($day:literal => $solution:path),* $(,)? }) => {
+        pub const YEAR: $crate::year::Year = $crate::year::Year {
+            year: $year,
+            directory: $directory,
+            days: &[
+                $($crate::year::Day {
+                    day: $day,
+                    solve: $crate::solution::solve::<$solution>,
+                },)*
+            ],
+        };
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    mod day01 {
+        use crate::parse::ParseError;
+        use crate::solution::{Answer, Solution};
+
+        pub struct Day01;
+
+        impl Solution for Day01 {
+            type Input<'a> = &'a str;
+
+            fn parse(input: &str) -> Result<Self::Input<'_>, ParseError> {
+                Ok(input)
+            }
+
+            fn part1(input: &Self::Input<'_>) -> Answer {
+                input.len().into()
+            }
+
+            fn part2(input: &Self::Input<'_>) -> Answer {
+                (*input).into()
+            }
+        }
+    }
+
+    year!(2015, "aoc15", {
+        1 => day01::Day01,
+    });
+
+    #[test]
+    fn registered_days_are_found() {
+        let solved = (YEAR.get(1).unwrap().solve)("input").unwrap();
+
+        assert_eq!(5, solved.part1);
+        assert_eq!("input", solved.part2);
+    }
+
+    #[test]
+    fn missing_days_are_not_found() {
+        assert!(YEAR.get(2).is_none());
+    }
+}